@@ -1,4 +1,6 @@
-use jta_display_wall_adapter::{MessageFromWebControl, MessageToWebControl};
+use jta_display_wall_adapter::{
+    MessageFromWebControl, MessageToWebControl, WebControlLoginRequest, WebControlLoginResponse,
+};
 use rust_to_ts_types::TypescriptSerializable;
 use std::{collections::HashSet, fs, path::PathBuf};
 
@@ -11,6 +13,8 @@ fn main() {
     let mut collector = Vec::new();
     collector.append(&mut MessageFromWebControl::all_types_output());
     collector.append(&mut MessageToWebControl::all_types_output());
+    collector.append(&mut WebControlLoginRequest::all_types_output());
+    collector.append(&mut WebControlLoginResponse::all_types_output());
 
     let mut out_vec = collector
         .into_iter()
@@ -431,6 +431,8 @@ struct ImagesStorageSerealizer<'a> {
 pub struct ImagesStorage {
     pub jta_logo: ImageMeta,
     pub advertisement_images: Vec<ImageMeta>,
+    /// File names of the advertisement images, in the same order (not part of the compile data)
+    pub advertisement_image_names: Vec<String>,
     pub cached_rescaler: CachedImageScaler,
     pub fireworks_animation: Animation,
 }
@@ -455,6 +457,7 @@ impl ImagesStorage {
             jta_logo,
             cached_rescaler: scaler,
             advertisement_images: Vec::new(),
+            advertisement_image_names: Vec::new(),
             fireworks_animation,
         };
     }
@@ -489,6 +492,7 @@ impl ImagesStorage {
                 .into_iter()
                 .map(|a| ImageMeta::from_bytes(a))
                 .collect(),
+            advertisement_image_names: Vec::new(),
             cached_rescaler: CachedImageScaler::from_bytes(dec.cached_rescaler),
             fireworks_animation: Animation::from_bytes(dec.fireworks_animation),
         };
@@ -25,6 +25,9 @@ pub struct Args {
     /// Address of where the display program lives (like "127.0.0.1")
     #[arg(long, default_value_t = String::from("127.0.0.1"))]
     pub passthrough_address_display_program: String,
+    /// Timing system that feeds heats, start lists and results into the server (usually fixed for one meet)
+    #[arg(long, value_enum, default_value_t = TimingSourceBackend::CameraProgram)]
+    pub timing_source: TimingSourceBackend,
     /// Address of where camera program lives (like "127.0.0.1") - if unset, no connection to camera program will be attempted
     #[arg(long)]
     pub address_camera_program: Option<String>,
@@ -37,15 +40,24 @@ pub struct Args {
     /// Port where the camera program has their xml endpoint
     #[arg(long, default_value_t = String::from("4447"))]
     pub camera_exchange_xml_port: String,
+    /// Folder FinishLynx reads its start lists (lynx.evt, lynx.ppl) from and writes its results (.lif) to
+    #[arg(long)]
+    pub lynx_folder_path: Option<String>,
     /// Port where the internal communication between server and client takes place
     #[arg(long, default_value_t = String::from("5678"))]
     pub display_client_communication_port: String,
     /// Port where the internal communication between server and webclient takes place
     #[arg(long, default_value_t = String::from("6789"))]
     pub internal_webcontrol_port: String,
-    /// Password to gate the admin webcontrol functions a little (NOT cryptographically secure in ANY way. Data gets exposed even without password!)
+    /// Password of the built-in "admin" webcontrol user (only used, if no webcontrol_user is configured)
     #[arg(long, default_value_t = String::from("password"))]
     pub webcontrol_password: String,
+    /// Webcontrol user as "name:role:hash" with role viewer, operator or admin (can be given multiple times, generate the hash in key mode with hash_webcontrol_password and quote it, it contains $ signs)
+    #[arg(long)]
+    pub webcontrol_user: Vec<String>,
+    /// Password to print the hash for a webcontrol_user entry of (only in key mode)
+    #[arg(long)]
+    pub hash_webcontrol_password: Option<String>,
     /// Address of the wind server lives (for the server to talk to) (like "127.0.0.1") - if unset, no connection to wind server will be attempted
     #[arg(long)]
     pub address_wind_server: Option<String>,
@@ -67,6 +79,16 @@ pub struct Args {
     /// Address of where display client lives (for the server to talk to) (like "127.0.0.1") - if unset, no connection to display client will be attempted
     #[arg(long)]
     pub address_display_client: Option<String>,
+    /// Further display clients the server talks to, as "name,role,address[:port]" with role one of finish, start, speaker, callroom (can be given multiple times)
+    #[arg(long)]
+    pub additional_display_client: Vec<String>,
+    /// Character LED scoreboards driven over RS-232, as "name,framing,port[,baud[,width]]" (can be given multiple times).
+    /// The framing is "line", "framed" or a template of {text}, {status} and <XX> hex bytes like "<02>{status}{text}<03>"
+    #[arg(long)]
+    pub scoreboard: Vec<String>,
+    /// Number of upcoming heats a display client in call room mode lists
+    #[arg(long, default_value_t = 3)]
+    pub call_room_heats: usize,
     /// If the data that is incoming through should get hexdump-displayed
     #[arg(long, action = clap::ArgAction::SetTrue)]
     pub hexdump_incoming_communication: bool,
@@ -115,6 +137,18 @@ pub struct Args {
     /// Export folder path
     #[arg(long)]
     pub export_folder_path: Option<String>,
+    /// Run the display client without opening a window (renders into memory, frames still get sent to the server)
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pub headless_client: bool,
+    /// Folder where the headless display client writes png snapshots of the rendered frames - if unset, no snapshots are written
+    #[arg(long)]
+    pub headless_snapshot_folder: Option<String>,
+    /// Number of time that passes, until the headless client writes the next snapshot
+    #[arg(long, default_value_t = 1000)]
+    pub headless_snapshot_every_nr_of_ms: u64,
+    /// Json layout template for the timing screens (regions as fractions of the display, for walls that differ from 720x240) - if unset, the built-in layouts are used
+    #[arg(long)]
+    pub layout_template_file: Option<String>,
     /// overwrite the client to place window not "Always on top"
     #[arg(long, action = clap::ArgAction::SetTrue)]
     pub do_not_set_client_window_always_on_top: bool,
@@ -175,6 +209,15 @@ pub struct Args {
     /// Port where the internal communication between idcapture server and server takes place
     #[arg(long, default_value_t = String::from("8901"))]
     pub idcapture_exchange_port: String,
+    /// Record all inbound streams (camera program, timing program, wind and bib server) into this session file
+    #[arg(long)]
+    pub record_session_file: Option<String>,
+    /// Replay a previously recorded session file into the server (as if the data came in over the network)
+    #[arg(long)]
+    pub replay_session_file: Option<String>,
+    /// Speedup for replaying a session file (1.0 is original speed, 0 or below replays as fast as possible)
+    #[arg(long, default_value_t = 1.0)]
+    pub replay_speed_factor: f64,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
@@ -186,4 +229,11 @@ pub enum Mode {
     Key,
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
+pub enum TimingSourceBackend {
+    CameraProgram,
+    FinishLynx,
+    None,
+}
+
 pub const MAX_NUMBER_OF_MESSAGES_IN_INTERNAL_BUFFERS: usize = 100;
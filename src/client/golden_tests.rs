@@ -0,0 +1,468 @@
+//! Golden image regression tests for the client rendering
+//!
+//! Every scenario is rendered headless and compared against `tests/golden_images/<name>.png`.
+//! Run with `UPDATE_GOLDEN_IMAGES=1 cargo test` to (re)write the reference images after an intended layout change.
+
+use crate::args::Args;
+use crate::client::parts::headless::HeadlessRenderer;
+use crate::client::qrcode::QrCodePayload;
+use crate::client::ticker::TickerMessage;
+use crate::client::timing::{TimingSettings, TimingTimeDisplayMode, TimingUpdate};
+use crate::client::TARGET_FPS;
+use crate::interface::{ClientStateMachine, MessageFromServerToClient};
+use crate::server::bib_detection::DisplayEntry;
+use crate::server::camera_program_types::{
+    HeatCompetitor, HeatCompetitorResult, HeatResult, HeatStartList,
+};
+use crate::server::field_events::{
+    FieldAttemptResult, FieldEvent, FieldEventAttempt, FieldEventCompetitor, FieldEventData,
+    FieldEventKind,
+};
+use crate::times::{DayTime, RaceTime, RaceWind};
+use async_channel::Receiver;
+use clap::Parser;
+use image::{DynamicImage, ImageBuffer, ImageFormat, Rgba, RgbaImage};
+use std::io::Cursor;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+const RESOLUTIONS: [(u32, u32); 3] = [(720, 240), (480, 160), (1280, 320)];
+/// channel difference that still counts as the same pixel (font rasterization may differ slightly)
+const CHANNEL_TOLERANCE: u8 = 24;
+/// fraction of pixels that may exceed the channel tolerance
+const MAX_DIFFERING_PIXEL_FRACTION: f64 = 0.005;
+/// holding must never run out while a scenario is rendered
+const HOLD_FOREVER_MS: u32 = 3_600_000;
+
+struct Scenario {
+    args: Args,
+    state_machine: ClientStateMachine,
+    self_receiver: Receiver<MessageFromServerToClient>,
+}
+impl Scenario {
+    fn new() -> Self {
+        let args = Args::parse_from(["jta-display-wall-adapter", "client"]);
+        let (sender, receiver) = async_channel::unbounded::<MessageFromServerToClient>();
+        let mut state_machine = ClientStateMachine::new(&args, sender);
+
+        // leave the Created state, as on a real connection
+        state_machine.parse_server_command(MessageFromServerToClient::RequestVersion);
+
+        Self {
+            args,
+            state_machine,
+            self_receiver: receiver,
+        }
+    }
+
+    fn send(&mut self, msg: MessageFromServerToClient) {
+        self.state_machine.parse_server_command(msg);
+
+        // the timing state machine talks to the client state machine over this channel
+        while let Ok(internal) = self.self_receiver.try_recv() {
+            self.state_machine.parse_server_command(internal);
+        }
+    }
+
+    fn timing(&mut self, mode: TimingTimeDisplayMode) {
+        let mut settings = TimingSettings::new(&self.args);
+        settings.mode = mode;
+        settings.hold_time_ms = HOLD_FOREVER_MS;
+        self.send(MessageFromServerToClient::TimingSettingsUpdate(settings));
+        self.send(MessageFromServerToClient::Timing);
+    }
+
+    fn update(&mut self, update: TimingUpdate) {
+        self.send(MessageFromServerToClient::TimingStateUpdate(update));
+    }
+}
+
+fn golden_image_folder() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden_images")
+}
+
+fn race_time(text: &str) -> RaceTime {
+    RaceTime::parse_from_string(text).unwrap()
+}
+
+fn competitor(lane: u32, bib: u32, last_name: &str, first_name: &str) -> HeatCompetitor {
+    HeatCompetitor {
+        id: format!("{}", bib),
+        lane,
+        bib,
+        class: String::from("MHK"),
+        last_name: last_name.into(),
+        first_name: first_name.into(),
+        nation: String::from("GER"),
+        club: String::from("LG Teststadt"),
+        gender: String::from("M"),
+        disqualified: None,
+    }
+}
+
+fn start_list(distance_meters: u32) -> HeatStartList {
+    HeatStartList {
+        name: format!("{}m Männer Lauf 1", distance_meters),
+        id: Uuid::from_u128(distance_meters as u128),
+        nr: 1,
+        session_nr: 1,
+        distance_meters,
+        scheduled_start_time: DayTime::from_hms_opt(10, 0, 0).unwrap(),
+        competitors: vec![
+            competitor(1, 101, "Mustermann", "Max"),
+            competitor(2, 102, "Schmidt", "Lukas"),
+            competitor(3, 103, "Weber", "Jonas"),
+            competitor(4, 104, "Fischer", "Paul"),
+            competitor(5, 105, "Wagner", "Felix"),
+        ],
+    }
+}
+
+fn heat_result(list: &HeatStartList) -> HeatResult {
+    let times = ["10.87", "11.02", "11.05", "11.31", "11.64"];
+
+    HeatResult {
+        id: list.id,
+        name: list.name.clone(),
+        distance_meters: list.distance_meters,
+        start_time: DayTime::from_hms_opt(10, 0, 0).unwrap(),
+        wind: Some(RaceWind::parse_from_f32(1.5)),
+        competitors_evaluated: list
+            .competitors
+            .iter()
+            .zip(times)
+            .enumerate()
+            .map(|(i, (comp, time))| HeatCompetitorResult {
+                competitor: comp.clone(),
+                distance: list.distance_meters,
+                rank: i as u32 + 1,
+                runtime: race_time(time),
+                runtime_full_precision: race_time(time),
+                finish_time: DayTime::from_hms_opt(10, 0, 11).unwrap(),
+            })
+            .collect(),
+        competitors_left_to_evaluate: Vec::new(),
+    }
+}
+
+fn long_jump() -> FieldEventData {
+    let competitors: Vec<FieldEventCompetitor> = [
+        (201, "Anna", "Becker"),
+        (202, "Lea", "Hoffmann"),
+        (203, "Marie", "Koch"),
+    ]
+    .into_iter()
+    .map(|(bib, first_name, last_name)| FieldEventCompetitor {
+        bib,
+        first_name: first_name.into(),
+        last_name: last_name.into(),
+        club: String::from("LG Teststadt"),
+    })
+    .collect();
+    let event = FieldEvent {
+        id: Uuid::from_u128(200),
+        name: String::from("Weitsprung Frauen"),
+        kind: FieldEventKind::Jump,
+        nr_attempts: 3,
+        scheduled_start_time: DayTime::from_hms_opt(11, 0, 0).unwrap(),
+        competitors,
+    };
+    let attempts = [
+        (201, 1, FieldAttemptResult::Mark(585), Some(0.8)),
+        (202, 1, FieldAttemptResult::Foul, Some(1.2)),
+        (203, 1, FieldAttemptResult::Mark(602), Some(-0.4)),
+        (201, 2, FieldAttemptResult::Pass, None),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(i, (bib, attempt_nr, result, wind))| FieldEventAttempt {
+        id: Uuid::from_u128(300 + i as u128),
+        event_id: event.id,
+        bib,
+        attempt_nr,
+        result,
+        wind: wind.map(RaceWind::parse_from_f32),
+    })
+    .collect();
+
+    FieldEventData::build(event, attempts)
+}
+
+fn advertisement_image(color: [u8; 3]) -> Vec<u8> {
+    let img: RgbaImage = ImageBuffer::from_fn(300, 100, |x, y| {
+        Rgba([
+            color[0].saturating_add((x / 3) as u8),
+            color[1],
+            color[2].saturating_add(y as u8),
+            255,
+        ])
+    });
+
+    let mut bytes = Vec::new();
+    DynamicImage::ImageRgba8(img)
+        .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+        .unwrap();
+    bytes
+}
+
+fn compare_with_golden_image(name: &str, rendered: &RgbaImage) -> Result<(), String> {
+    let path = golden_image_folder().join(format!("{}.png", name));
+
+    if std::env::var("UPDATE_GOLDEN_IMAGES").is_ok() {
+        std::fs::create_dir_all(golden_image_folder()).map_err(|e| e.to_string())?;
+        rendered.save(&path).map_err(|e| e.to_string())?;
+        return Ok(());
+    }
+
+    let reference = match image::open(&path) {
+        Ok(img) => img.to_rgba8(),
+        Err(e) => {
+            return Err(format!(
+                "{}: no reference image at {} ({})",
+                name,
+                path.display(),
+                e
+            ))
+        }
+    };
+
+    if reference.dimensions() != rendered.dimensions() {
+        return Err(format!(
+            "{}: size {:?} does not match reference size {:?}",
+            name,
+            rendered.dimensions(),
+            reference.dimensions()
+        ));
+    }
+
+    let differing = reference
+        .pixels()
+        .zip(rendered.pixels())
+        .filter(|(a, b)| {
+            a.0.iter()
+                .zip(b.0.iter())
+                .any(|(ca, cb)| ca.abs_diff(*cb) > CHANNEL_TOLERANCE)
+        })
+        .count();
+    let fraction = differing as f64 / (rendered.width() * rendered.height()) as f64;
+
+    if fraction > MAX_DIFFERING_PIXEL_FRACTION {
+        let failed_path = std::env::temp_dir().join(format!("golden_failed_{}.png", name));
+        let _ = rendered.save(&failed_path);
+
+        return Err(format!(
+            "{}: {:.2}% of pixels differ from the reference (rendered image written to {})",
+            name,
+            fraction * 100.0,
+            failed_path.display()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Renders the scenario in all resolutions and collects mismatches
+fn check_scenario(name: &str, setup: impl Fn(&mut Scenario)) {
+    let mut errors = Vec::new();
+
+    for (width, height) in RESOLUTIONS {
+        let mut scenario = Scenario::new();
+        setup(&mut scenario);
+
+        let mut renderer = HeadlessRenderer::new(width, height);
+        let rendered = renderer
+            .render(&mut scenario.state_machine)
+            .unwrap()
+            .get_image_buffer();
+
+        if let Err(e) =
+            compare_with_golden_image(&format!("{}_{}x{}", name, width, height), &rendered)
+        {
+            errors.push(e);
+        }
+    }
+
+    assert!(errors.is_empty(), "{}", errors.join("\n"));
+}
+
+#[test]
+fn idle() {
+    check_scenario("idle", |_| {});
+}
+
+#[test]
+fn display_text() {
+    check_scenario("display_text", |s| {
+        s.send(MessageFromServerToClient::DisplayText(String::from(
+            "Willkommen zum Sportfest",
+        )));
+    });
+}
+
+#[test]
+fn advertisements() {
+    check_scenario("advertisements", |s| {
+        s.send(MessageFromServerToClient::AdvertisementImages(vec![
+            (String::from("a.png"), advertisement_image([200, 40, 40])),
+            (String::from("b.png"), advertisement_image([40, 40, 200])),
+        ]));
+        s.send(MessageFromServerToClient::Advertisements);
+    });
+}
+
+#[test]
+fn clock() {
+    check_scenario("clock", |s| {
+        s.send(MessageFromServerToClient::Clock(
+            DayTime::from_hms_opt(12, 34, 56).unwrap(),
+        ));
+    });
+}
+
+#[test]
+fn timing_start_list() {
+    check_scenario("timing_start_list", |s| {
+        s.timing(TimingTimeDisplayMode::TimeBigAndHoldTopWithRunName);
+        s.update(TimingUpdate::Meta(start_list(100)));
+        s.update(TimingUpdate::StartList);
+    });
+}
+
+#[test]
+fn timing_result_list() {
+    check_scenario("timing_result_list", |s| {
+        let list = start_list(100);
+        s.timing(TimingTimeDisplayMode::TimeBigAndHoldTopWithRunName);
+        s.update(TimingUpdate::Meta(list.clone()));
+        s.update(TimingUpdate::ResultMeta(heat_result(&list)));
+        s.update(TimingUpdate::ResultList);
+    });
+}
+
+fn finished_sprint(s: &mut Scenario, mode: TimingTimeDisplayMode) {
+    s.timing(mode);
+    s.update(TimingUpdate::Meta(start_list(100)));
+    s.update(TimingUpdate::Timing);
+    s.update(TimingUpdate::Wind(RaceWind::parse_from_f32(1.5)));
+    s.update(TimingUpdate::End(race_time("10.87")));
+}
+
+fn held_middle_distance(s: &mut Scenario, mode: TimingTimeDisplayMode) {
+    s.timing(mode);
+    s.update(TimingUpdate::Meta(start_list(800)));
+    s.update(TimingUpdate::Timing);
+    s.update(TimingUpdate::Intermediate(race_time("1:01.20")));
+    s.update(TimingUpdate::End(race_time("2:04.31")));
+}
+
+#[test]
+fn timing_time_big_and_hold() {
+    check_scenario("timing_time_big_and_hold", |s| {
+        finished_sprint(s, TimingTimeDisplayMode::TimeBigAndHold)
+    });
+}
+
+#[test]
+fn timing_time_big_and_hold_top() {
+    check_scenario("timing_time_big_and_hold_top", |s| {
+        finished_sprint(s, TimingTimeDisplayMode::TimeBigAndHoldTop)
+    });
+    check_scenario("timing_time_big_and_hold_top_held", |s| {
+        held_middle_distance(s, TimingTimeDisplayMode::TimeBigAndHoldTop)
+    });
+}
+
+#[test]
+fn timing_time_big_and_hold_with_run_name() {
+    check_scenario("timing_time_big_and_hold_with_run_name", |s| {
+        finished_sprint(s, TimingTimeDisplayMode::TimeBigAndHoldWithRunName)
+    });
+}
+
+#[test]
+fn timing_time_big_and_hold_top_with_run_name() {
+    check_scenario("timing_time_big_and_hold_top_with_run_name", |s| {
+        finished_sprint(s, TimingTimeDisplayMode::TimeBigAndHoldTopWithRunName)
+    });
+    check_scenario("timing_time_big_and_hold_top_with_run_name_held", |s| {
+        held_middle_distance(s, TimingTimeDisplayMode::TimeBigAndHoldTopWithRunName)
+    });
+}
+
+#[test]
+fn timing_street_run() {
+    check_scenario("timing_street_run", |s| {
+        s.timing(TimingTimeDisplayMode::StreetRun);
+        s.update(TimingUpdate::Meta(start_list(5000)));
+        s.update(TimingUpdate::Timing);
+        s.update(TimingUpdate::End(race_time("25:31.4")));
+        for (bib, name, round) in [(101, "Max Mustermann", 3), (102, "Lukas Schmidt", 2)] {
+            s.send(MessageFromServerToClient::PushDisplayEntry(DisplayEntry {
+                bib,
+                name: name.into(),
+                round,
+                max_rounds: 12,
+            }));
+        }
+    });
+}
+
+#[test]
+fn timing_time_big_with_splits() {
+    check_scenario("timing_time_big_with_splits", |s| {
+        s.timing(TimingTimeDisplayMode::TimeBigWithSplits);
+        s.update(TimingUpdate::Meta(start_list(800)));
+        s.update(TimingUpdate::Timing);
+        s.update(TimingUpdate::Intermediate(race_time("1:01.20")));
+        s.update(TimingUpdate::Intermediate(race_time("2:04.31")));
+    });
+}
+
+#[test]
+fn qr_code() {
+    check_scenario("qr_code", |s| {
+        s.send(MessageFromServerToClient::DisplayQrCode(QrCodePayload {
+            content: String::from("https://example.com/results"),
+            caption: Some(String::from("Ergebnisse")),
+        }));
+    });
+}
+
+#[test]
+fn call_room() {
+    check_scenario("call_room", |s| {
+        s.send(MessageFromServerToClient::CallRoom(vec![
+            start_list(100),
+            start_list(200),
+        ]));
+    });
+}
+
+#[test]
+fn field_event() {
+    check_scenario("field_event", |s| {
+        s.send(MessageFromServerToClient::FieldEvent(long_jump()));
+    });
+}
+
+#[test]
+fn ticker() {
+    check_scenario("ticker", |s| {
+        s.send(MessageFromServerToClient::Clock(
+            DayTime::from_hms_opt(12, 34, 56).unwrap(),
+        ));
+        s.send(MessageFromServerToClient::TickerQueue(vec![
+            TickerMessage {
+                id: Uuid::from_u128(1),
+                text: String::from("Nächster Lauf: 100m Männer"),
+                priority: 1,
+                repeat: None,
+            },
+        ]));
+        // the message starts scrolling in with the first frame, render it 5 seconds later
+        s.state_machine.ticker.current(0);
+        s.state_machine.frame_counter = 5 * TARGET_FPS;
+    });
+}
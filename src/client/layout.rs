@@ -0,0 +1,358 @@
+#[cfg(test)]
+mod parsing_tests;
+
+use crate::{
+    args::Args,
+    client::{
+        rasterizing::{
+            Color, FontSizeChooserCache, FontSizeDebouncer, FontWidthDebouncer, JTA_GRAY_COLOR,
+        },
+        rendering::{NUMBER_SPACE_FRACTION, RESULT_SPACE_FRACTION_TEMPLATE},
+    },
+};
+use serde::{Deserialize, Serialize};
+
+/// Number of display entry lines (bib popups) the timing state machine keeps visible
+pub const LAYOUT_ENTRY_LINES: usize = 3;
+
+/// Declarative scoreboard layout that replaces the built-in (720x240 tuned) layouts of the timing screens.
+/// Every screen that is left out keeps being rendered by the built-in layout.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutTemplate {
+    pub name: String,
+    #[serde(default = "default_background")]
+    pub background: Color,
+    /// Used for the running time, independent of the timing display mode selected in the webcontrol
+    #[serde(default)]
+    pub timing: Option<Vec<LayoutElement>>,
+    #[serde(default)]
+    pub start_list: Option<Vec<LayoutElement>>,
+    #[serde(default)]
+    pub result_list: Option<Vec<LayoutElement>>,
+}
+fn default_background() -> Color {
+    JTA_GRAY_COLOR
+}
+
+/// Position and size as fractions (0.0 to 1.0) of the display, so one template fits every resolution
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct LayoutRegion {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+impl LayoutRegion {
+    /// (x, y, width, height) in pixels
+    pub fn in_pixels(&self, texture_width: usize, texture_height: usize) -> (f32, f32, f32, f32) {
+        (
+            self.x * texture_width as f32,
+            self.y * texture_height as f32,
+            self.width * texture_width as f32,
+            self.height * texture_height as f32,
+        )
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        for (name, value) in [
+            ("x", self.x),
+            ("y", self.y),
+            ("width", self.width),
+            ("height", self.height),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(format!(
+                    "Region value {} = {} is not a fraction between 0 and 1",
+                    name, value
+                ));
+            }
+        }
+        if self.x + self.width > 1.0 + f32::EPSILON || self.y + self.height > 1.0 + f32::EPSILON {
+            return Err(format!("Region {:?} reaches outside of the display", self));
+        }
+
+        Ok(())
+    }
+}
+
+/// Data a text element displays. Elements whose binding has no value (e.g. no wind measured yet) are not drawn
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum LayoutTextBinding {
+    Static(String),
+    RaceTime,
+    /// Held intermediate time, prefixed by the distance if known ("800m: 2:04.31")
+    HeldTime,
+    Wind,
+    HeatName,
+    Distance,
+    EntryBib(usize),
+    EntryName(usize),
+    EntryRound(usize),
+    /// Held times of the race as "800m 2:04.31", 0 is the most recent one
+    Split(usize),
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum LayoutTextAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum LayoutIcon {
+    Wind,
+    Finish,
+    Round,
+}
+
+/// Images an image element can show
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum LayoutImage {
+    /// Advertisement image with this file name, as sent by the server
+    Advertisement(String),
+    JtaLogo,
+}
+
+/// Additional requirement for an element to be drawn
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum LayoutCondition {
+    WindKnown,
+    TimeHeld,
+    /// The race has been finished, but the display keeps showing the running time
+    FinishedWhileTimeRunning,
+    EntryPresent(usize),
+    SplitPresent(usize),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum LayoutElement {
+    Box {
+        region: LayoutRegion,
+        color: Color,
+        #[serde(default)]
+        condition: Option<LayoutCondition>,
+    },
+    /// Without `size` the text is fitted as big as possible into the region.
+    /// With `size` (fraction of the display height) left aligned text scrolls if it is too wide.
+    Text {
+        region: LayoutRegion,
+        binding: LayoutTextBinding,
+        #[serde(default)]
+        align: LayoutTextAlign,
+        #[serde(default)]
+        size: Option<f32>,
+        #[serde(default)]
+        condition: Option<LayoutCondition>,
+    },
+    Icon {
+        region: LayoutRegion,
+        icon: LayoutIcon,
+        #[serde(default)]
+        condition: Option<LayoutCondition>,
+    },
+    /// Scaled into the region keeping its aspect ratio. An advertisement image that is not loaded is not drawn
+    Image {
+        region: LayoutRegion,
+        image: LayoutImage,
+        #[serde(default = "default_opacity")]
+        opacity: u8,
+        #[serde(default)]
+        condition: Option<LayoutCondition>,
+    },
+    /// Start or result list (depending on the screen) as number / name / bib-or-result columns, paged like the built-in tables
+    Table {
+        region: LayoutRegion,
+        #[serde(default = "default_number_fraction")]
+        number_fraction: f32,
+        #[serde(default = "default_result_fraction")]
+        result_fraction: f32,
+    },
+}
+fn default_opacity() -> u8 {
+    u8::MAX
+}
+fn default_number_fraction() -> f32 {
+    NUMBER_SPACE_FRACTION
+}
+fn default_result_fraction() -> f32 {
+    RESULT_SPACE_FRACTION_TEMPLATE
+}
+impl LayoutElement {
+    fn validate(&self) -> Result<(), String> {
+        let check_line = |line: usize| -> Result<(), String> {
+            if line >= LAYOUT_ENTRY_LINES {
+                return Err(format!(
+                    "Entry line {} does not exist (only {} lines are available)",
+                    line, LAYOUT_ENTRY_LINES
+                ));
+            }
+            Ok(())
+        };
+        let check_condition = |condition: &Option<LayoutCondition>| -> Result<(), String> {
+            match condition {
+                Some(LayoutCondition::EntryPresent(line)) => check_line(*line),
+                _ => Ok(()),
+            }
+        };
+
+        match self {
+            LayoutElement::Box {
+                region, condition, ..
+            }
+            | LayoutElement::Icon {
+                region, condition, ..
+            } => {
+                region.validate()?;
+                check_condition(condition)
+            }
+            LayoutElement::Image {
+                region,
+                image,
+                condition,
+                ..
+            } => {
+                region.validate()?;
+                check_condition(condition)?;
+                match image {
+                    LayoutImage::Advertisement(name) if name.is_empty() => {
+                        Err(String::from("Advertisement image needs a file name"))
+                    }
+                    _ => Ok(()),
+                }
+            }
+            LayoutElement::Text {
+                region,
+                binding,
+                size,
+                condition,
+                ..
+            } => {
+                region.validate()?;
+                check_condition(condition)?;
+                if let Some(size) = size {
+                    if *size <= 0.0 || *size > 1.0 {
+                        return Err(format!(
+                            "Text size {} is not a fraction of the display height",
+                            size
+                        ));
+                    }
+                }
+                match binding {
+                    LayoutTextBinding::EntryBib(line)
+                    | LayoutTextBinding::EntryName(line)
+                    | LayoutTextBinding::EntryRound(line) => check_line(*line),
+                    _ => Ok(()),
+                }
+            }
+            LayoutElement::Table {
+                region,
+                number_fraction,
+                result_fraction,
+            } => {
+                region.validate()?;
+                if *number_fraction < 0.0
+                    || *result_fraction < 0.0
+                    || number_fraction + result_fraction >= 1.0
+                {
+                    return Err(String::from(
+                        "Table column fractions must be positive and leave space for the name column",
+                    ));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl LayoutTemplate {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let template: LayoutTemplate = serde_json::from_str(json).map_err(|e| e.to_string())?;
+
+        for elements in [
+            &template.timing,
+            &template.start_list,
+            &template.result_list,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            for element in elements {
+                element.validate()?;
+            }
+        }
+
+        Ok(template)
+    }
+}
+
+/// Reads the template given with `layout_template_file`. Problems are logged and the built-in layouts are used instead
+pub fn layout_template_from_args(args: &Args) -> Option<LayoutTemplate> {
+    let path = args.layout_template_file.as_ref()?;
+
+    match std::fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|content| LayoutTemplate::from_json(&content))
+    {
+        Ok(template) => {
+            info!("Using layout template '{}' from {}", template.name, path);
+            Some(template)
+        }
+        Err(e) => {
+            error!(
+                "Could not load layout template from {}, using built-in layouts: {}",
+                path, e
+            );
+            None
+        }
+    }
+}
+
+/// Font caches of one element (text is re-fitted every frame otherwise)
+pub struct LayoutElementCache {
+    pub font_size_cache: FontSizeChooserCache,
+    pub width_debouncer: FontWidthDebouncer,
+    pub size_debouncer: FontSizeDebouncer,
+}
+impl LayoutElementCache {
+    fn new() -> Self {
+        Self {
+            font_size_cache: FontSizeChooserCache::new(),
+            width_debouncer: FontWidthDebouncer::new(),
+            size_debouncer: FontSizeDebouncer::new(),
+        }
+    }
+}
+
+pub struct LayoutScreen {
+    pub elements: Vec<(LayoutElement, LayoutElementCache)>,
+}
+impl LayoutScreen {
+    fn new(elements: &Option<Vec<LayoutElement>>) -> Option<Self> {
+        elements.as_ref().map(|elements| Self {
+            elements: elements
+                .iter()
+                .map(|e| (e.clone(), LayoutElementCache::new()))
+                .collect(),
+        })
+    }
+}
+
+pub struct LoadedLayout {
+    pub background: Color,
+    pub timing: Option<LayoutScreen>,
+    pub start_list: Option<LayoutScreen>,
+    pub result_list: Option<LayoutScreen>,
+}
+impl LoadedLayout {
+    pub fn new(template: LayoutTemplate) -> Self {
+        Self {
+            timing: LayoutScreen::new(&template.timing),
+            start_list: LayoutScreen::new(&template.start_list),
+            result_list: LayoutScreen::new(&template.result_list),
+            background: template.background,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Parsing and validation of layout templates, including the shipped ones in `layout_templates/`
+
+use super::{LayoutElement, LayoutImage, LayoutTemplate};
+
+fn template_with_timing(elements: &str) -> Result<LayoutTemplate, String> {
+    LayoutTemplate::from_json(&format!(
+        r#"{{ "name": "Test", "timing": [{}] }}"#,
+        elements
+    ))
+}
+
+#[test]
+fn shipped_wide_template() {
+    let path = format!(
+        "{}/layout_templates/wide_1280x320.json",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let content = std::fs::read_to_string(path).expect("missing layout template");
+    let template = LayoutTemplate::from_json(&content).expect("template does not parse");
+
+    assert_eq!(template.name, "Wide wall (4:1)");
+    assert_eq!(template.timing.map(|e| e.len()), Some(10));
+    let start_list = template.start_list.expect("start list screen");
+    assert!(matches!(
+        start_list.last(),
+        Some(LayoutElement::Table { .. })
+    ));
+    let result_list = template.result_list.expect("result list screen");
+    assert!(matches!(
+        result_list.last(),
+        Some(LayoutElement::Table { .. })
+    ));
+}
+
+#[test]
+fn image_elements() {
+    let template = template_with_timing(
+        r#"
+        {
+            "type": "Image",
+            "region": { "x": 0.0, "y": 0.0, "width": 0.25, "height": 1.0 },
+            "image": { "Advertisement": "sponsor.png" },
+            "opacity": 128,
+            "condition": "TimeHeld"
+        },
+        {
+            "type": "Image",
+            "region": { "x": 0.75, "y": 0.0, "width": 0.25, "height": 1.0 },
+            "image": "JtaLogo"
+        }"#,
+    )
+    .expect("template does not parse");
+
+    let elements = template.timing.unwrap();
+    match &elements[0] {
+        LayoutElement::Image {
+            image: LayoutImage::Advertisement(name),
+            opacity,
+            condition,
+            ..
+        } => {
+            assert_eq!(name, "sponsor.png");
+            assert_eq!(*opacity, 128);
+            assert!(condition.is_some());
+        }
+        other => panic!("not an advertisement image: {:?}", other),
+    }
+    // opaque without an opacity
+    assert!(matches!(
+        elements[1],
+        LayoutElement::Image {
+            image: LayoutImage::JtaLogo,
+            opacity: 255,
+            condition: None,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn invalid_elements_are_rejected() {
+    // outside of the display
+    assert!(template_with_timing(
+        r#"{ "type": "Image", "region": { "x": 0.9, "y": 0.0, "width": 0.2, "height": 1.0 }, "image": "JtaLogo" }"#
+    )
+    .is_err());
+    // advertisement without a file name
+    assert!(template_with_timing(
+        r#"{ "type": "Image", "region": { "x": 0.0, "y": 0.0, "width": 0.2, "height": 1.0 }, "image": { "Advertisement": "" } }"#
+    )
+    .is_err());
+    // there are only three entry lines
+    assert!(template_with_timing(
+        r#"{ "type": "Text", "region": { "x": 0.0, "y": 0.0, "width": 0.2, "height": 1.0 }, "binding": { "EntryBib": 3 } }"#
+    )
+    .is_err());
+    // unknown image
+    assert!(template_with_timing(
+        r#"{ "type": "Image", "region": { "x": 0.0, "y": 0.0, "width": 0.2, "height": 1.0 }, "image": "Fireworks" }"#
+    )
+    .is_err());
+}
@@ -1,8 +1,13 @@
 mod bitmap;
 pub mod frametime;
+#[cfg(test)]
+mod golden_tests;
+mod layout;
 mod parts;
+mod qrcode;
 mod rasterizing;
 mod rendering;
+mod ticker;
 mod timing;
 
 pub use parts::client::run_client;
@@ -12,4 +17,6 @@ pub const REPORT_FRAME_LOGS_EVERY_SECONDS: u64 = 10;
 pub const PUBLISH_FRAME_TIME_MESSAGE_EVERY_SECONDS: u64 = 2;
 pub const FRAME_TIME_NS: u64 = 1_000_000_000 / TARGET_FPS as u64;
 
-pub use timing::{ClockState, TimingSettings, TimingStateMachine, TimingUpdate};
+pub use qrcode::{QrCodePayload, QrCodeState};
+pub use ticker::{TickerMessage, TickerState};
+pub use timing::{ClockState, RaceDistance, TimingSettings, TimingStateMachine, TimingUpdate};
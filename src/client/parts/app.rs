@@ -1,14 +1,15 @@
 use crate::args::Args;
 use crate::client::bitmap::png_to_bmp_bytes;
-use crate::client::rasterizing::RasterizerMeta;
+use crate::client::layout::layout_template_from_args;
+use crate::client::rasterizing::{load_font_and_layout, RasterizerMeta};
 use crate::client::rendering::{render_client_frame, RenderCache};
 use crate::client::{FRAME_TIME_NS, REPORT_FRAME_LOGS_EVERY_SECONDS, TARGET_FPS};
 use crate::file::{create_file_if_not_there_and_write, make_sure_folder_exists};
 use crate::interface::{ClientStateMachine, MessageFromClientToServer, MessageFromServerToClient};
 use async_broadcast::{Sender as BroadcastSender, TrySendError};
 use async_channel::{Receiver, Sender, TryRecvError};
-use fontdue::layout::{CoordinateSystem, Layout};
-use fontdue::{Font, FontSettings};
+use fontdue::layout::Layout;
+use fontdue::Font;
 use pixels::{Pixels, SurfaceTexture};
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -37,9 +38,7 @@ pub fn run_display_task(
     event_loop.set_control_flow(ControlFlow::wait_duration(Duration::from_millis(2)));
 
     // font setup
-    let font_data = include_bytes!("/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf") as &[u8];
-    let font = Font::from_bytes(font_data, FontSettings::default()).unwrap();
-    let font_layout = Layout::new(CoordinateSystem::PositiveYDown);
+    let (font, font_layout) = load_font_and_layout();
 
     // run app
     let mut app = App {
@@ -53,7 +52,7 @@ pub fn run_display_task(
         shutdown_marker: shutdown_marker,
         state_machine: ClientStateMachine::new(&args, tx_to_ui),
         last_draw_call: Instant::now(),
-        draw_cache: RenderCache::new(),
+        draw_cache: RenderCache::new().with_layout_template(layout_template_from_args(&args)),
     };
     let _ = event_loop.run_app(&mut app);
 }
@@ -1,5 +1,6 @@
 use crate::args::{Args, MAX_NUMBER_OF_MESSAGES_IN_INTERNAL_BUFFERS};
 use crate::client::parts::app::run_display_task;
+use crate::client::parts::headless::run_headless_display_task;
 use crate::client::parts::tcp::run_network_task;
 use crate::interface::{MessageFromClientToServer, MessageFromServerToClient};
 use std::io::Error;
@@ -42,11 +43,21 @@ pub async fn run_client(args: &Args) -> () {
         };
     });
 
-    run_display_task(
-        args.clone(),
-        rx_to_ui,
-        tx_from_ui,
-        tx_to_ui,
-        Arc::clone(&shutdown_marker),
-    );
+    if args.headless_client {
+        run_headless_display_task(
+            args.clone(),
+            rx_to_ui,
+            tx_from_ui,
+            tx_to_ui,
+            Arc::clone(&shutdown_marker),
+        );
+    } else {
+        run_display_task(
+            args.clone(),
+            rx_to_ui,
+            tx_from_ui,
+            tx_to_ui,
+            Arc::clone(&shutdown_marker),
+        );
+    }
 }
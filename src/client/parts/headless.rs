@@ -0,0 +1,214 @@
+use crate::args::Args;
+use crate::client::bitmap::png_to_bmp_bytes;
+use crate::client::layout::{layout_template_from_args, LayoutTemplate};
+use crate::client::rasterizing::{load_font_and_layout, RasterizerMeta};
+use crate::client::rendering::{render_client_frame, RenderCache};
+use crate::client::{FRAME_TIME_NS, REPORT_FRAME_LOGS_EVERY_SECONDS, TARGET_FPS};
+use crate::file::make_sure_folder_exists;
+use crate::interface::{
+    ClientState, ClientStateMachine, MessageFromClientToServer, MessageFromServerToClient,
+};
+use async_broadcast::{Sender as BroadcastSender, TrySendError};
+use async_channel::{Receiver, Sender, TryRecvError};
+use fontdue::layout::Layout;
+use fontdue::Font;
+use images_core::images::ImageMeta;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Renders the client state machine into an in-memory RGBA buffer instead of a window
+pub struct HeadlessRenderer {
+    font: Font,
+    font_layout: Layout,
+    frame: Vec<u8>,
+    width: u32,
+    height: u32,
+    draw_cache: RenderCache,
+}
+impl HeadlessRenderer {
+    pub fn new(width: u32, height: u32) -> Self {
+        let (font, font_layout) = load_font_and_layout();
+
+        Self {
+            font,
+            font_layout,
+            frame: vec![0; width as usize * height as usize * 4],
+            width,
+            height,
+            draw_cache: RenderCache::new(),
+        }
+    }
+
+    pub fn with_layout_template(mut self, template: Option<LayoutTemplate>) -> Self {
+        self.draw_cache = self.draw_cache.with_layout_template(template);
+        self
+    }
+
+    pub fn render(&mut self, state_machine: &mut ClientStateMachine) -> Result<ImageMeta, String> {
+        // there is no window to move, only the size is relevant
+        if let Some((_, _, w, h)) = state_machine.window_state_needs_update {
+            if w != self.width || h != self.height {
+                info!("Resizing headless frame buffer: {} {}", w, h);
+                self.width = w;
+                self.height = h;
+                self.frame = vec![0; w as usize * h as usize * 4];
+            }
+            state_machine.window_state_needs_update = None;
+        }
+        state_machine.current_frame_dimensions = Some((self.width, self.height));
+
+        let mut meta = RasterizerMeta {
+            font: &self.font,
+            font_layout: &mut self.font_layout,
+            frame: &mut self.frame,
+            texture_width: self.width as usize,
+            texture_height: self.height as usize,
+            server_imposed_settings: state_machine.server_imposed_settings.clone(),
+        };
+
+        render_client_frame(&mut meta, state_machine, &mut self.draw_cache);
+
+        meta.get_buffer_as_image()
+    }
+}
+
+pub fn run_headless_display_task(
+    args: Args,
+    rx_to_ui: Receiver<MessageFromServerToClient>,
+    tx_from_ui: BroadcastSender<MessageFromClientToServer>,
+    tx_to_ui: Sender<MessageFromServerToClient>,
+    shutdown_marker: Arc<AtomicBool>,
+) {
+    let mut renderer = HeadlessRenderer::new(args.dp_width, args.dp_height)
+        .with_layout_template(layout_template_from_args(&args));
+    let mut state_machine = ClientStateMachine::new(&args, tx_to_ui);
+
+    let frame_count_to_emit: u64 = std::cmp::max(
+        (args.client_emits_frame_every_nr_of_ms * 1000000) / FRAME_TIME_NS,
+        1,
+    );
+    let frame_count_to_snapshot: u64 = std::cmp::max(
+        (args.headless_snapshot_every_nr_of_ms * 1000000) / FRAME_TIME_NS,
+        1,
+    );
+
+    if let Some(folder) = &args.headless_snapshot_folder {
+        if let Err(e) = make_sure_folder_exists(Path::new(folder)) {
+            error!("{}", e);
+            return;
+        }
+        info!("Writing headless snapshots into {}", folder);
+    }
+
+    info!("Display client runs headless, no window will be opened");
+
+    loop {
+        let last_draw_call = Instant::now();
+
+        if shutdown_marker.load(Ordering::SeqCst) {
+            info!("Shutdown requested, stopping headless display");
+            break;
+        }
+
+        // IO and state machine
+        if !process_state(&mut state_machine, &rx_to_ui, &tx_from_ui) {
+            break;
+        }
+
+        match renderer.render(&mut state_machine) {
+            Ok(img) => {
+                if !matches!(state_machine.state, ClientState::DisplayExternalFrame(_))
+                    && state_machine
+                        .frame_counter
+                        .is_multiple_of(frame_count_to_emit)
+                {
+                    trace!("Sending back frame to the server");
+                    state_machine.push_new_message(MessageFromClientToServer::CurrentWindow(
+                        png_to_bmp_bytes(img.clone()),
+                    ));
+                }
+
+                if let Some(folder) = &args.headless_snapshot_folder {
+                    if state_machine
+                        .frame_counter
+                        .is_multiple_of(frame_count_to_snapshot)
+                    {
+                        let path = Path::new(folder)
+                            .join(format!("frame_{:08}.png", state_machine.frame_counter));
+                        match img.get_image_buffer().save(&path) {
+                            Ok(()) => trace!("Snapshot written to {}", path.display()),
+                            Err(e) => error!("Could not write snapshot: {}", e),
+                        }
+                    }
+                }
+            }
+            Err(e) => error!("Conversion error: {}", e),
+        }
+
+        let nanos_since_last_draw_start = last_draw_call.elapsed().as_nanos() as u64;
+        state_machine
+            .digest_frame_time_percentage((nanos_since_last_draw_start * 100) / FRAME_TIME_NS);
+
+        if state_machine
+            .frame_counter
+            .is_multiple_of(TARGET_FPS * REPORT_FRAME_LOGS_EVERY_SECONDS)
+        {
+            trace!(
+                "Headless frame was rendered (reports all {}s as per frame count)",
+                REPORT_FRAME_LOGS_EVERY_SECONDS
+            );
+        }
+
+        std::thread::sleep(Duration::from_nanos(
+            FRAME_TIME_NS.saturating_sub(nanos_since_last_draw_start),
+        ));
+    }
+}
+
+/// Same as the windowed App, returns false if the display task should exit
+fn process_state(
+    state_machine: &mut ClientStateMachine,
+    incoming: &Receiver<MessageFromServerToClient>,
+    outgoing: &BroadcastSender<MessageFromClientToServer>,
+) -> bool {
+    // handle the frame counter of the state machine
+    state_machine.advance_counters();
+
+    // update connection status (avoid pushing out messages if we know they will be trashed)
+    state_machine.set_outbound_connection_open(outgoing.receiver_count() > 0);
+
+    // read incoming messages (we do not need to loop, as this is running at 60 fps anyway)
+    match incoming.try_recv() {
+        Ok(msg) => {
+            state_machine.parse_server_command(msg);
+        }
+        Err(TryRecvError::Empty) => (),
+        Err(e) => {
+            error!("Error in inbound client internal communication: {}", e);
+            return false;
+        }
+    };
+    // send away outgoing messages (we do not need to loop, as this is running at 60 fps anyway)
+    if let Some(msg) = state_machine.get_one_message_to_send() {
+        match outgoing.try_broadcast(msg) {
+            Ok(Some(_)) => {
+                trace!("Thrown away old message in outgoing internal communication")
+            }
+            Ok(None) => (),
+            Err(TrySendError::Inactive(_)) => {
+                warn!("Outbound internal channel not open, no active receivers");
+            }
+            Err(TrySendError::Full(_)) => {
+                error!("Receivers are there, but outbound internal channel full. This should not happen!");
+            }
+            Err(TrySendError::Closed(_)) => {
+                error!("Outbound internal channel went away unexpectedly");
+                return false;
+            }
+        }
+    }
+
+    true
+}
@@ -1,3 +1,4 @@
 mod app;
 pub mod client;
+pub mod headless;
 mod tcp;
@@ -0,0 +1,78 @@
+use image::{DynamicImage, ImageBuffer, Rgba};
+use images_core::images::ImageMeta;
+use qrcode::{Color, EcLevel, QrCode};
+use rust_to_ts_types::TypescriptSerializable;
+use serde::{Deserialize, Serialize};
+
+/// Scanners need a light border of 4 modules around the code
+const QUIET_ZONE_MODULES: u32 = 4;
+const DARK_PIXEL: Rgba<u8> = Rgba([0, 0, 0, 255]);
+const LIGHT_PIXEL: Rgba<u8> = Rgba([255, 255, 255, 255]);
+
+/// What to encode (results url, sponsor link, "WIFI:T:WPA;S:<ssid>;P:<password>;;", ...)
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct QrCodePayload {
+    pub content: String,
+    pub caption: Option<String>,
+}
+
+pub struct QrCodeState {
+    pub caption: Option<String>,
+    width_modules: u32,
+    modules: Vec<bool>,
+    rendered: Option<(u32, ImageMeta)>,
+}
+impl QrCodeState {
+    pub fn new(payload: QrCodePayload) -> Result<Self, String> {
+        let code = QrCode::with_error_correction_level(payload.content.as_bytes(), EcLevel::M)
+            .map_err(|e| format!("Could not encode QR code: {}", e))?;
+
+        Ok(Self {
+            caption: payload.caption.filter(|c| !c.trim().is_empty()),
+            width_modules: code.width() as u32,
+            modules: code
+                .to_colors()
+                .into_iter()
+                .map(|c| c == Color::Dark)
+                .collect(),
+            rendered: None,
+        })
+    }
+
+    /// Square image with the quiet zone, at most `max_side` pixels wide. Cached until the size changes
+    pub fn image_for_size(&mut self, max_side: u32) -> Option<ImageMeta> {
+        if self.rendered.as_ref().map(|(side, _)| *side) != Some(max_side) {
+            let image = qr_code_image(&self.modules, self.width_modules, max_side)?;
+            self.rendered = Some((max_side, image));
+        }
+
+        self.rendered.as_ref().map(|(_, image)| image.clone())
+    }
+}
+
+/// Pixels are only scaled by whole numbers, blurred module edges make the code hard to scan
+fn qr_code_image(modules: &[bool], width_modules: u32, max_side: u32) -> Option<ImageMeta> {
+    let total_modules = width_modules + 2 * QUIET_ZONE_MODULES;
+    let scale = max_side / total_modules;
+    if scale == 0 {
+        return None;
+    }
+
+    let side = total_modules * scale;
+    let buffer = ImageBuffer::from_fn(side, side, |x, y| {
+        let module_x = (x / scale) as i64 - QUIET_ZONE_MODULES as i64;
+        let module_y = (y / scale) as i64 - QUIET_ZONE_MODULES as i64;
+        let inside = (0..width_modules as i64).contains(&module_x)
+            && (0..width_modules as i64).contains(&module_y);
+
+        if inside && modules[(module_y * width_modules as i64 + module_x) as usize] {
+            DARK_PIXEL
+        } else {
+            LIGHT_PIXEL
+        }
+    });
+
+    Some(ImageMeta::from_dynamic_image(DynamicImage::ImageRgba8(
+        buffer,
+    )))
+}
@@ -1,8 +1,8 @@
 use crate::{client::FRAME_TIME_NS, interface::ServerImposedSettings};
 use core::f32;
 use fontdue::{
-    layout::{GlyphPosition, Layout, LayoutSettings, TextStyle},
-    Font,
+    layout::{CoordinateSystem, GlyphPosition, Layout, LayoutSettings, TextStyle},
+    Font, FontSettings,
 };
 use image::Rgba;
 use image::{DynamicImage, ImageBuffer};
@@ -11,6 +11,14 @@ use std::collections::HashMap;
 
 const TEXT_SIZE_FINDING_STEP: f32 = 1.0; // think if this should be so small // TODO make argument
 
+pub fn load_font_and_layout() -> (Font, Layout) {
+    let font_data = include_bytes!("/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf") as &[u8];
+    let font = Font::from_bytes(font_data, FontSettings::default()).unwrap();
+    let font_layout = Layout::new(CoordinateSystem::PositiveYDown);
+
+    (font, font_layout)
+}
+
 pub struct RasterizerMeta<'a> {
     pub font: &'a Font,
     pub font_layout: &'a mut Layout,
@@ -468,6 +476,13 @@ pub fn draw_text(text: &str, x: f32, y: f32, script_size: f32, meta: &mut Raster
     draw_text_scrolling_with_width(text, x, y, script_size, f32::MAX, 0, meta);
 }
 
+pub fn measure_text_width(text: &str, script_size: f32, meta: &mut RasterizerMeta) -> usize {
+    layout_text(text, None, None, script_size, meta);
+    let (_, text_width, _) = text_meta_data(0.0, &meta.font_layout);
+
+    text_width
+}
+
 pub fn draw_text_right_aligned(
     text: &str,
     x: f32,
@@ -491,6 +506,25 @@ pub fn draw_text_right_aligned(
     );
 }
 
+pub fn draw_text_centered(
+    text: &str,
+    center_x: f32,
+    y: f32,
+    script_size: f32,
+    meta: &mut RasterizerMeta,
+) {
+    layout_text(text, None, Some(y), script_size, meta);
+    let (_, text_width, _) = text_meta_data(0.0, &meta.font_layout);
+
+    draw_text(
+        text,
+        center_x - text_width as f32 / 2.0,
+        y,
+        script_size,
+        meta,
+    );
+}
+
 pub type FontWidthDebouncer = FontDebouncer<usize>;
 pub type FontSizeDebouncer = FontDebouncer<FontChooserResult>;
 
@@ -1,20 +1,29 @@
-use super::timing::TimingTimeDisplayMode;
+use super::timing::{TimingStateMeta, TimingTimeDisplayMode};
 use crate::{
     client::{
+        layout::{
+            LayoutCondition, LayoutElement, LayoutIcon, LayoutImage, LayoutTemplate,
+            LayoutTextAlign, LayoutTextBinding, LoadedLayout, LAYOUT_ENTRY_LINES,
+        },
         rasterizing::{
             clear, draw_image, draw_image_at_opacity, draw_text, draw_text_as_big_as_possible,
-            draw_text_as_big_as_possible_right_aligned, draw_text_right_aligned,
-            draw_text_scrolling_with_width, fill_box_with_color, fill_with_color,
-            FontSizeChooserCache, FontSizeDebouncer, FontWidthDebouncer, RasterizerMeta,
-            JTA_GRAY_COLOR, JTA_GREEN_COLOR,
+            draw_text_as_big_as_possible_right_aligned, draw_text_centered,
+            draw_text_right_aligned, draw_text_scrolling_with_width, fill_box_with_color,
+            fill_with_color, measure_text_width, FontSizeChooserCache, FontSizeDebouncer,
+            FontWidthDebouncer, RasterizerMeta, JTA_GRAY_COLOR, JTA_GREEN_COLOR,
         },
         timing::TimingMode,
         TimingSettings, TimingStateMachine, FRAME_TIME_NS,
     },
-    interface::{ClientState, ClientStateMachine},
-    server::camera_program_types::HeatCompetitor,
-    times::RaceTime,
+    interface::{ClientState, ClientStateMachine, MessageFromClientToServer},
+    server::{
+        bib_detection::DisplayEntry,
+        camera_program_types::{HeatCompetitor, HeatStartList},
+        field_events::{format_field_mark, FieldEventData, FieldEventKind},
+    },
+    times::{RaceTime, WindLegality},
 };
+use images_core::images::{CachedImageScaler, IconsStorage, ImagesStorage};
 
 pub struct RenderCache {
     main_number_display_width_debouncer_street_race: FontWidthDebouncer,
@@ -23,14 +32,18 @@ pub struct RenderCache {
     font_size_cache_time_main_number_b: FontSizeChooserCache,
     font_size_cache_time_main_number_c: FontSizeChooserCache,
     font_size_cache_time_main_number_d: FontSizeChooserCache,
+    font_size_cache_time_main_number_e: FontSizeChooserCache,
     main_number_display_width_debouncer_a: FontWidthDebouncer,
     main_number_display_width_debouncer_b: FontWidthDebouncer,
     main_number_display_width_debouncer_c: FontWidthDebouncer,
     main_number_display_width_debouncer_d: FontWidthDebouncer,
+    main_number_display_width_debouncer_e: FontWidthDebouncer,
     main_number_display_size_debouncer_a: FontSizeDebouncer,
     main_number_display_size_debouncer_b: FontSizeDebouncer,
     main_number_display_size_debouncer_c: FontSizeDebouncer,
     main_number_display_size_debouncer_d: FontSizeDebouncer,
+    main_number_display_size_debouncer_e: FontSizeDebouncer,
+    layout: Option<LoadedLayout>,
 }
 impl RenderCache {
     pub fn new() -> Self {
@@ -41,16 +54,37 @@ impl RenderCache {
             main_number_display_width_debouncer_b: FontWidthDebouncer::new(),
             main_number_display_width_debouncer_c: FontWidthDebouncer::new(),
             main_number_display_width_debouncer_d: FontWidthDebouncer::new(),
+            main_number_display_width_debouncer_e: FontWidthDebouncer::new(),
             main_number_display_size_debouncer_a: FontSizeDebouncer::new(),
             main_number_display_size_debouncer_b: FontSizeDebouncer::new(),
             main_number_display_size_debouncer_c: FontSizeDebouncer::new(),
             main_number_display_size_debouncer_d: FontSizeDebouncer::new(),
+            main_number_display_size_debouncer_e: FontSizeDebouncer::new(),
             // only caching for performance
             font_size_cache_freetext: FontSizeChooserCache::new(),
             font_size_cache_time_main_number_a: FontSizeChooserCache::new(),
             font_size_cache_time_main_number_b: FontSizeChooserCache::new(),
             font_size_cache_time_main_number_c: FontSizeChooserCache::new(),
             font_size_cache_time_main_number_d: FontSizeChooserCache::new(),
+            font_size_cache_time_main_number_e: FontSizeChooserCache::new(),
+            layout: None,
+        }
+    }
+
+    /// Timing screens the template describes are rendered from it instead of the built-in layouts
+    pub fn with_layout_template(mut self, template: Option<LayoutTemplate>) -> Self {
+        self.layout = template.map(LoadedLayout::new);
+        self
+    }
+
+    fn layout_covers(&self, timing_mode: &TimingMode) -> bool {
+        match &self.layout {
+            None => false,
+            Some(layout) => match timing_mode {
+                TimingMode::Timing => layout.timing.is_some(),
+                TimingMode::StartList(_) => layout.start_list.is_some(),
+                TimingMode::ResultList(_) => layout.result_list.is_some(),
+            },
         }
     }
 }
@@ -114,6 +148,54 @@ pub fn render_client_frame(
         ClientState::DisplayExternalFrame(image) => {
             draw_image(0, 0, &image, meta);
         }
+        ClientState::QrCode(qr_code) => {
+            fill_with_color(JTA_GRAY_COLOR, meta);
+
+            let window_width = meta.texture_width as u32;
+            let window_height = meta.texture_height as u32;
+            let max_side = window_width.min(window_height);
+
+            match qr_code.image_for_size(max_side) {
+                Some(image) => {
+                    let side = image.width;
+                    if let Some(caption) = &qr_code.caption {
+                        // code on the left, caption in the remaining space to the right
+                        let pos_y = (window_height - side) / 2;
+                        draw_image(0, pos_y, &image, meta);
+                        draw_text_as_big_as_possible(
+                            caption,
+                            (side + 5) as f32,
+                            0.0,
+                            (window_width.saturating_sub(side + 10)) as usize,
+                            meta.texture_height,
+                            &mut cache.font_size_cache_freetext,
+                            meta,
+                        );
+                    } else {
+                        let pos_x = (window_width - side) / 2;
+                        let pos_y = (window_height - side) / 2;
+                        draw_image(pos_x, pos_y, &image, meta);
+                    }
+                }
+                None => {
+                    draw_text_as_big_as_possible(
+                        "QR code does not fit",
+                        5.0,
+                        0.0,
+                        meta.texture_width.saturating_sub(10),
+                        meta.texture_height,
+                        &mut cache.font_size_cache_freetext,
+                        meta,
+                    );
+                }
+            }
+        }
+        ClientState::CallRoom(heats) => {
+            render_call_room(heats, state.frame_counter, intermediate, meta);
+        }
+        ClientState::FieldEvent(data) => {
+            render_field_event(data, state.frame_counter, intermediate, meta);
+        }
         ClientState::Advertisements => {
             let nr_images = state.permanent_images_storage.advertisement_images.len();
             if nr_images > 0 {
@@ -186,16 +268,24 @@ pub fn render_client_frame(
                 draw_text("No images", 10.0, 10.0, 20.0, meta);
             }
         }
+        ClientState::Timing(timing_state_machine)
+            if cache.layout_covers(&timing_state_machine.timing_mode) =>
+        {
+            if let Some(layout) = &mut cache.layout {
+                render_timing_with_layout(
+                    layout,
+                    timing_state_machine,
+                    intermediate,
+                    &mut state.permanent_icons_storage,
+                    &mut state.permanent_images_storage,
+                    state.frame_counter,
+                    meta,
+                );
+            }
+        }
         ClientState::Timing(timing_state_machine) => {
-            let to_set = match timing_state_machine.settings.max_decimal_places_after_comma {
-                -1 => 0u8,
-                0 => 0,
-                1 => 1,
-                2 => 2,
-                3 => 4,
-                4 => 4,
-                _ => 4,
-            };
+            let to_set =
+                debounce_number_chars(timing_state_machine.settings.max_decimal_places_after_comma);
             cache
                 .main_number_display_width_debouncer_street_race
                 .set_debounce_number_chars(to_set);
@@ -215,6 +305,7 @@ pub fn render_client_frame(
                     TimingTimeDisplayMode::TimeBigAndHoldTop => false,
                     TimingTimeDisplayMode::TimeBigAndHoldTopWithRunName => true,
                     TimingTimeDisplayMode::TimeBigAndHoldWithRunName => true,
+                    TimingTimeDisplayMode::TimeBigWithSplits => true,
                 }
             }
 
@@ -582,40 +673,62 @@ pub fn render_client_frame(
                                 meta,
                             );
                         }
-                    }
+                        TimingTimeDisplayMode::TimeBigWithSplits => {
+                            let split_column_width = window_width * SPLIT_COLUMN_FRACTION;
+                            let split_lines = ((window_height - title_height as f32)
+                                / text_height as f32)
+                                .floor() as usize;
 
-                    // animations
-                    if let Some(over_top_player) = &mut timing_state_machine.over_top_animation {
-                        match over_top_player.get_current_frame(
-                            meta.texture_width as u32,
-                            meta.texture_height as u32,
-                            state.frame_counter,
-                            &mut state.permanent_images_storage.cached_rescaler,
-                        ) {
-                            Some(frame) => draw_image(0, 0, &frame, meta),
-                            None => (),
+                            // most recent split on top, older ones roll out at the bottom
+                            for (i, split_text) in timing_state_machine
+                                .get_split_texts()
+                                .iter()
+                                .take(split_lines)
+                                .enumerate()
+                            {
+                                draw_text_scrolling_with_width(
+                                    split_text,
+                                    border,
+                                    title_height as f32 * 1.15 + (i * text_height) as f32,
+                                    text_height as f32 * 0.85,
+                                    split_column_width - border,
+                                    state.frame_counter,
+                                    meta,
+                                );
+                            }
+                            draw_text_as_big_as_possible_right_aligned(
+                                &timing_state_machine
+                                    .get_main_display_race_time()
+                                    .optimize_representation_for_display(Some(
+                                        timing_state_machine
+                                            .settings
+                                            .max_decimal_places_after_comma,
+                                    ))
+                                    .to_string(),
+                                window_width - border,
+                                title_height as f32,
+                                (window_width - split_column_width - 2.0 * border) as usize,
+                                window_height as usize - title_height,
+                                &mut cache.font_size_cache_time_main_number_e,
+                                Some(&mut cache.main_number_display_width_debouncer_e),
+                                Some(&mut cache.main_number_display_size_debouncer_e),
+                                meta,
+                            );
                         }
                     }
+
+                    // animations
+                    draw_over_top_animation(
+                        timing_state_machine,
+                        state.frame_counter,
+                        &mut state.permanent_images_storage.cached_rescaler,
+                        meta,
+                    );
                 }
                 TimingMode::StartList(tms) => {
-                    let list = match &timing_state_machine.meta {
-                        Some(meta) => match &meta.start_list {
-                            Some(list) => list.competitors.clone(),
-                            None => Vec::new(),
-                        },
-                        None => Vec::new(),
-                    }
-                    .iter()
-                    .map(|a| ListLine {
-                        number: a.lane,
-                        athlete: a.clone(),
-                        res: None,
-                    })
-                    .collect();
-
                     draw_table(
                         info_for_table,
-                        list,
+                        start_list_lines(&timing_state_machine.meta),
                         true,
                         tms,
                         meta,
@@ -623,28 +736,15 @@ pub fn render_client_frame(
                         (title_height + 1) as f32,
                         window_width,
                         window_height - 1.0 - title_height as f32,
+                        NUMBER_SPACE_FRACTION,
+                        RESULT_SPACE_FRACTION_TEMPLATE,
                     );
                 }
                 TimingMode::ResultList(tms) => {
-                    let list = match &timing_state_machine.meta {
-                        Some(meta) => match &meta.result {
-                            Some(list) => list.competitors_evaluated.clone(),
-                            None => Vec::new(),
-                        },
-                        None => Vec::new(),
-                    }
-                    .iter()
-                    .map(|a| ListLine {
-                        number: a.rank,
-                        athlete: a.competitor.clone(),
-                        res: Some(a.runtime_full_precision.clone()),
-                    })
-                    .collect();
-
                     // TODO somehow note of the "competitors left to evaluate" maybe
                     draw_table(
                         info_for_table,
-                        list,
+                        result_list_lines(&timing_state_machine.meta),
                         false,
                         tms,
                         meta,
@@ -652,6 +752,8 @@ pub fn render_client_frame(
                         (title_height + 1) as f32,
                         window_width,
                         window_height - 1.0 - title_height as f32,
+                        NUMBER_SPACE_FRACTION,
+                        RESULT_SPACE_FRACTION_TEMPLATE,
                     );
                 }
             }
@@ -670,6 +772,8 @@ pub fn render_client_frame(
         }
     }
 
+    render_ticker(meta, state);
+
     if state.product_key.is_none() {
         draw_text_as_big_as_possible(
             "Unlicensed",
@@ -683,6 +787,307 @@ pub fn render_client_frame(
     }
 }
 
+const CALL_ROOM_LINES_PER_PAGE: usize = 4;
+
+/// One page per heat (more, if it has more competitors than fit), cycling like the tables
+fn render_call_room(
+    heats: &Vec<HeatStartList>,
+    frame_counter: u64,
+    intermediate: TSMForTableRenderIntermediate,
+    meta: &mut RasterizerMeta,
+) {
+    fill_with_color(JTA_GRAY_COLOR, meta);
+
+    let window_width: f32 = meta.texture_width as f32;
+    let window_height: f32 = meta.texture_height as f32;
+    let title_height = meta.texture_height / 5;
+    let text_height = meta.texture_height / 6;
+    let border = window_width / 36.0;
+
+    if heats.is_empty() {
+        draw_text_centered(
+            "No upcoming heats",
+            window_width / 2.0,
+            (window_height - text_height as f32) / 2.0,
+            text_height as f32,
+            meta,
+        );
+        return;
+    }
+
+    // (heat index, first competitor on the page)
+    let mut pages = Vec::new();
+    for (heat_index, heat) in heats.iter().enumerate() {
+        let mut first = 0;
+        loop {
+            pages.push((heat_index, first));
+            first += CALL_ROOM_LINES_PER_PAGE;
+            if first >= heat.competitors.len() {
+                break;
+            }
+        }
+    }
+    let frames_per_page =
+        ((intermediate.table_duration_nr_ms as u64 * 1000000) / FRAME_TIME_NS) + 1;
+    let (heat_index, first) = pages[((frame_counter / frames_per_page) as usize) % pages.len()];
+    let heat = &heats[heat_index];
+
+    fill_box_with_color(
+        0,
+        0,
+        meta.texture_width,
+        title_height,
+        JTA_GREEN_COLOR,
+        meta,
+    );
+    let counter = format!("{}/{}", heat_index + 1, heats.len());
+    let counter_width = window_width / 9.0;
+    draw_text_scrolling_with_width(
+        &format!(
+            "{:02}:{:02} {}",
+            heat.scheduled_start_time.hours, heat.scheduled_start_time.minutes, heat.name
+        ),
+        border,
+        0.0,
+        text_height as f32,
+        window_width - 3.0 * border - counter_width,
+        frame_counter,
+        meta,
+    );
+    draw_text_right_aligned(
+        &counter,
+        window_width - border,
+        0.0,
+        text_height as f32,
+        None,
+        meta,
+    );
+
+    let mut competitors = heat.competitors.clone();
+    competitors.sort_by_key(|c| c.lane);
+    let line_height = (window_height - title_height as f32 - 1.0) / CALL_ROOM_LINES_PER_PAGE as f32;
+    let font_size = line_height * 0.9;
+    let lane_width = window_width * NUMBER_SPACE_FRACTION;
+    let bib_width = window_width / 8.0;
+    let name_x = border + lane_width + bib_width;
+    for (i, competitor) in competitors
+        .iter()
+        .skip(first)
+        .take(CALL_ROOM_LINES_PER_PAGE)
+        .enumerate()
+    {
+        let y = title_height as f32 + 1.0 + i as f32 * line_height;
+        draw_text(&format!("{}", competitor.lane), border, y, font_size, meta);
+        draw_text(
+            &format!("{}", competitor.bib),
+            border + lane_width,
+            y,
+            font_size,
+            meta,
+        );
+        draw_text_scrolling_with_width(
+            &format!(
+                "{} {} ({})",
+                competitor.first_name, competitor.last_name, competitor.club
+            ),
+            name_x,
+            y,
+            font_size,
+            window_width - name_x - border,
+            frame_counter,
+            meta,
+        );
+    }
+}
+
+const FIELD_EVENT_STANDINGS_PER_PAGE: usize = 3;
+
+/// Who is up next in the first line, the standings below are paged like the tables
+fn render_field_event(
+    data: &FieldEventData,
+    frame_counter: u64,
+    intermediate: TSMForTableRenderIntermediate,
+    meta: &mut RasterizerMeta,
+) {
+    fill_with_color(JTA_GRAY_COLOR, meta);
+
+    let window_width: f32 = meta.texture_width as f32;
+    let window_height: f32 = meta.texture_height as f32;
+    let title_height = meta.texture_height / 5;
+    let text_height = meta.texture_height / 6;
+    let border = window_width / 36.0;
+
+    fill_box_with_color(
+        0,
+        0,
+        meta.texture_width,
+        title_height,
+        JTA_GREEN_COLOR,
+        meta,
+    );
+    let counter = match &data.up_next {
+        Some(up_next) => format!("{}/{}", up_next.attempt_nr, data.event.nr_attempts),
+        None => String::from("Final"),
+    };
+    let counter_width = window_width / 6.0;
+    draw_text_scrolling_with_width(
+        &data.event.name,
+        border,
+        0.0,
+        text_height as f32,
+        window_width - 3.0 * border - counter_width,
+        frame_counter,
+        meta,
+    );
+    draw_text_right_aligned(
+        &counter,
+        window_width - border,
+        0.0,
+        text_height as f32,
+        None,
+        meta,
+    );
+
+    let line_height =
+        (window_height - title_height as f32 - 1.0) / (FIELD_EVENT_STANDINGS_PER_PAGE + 1) as f32;
+    let font_size = line_height * 0.9;
+    let rank_width = window_width * NUMBER_SPACE_FRACTION;
+    let mark_width = window_width / 3.0;
+    let name_x = border + rank_width;
+
+    if let Some(up_next) = &data.up_next {
+        let y = title_height as f32 + 1.0;
+        // marker on the left, to set the competitor apart from the standings
+        fill_box_with_color(
+            0,
+            y as usize,
+            (border / 2.0) as usize,
+            line_height as usize,
+            JTA_GREEN_COLOR,
+            meta,
+        );
+        draw_text_scrolling_with_width(
+            &format!(
+                "{} {} {} ({})",
+                up_next.competitor.bib,
+                up_next.competitor.first_name,
+                up_next.competitor.last_name,
+                up_next.competitor.club
+            ),
+            border,
+            y,
+            font_size,
+            window_width - 2.0 * border,
+            frame_counter,
+            meta,
+        );
+    }
+
+    let nr_pages = data
+        .standings
+        .len()
+        .div_ceil(FIELD_EVENT_STANDINGS_PER_PAGE)
+        .max(1);
+    let frames_per_page =
+        ((intermediate.table_duration_nr_ms as u64 * 1000000) / FRAME_TIME_NS) + 1;
+    let page = ((frame_counter / frames_per_page) as usize) % nr_pages;
+
+    for (i, standing) in data
+        .standings
+        .iter()
+        .skip(page * FIELD_EVENT_STANDINGS_PER_PAGE)
+        .take(FIELD_EVENT_STANDINGS_PER_PAGE)
+        .enumerate()
+    {
+        let y = title_height as f32 + 1.0 + (i + 1) as f32 * line_height;
+        if let Some(rank) = standing.rank {
+            draw_text(&format!("{}", rank), border, y, font_size, meta);
+        }
+        draw_text_scrolling_with_width(
+            &format!(
+                "{} {}",
+                standing.competitor.first_name, standing.competitor.last_name
+            ),
+            name_x,
+            y,
+            font_size,
+            window_width - name_x - mark_width - 2.0 * border,
+            frame_counter,
+            meta,
+        );
+        if let Some(best_mark) = standing.best_mark {
+            let mark = match &standing.best_mark_wind {
+                Some(wind) if data.event.kind == FieldEventKind::Jump => {
+                    let legality = WindLegality::evaluate(Some(wind), true);
+                    format!(
+                        "{} {} {}",
+                        format_field_mark(best_mark),
+                        wind,
+                        legality.marker()
+                    )
+                    .trim_end()
+                    .to_string()
+                }
+                _ => format_field_mark(best_mark),
+            };
+            draw_text_right_aligned(&mark, window_width - border, y, font_size, None, meta);
+        }
+    }
+}
+
+const TICKER_BAND_HEIGHT_FRACTION: f32 = 0.2;
+const TICKER_PIXEL_PER_SEC_DEFAULT: u64 = 60;
+
+/// Band at the bottom, on top of whatever the client shows. Messages run in from the right until they left on the left
+fn render_ticker(meta: &mut RasterizerMeta, state: &mut ClientStateMachine) {
+    // a partly covered QR code can not be scanned anymore
+    if matches!(state.state, ClientState::Created | ClientState::QrCode(_)) {
+        return;
+    }
+    let (text, frames_running) = match state.ticker.current(state.frame_counter) {
+        Some(current) => current,
+        None => return,
+    };
+
+    let window_width = meta.texture_width as f32;
+    let band_height = (meta.texture_height as f32 * TICKER_BAND_HEIGHT_FRACTION).round();
+    let band_y = meta.texture_height as f32 - band_height;
+    fill_box_with_color(
+        0,
+        band_y as usize,
+        meta.texture_width,
+        band_height as usize,
+        JTA_GREEN_COLOR,
+        meta,
+    );
+
+    // same speed setting as the scrolling text in the tables
+    let pixels_moved = (frames_running
+        * FRAME_TIME_NS
+        * TICKER_PIXEL_PER_SEC_DEFAULT
+        * meta.server_imposed_settings.scroll_text_speed as u64
+        / 100
+        / 1_000_000_000) as f32;
+    let script_size = band_height * 0.8;
+    let text_width = measure_text_width(&text, script_size, meta) as f32;
+
+    draw_text(
+        &text,
+        window_width - pixels_moved,
+        band_y + band_height * 0.05,
+        script_size,
+        meta,
+    );
+
+    if pixels_moved > window_width + text_width {
+        if let Some(finished_id) = state.ticker.current_passed() {
+            state.push_new_message(MessageFromClientToServer::TickerMessageFinished(
+                finished_id,
+            ));
+        }
+    }
+}
+
 fn get_holding_top_text(timing_state_machine: &TimingStateMachine) -> Option<String> {
     if let Some(hts) = timing_state_machine.get_held_display_race_time() {
         let time_string = hts
@@ -702,6 +1107,379 @@ fn get_holding_top_text(timing_state_machine: &TimingStateMachine) -> Option<Str
     return None;
 }
 
+fn debounce_number_chars(max_decimal_places_after_comma: i8) -> u8 {
+    match max_decimal_places_after_comma {
+        -1 => 0u8,
+        0 => 0,
+        1 => 1,
+        2 => 2,
+        3 => 4,
+        4 => 4,
+        _ => 4,
+    }
+}
+
+fn draw_over_top_animation(
+    timing_state_machine: &mut TimingStateMachine,
+    frame_counter: u64,
+    rescaler: &mut CachedImageScaler,
+    meta: &mut RasterizerMeta,
+) {
+    if let Some(over_top_player) = &mut timing_state_machine.over_top_animation {
+        match over_top_player.get_current_frame(
+            meta.texture_width as u32,
+            meta.texture_height as u32,
+            frame_counter,
+            rescaler,
+        ) {
+            Some(frame) => draw_image(0, 0, &frame, meta),
+            None => (),
+        }
+    }
+}
+
+fn start_list_lines(timing_meta: &Option<TimingStateMeta>) -> Vec<ListLine> {
+    match timing_meta {
+        Some(meta) => match &meta.start_list {
+            Some(list) => list.competitors.clone(),
+            None => Vec::new(),
+        },
+        None => Vec::new(),
+    }
+    .iter()
+    .map(|a| ListLine {
+        number: a.lane,
+        athlete: a.clone(),
+        res: None,
+    })
+    .collect()
+}
+
+fn result_list_lines(timing_meta: &Option<TimingStateMeta>) -> Vec<ListLine> {
+    match timing_meta {
+        Some(meta) => match &meta.result {
+            Some(list) => list.competitors_evaluated.clone(),
+            None => Vec::new(),
+        },
+        None => Vec::new(),
+    }
+    .iter()
+    .map(|a| ListLine {
+        number: a.rank,
+        athlete: a.competitor.clone(),
+        res: Some(a.runtime_full_precision.clone()),
+    })
+    .collect()
+}
+
+/// Everything a layout template can bind to, read out once per frame
+struct LayoutValues {
+    race_time: String,
+    held_time: Option<String>,
+    wind: Option<String>,
+    heat_name: Option<String>,
+    distance: Option<String>,
+    entries: [Option<DisplayEntry>; LAYOUT_ENTRY_LINES],
+    splits: Vec<String>,
+    finished_while_time_running: bool,
+}
+impl LayoutValues {
+    fn read(timing_state_machine: &mut TimingStateMachine) -> Self {
+        // the entries count down their display time on reading, so only do this when they can be shown
+        let entries = match timing_state_machine.timing_mode {
+            TimingMode::Timing => {
+                let (a, b, c) =
+                    timing_state_machine.get_display_entries_at_lines_and_advance_frame_countdown();
+                [a, b, c]
+            }
+            _ => [None, None, None],
+        };
+
+        Self {
+            race_time: timing_state_machine
+                .get_main_display_race_time()
+                .optimize_representation_for_display(Some(
+                    timing_state_machine.settings.max_decimal_places_after_comma,
+                ))
+                .to_string(),
+            held_time: get_holding_top_text(timing_state_machine),
+            wind: timing_state_machine.race_wind(),
+            heat_name: timing_state_machine
+                .meta
+                .as_ref()
+                .map(|tsm| tsm.title.clone()),
+            distance: timing_state_machine
+                .meta
+                .as_ref()
+                .map(|tsm| format!("{}m", tsm.distance.get_distance_as_number())),
+            entries,
+            splits: timing_state_machine.get_split_texts(),
+            finished_while_time_running: timing_state_machine.race_finished()
+                && timing_state_machine.time_continues_running(),
+        }
+    }
+
+    fn text(&self, binding: &LayoutTextBinding) -> Option<String> {
+        match binding {
+            LayoutTextBinding::Static(text) => Some(text.clone()),
+            LayoutTextBinding::RaceTime => Some(self.race_time.clone()),
+            LayoutTextBinding::HeldTime => self.held_time.clone(),
+            LayoutTextBinding::Wind => self.wind.clone(),
+            LayoutTextBinding::HeatName => self.heat_name.clone(),
+            LayoutTextBinding::Distance => self.distance.clone(),
+            LayoutTextBinding::EntryBib(line) => self.entry(*line).map(|e| e.bib.to_string()),
+            LayoutTextBinding::EntryName(line) => self.entry(*line).map(|e| e.name.clone()),
+            LayoutTextBinding::EntryRound(line) => self
+                .entry(*line)
+                .map(|e| format!("{}/{}", e.round, e.max_rounds)),
+            LayoutTextBinding::Split(nr) => self.splits.get(*nr).cloned(),
+        }
+    }
+
+    fn entry(&self, line: usize) -> Option<&DisplayEntry> {
+        self.entries.get(line).and_then(|e| e.as_ref())
+    }
+
+    fn fulfills(&self, condition: &Option<LayoutCondition>) -> bool {
+        match condition {
+            None => true,
+            Some(LayoutCondition::WindKnown) => self.wind.is_some(),
+            Some(LayoutCondition::TimeHeld) => self.held_time.is_some(),
+            Some(LayoutCondition::FinishedWhileTimeRunning) => self.finished_while_time_running,
+            Some(LayoutCondition::EntryPresent(line)) => self.entry(*line).is_some(),
+            Some(LayoutCondition::SplitPresent(nr)) => self.splits.get(*nr).is_some(),
+        }
+    }
+}
+
+fn render_timing_with_layout(
+    layout: &mut LoadedLayout,
+    timing_state_machine: &mut TimingStateMachine,
+    intermediate: TSMForTableRenderIntermediate,
+    icons: &mut IconsStorage,
+    images: &mut ImagesStorage,
+    frame_counter: u64,
+    meta: &mut RasterizerMeta,
+) {
+    fill_with_color(layout.background, meta);
+
+    let values = LayoutValues::read(timing_state_machine);
+    let debounce_chars =
+        debounce_number_chars(timing_state_machine.settings.max_decimal_places_after_comma);
+    let mut info_for_table = Some(
+        timing_state_machine
+            .settings
+            .convert_to_table_info_ro(intermediate),
+    );
+
+    let (screen, mut table) = match &mut timing_state_machine.timing_mode {
+        TimingMode::Timing => (&mut layout.timing, None),
+        TimingMode::StartList(tms) => (
+            &mut layout.start_list,
+            Some((start_list_lines(&timing_state_machine.meta), true, tms)),
+        ),
+        TimingMode::ResultList(tms) => (
+            &mut layout.result_list,
+            Some((result_list_lines(&timing_state_machine.meta), false, tms)),
+        ),
+    };
+    let screen = match screen {
+        Some(screen) => screen,
+        None => return,
+    };
+
+    for (element, element_cache) in screen.elements.iter_mut() {
+        match element {
+            LayoutElement::Box {
+                region,
+                color,
+                condition,
+            } => {
+                if !values.fulfills(condition) {
+                    continue;
+                }
+                let (x, y, w, h) = region.in_pixels(meta.texture_width, meta.texture_height);
+                fill_box_with_color(x as usize, y as usize, w as usize, h as usize, *color, meta);
+            }
+            LayoutElement::Text {
+                region,
+                binding,
+                align,
+                size,
+                condition,
+            } => {
+                if !values.fulfills(condition) {
+                    continue;
+                }
+                let text = match values.text(binding) {
+                    Some(text) => text,
+                    None => continue,
+                };
+                let (x, y, w, h) = region.in_pixels(meta.texture_width, meta.texture_height);
+
+                // the running time changes every frame, avoid its size and position jittering
+                if let LayoutTextBinding::RaceTime = binding {
+                    element_cache
+                        .width_debouncer
+                        .set_debounce_number_chars(debounce_chars);
+                    element_cache
+                        .size_debouncer
+                        .set_debounce_number_chars(debounce_chars);
+                }
+
+                match (size, align) {
+                    (None, LayoutTextAlign::Right) => draw_text_as_big_as_possible_right_aligned(
+                        &text,
+                        x + w,
+                        y,
+                        w as usize,
+                        h as usize,
+                        &mut element_cache.font_size_cache,
+                        Some(&mut element_cache.width_debouncer),
+                        Some(&mut element_cache.size_debouncer),
+                        meta,
+                    ),
+                    // as big as possible is always centered in the box
+                    (None, _) => draw_text_as_big_as_possible(
+                        &text,
+                        x,
+                        y,
+                        w as usize,
+                        h as usize,
+                        &mut element_cache.font_size_cache,
+                        meta,
+                    ),
+                    (Some(size), LayoutTextAlign::Right) => draw_text_right_aligned(
+                        &text,
+                        x + w,
+                        y,
+                        *size * meta.texture_height as f32,
+                        Some(&mut element_cache.width_debouncer),
+                        meta,
+                    ),
+                    (Some(size), LayoutTextAlign::Center) => draw_text_centered(
+                        &text,
+                        x + w / 2.0,
+                        y,
+                        *size * meta.texture_height as f32,
+                        meta,
+                    ),
+                    (Some(size), LayoutTextAlign::Left) => draw_text_scrolling_with_width(
+                        &text,
+                        x,
+                        y,
+                        *size * meta.texture_height as f32,
+                        w,
+                        frame_counter,
+                        meta,
+                    ),
+                }
+            }
+            LayoutElement::Icon {
+                region,
+                icon,
+                condition,
+            } => {
+                if !values.fulfills(condition) {
+                    continue;
+                }
+                let (x, y, w, h) = region.in_pixels(meta.texture_width, meta.texture_height);
+                let image = match icon {
+                    LayoutIcon::Wind => &icons.wind_icon,
+                    LayoutIcon::Finish => &icons.finish_icon,
+                    LayoutIcon::Round => &icons.round_icon,
+                };
+                draw_image(
+                    x as u32,
+                    y as u32,
+                    &icons
+                        .cached_rescaler
+                        .scale_cached(image, w as u32, h as u32),
+                    meta,
+                );
+            }
+            LayoutElement::Image {
+                region,
+                image,
+                opacity,
+                condition,
+            } => {
+                if !values.fulfills(condition) {
+                    continue;
+                }
+                let image = match image {
+                    LayoutImage::JtaLogo => &images.jta_logo,
+                    LayoutImage::Advertisement(name) => match images
+                        .advertisement_image_names
+                        .iter()
+                        .position(|n| n == name)
+                        .and_then(|i| images.advertisement_images.get(i))
+                    {
+                        Some(image) => image,
+                        None => continue,
+                    },
+                };
+                let (x, y, w, h) = region.in_pixels(meta.texture_width, meta.texture_height);
+
+                // keep the aspect ratio, centered in the region
+                let scale = (w / image.width as f32).min(h / image.height as f32);
+                let scaled_width = (image.width as f32 * scale).round();
+                let scaled_height = (image.height as f32 * scale).round();
+                if scaled_width < 1.0 || scaled_height < 1.0 {
+                    continue;
+                }
+                let pos_x = (x + (w - scaled_width) / 2.0).round() as u32;
+                let pos_y = (y + (h - scaled_height) / 2.0).round() as u32;
+
+                let scaled = images.cached_rescaler.scale_cached(
+                    image,
+                    scaled_width as u32,
+                    scaled_height as u32,
+                );
+                if *opacity == u8::MAX {
+                    draw_image(pos_x, pos_y, &scaled, meta);
+                } else {
+                    draw_image_at_opacity(pos_x, pos_y, &scaled, *opacity, meta);
+                }
+            }
+            LayoutElement::Table {
+                region,
+                number_fraction,
+                result_fraction,
+            } => {
+                // a screen can only show its list once, the paging state is shared
+                if let (Some((lines, start_list, table_meta)), Some(list_settings)) =
+                    (table.take(), info_for_table.take())
+                {
+                    let (x, y, w, h) = region.in_pixels(meta.texture_width, meta.texture_height);
+                    draw_table(
+                        list_settings,
+                        lines,
+                        start_list,
+                        table_meta,
+                        meta,
+                        x,
+                        y,
+                        w,
+                        h,
+                        *number_fraction,
+                        *result_fraction,
+                    );
+                }
+            }
+        }
+    }
+
+    if let TimingMode::Timing = timing_state_machine.timing_mode {
+        draw_over_top_animation(
+            timing_state_machine,
+            frame_counter,
+            &mut images.cached_rescaler,
+            meta,
+        );
+    }
+}
+
 fn construct_list_name_repr(athlete: &HeatCompetitor) -> String {
     format!(
         "{} {}",
@@ -762,6 +1540,13 @@ impl TimingSettings {
     }
 }
 
+/// Part of the display width the split times take up beside the running time
+const SPLIT_COLUMN_FRACTION: f32 = 0.38;
+
+pub const NUMBER_SPACE_FRACTION: f32 = 0.08;
+const IN_BETWEEN_SPACE_FRACTION: f32 = 0.015;
+pub const RESULT_SPACE_FRACTION_TEMPLATE: f32 = 0.20;
+
 // TODO wind on result list?
 fn draw_table(
     list_settings: TSMForTableRender,
@@ -773,6 +1558,8 @@ fn draw_table(
     y: f32,
     width: f32,
     height: f32,
+    number_space_fraction: f32,
+    result_space_fraction_template: f32,
 ) {
     let mut lines = lines;
     let lines_on_page = list_settings.no_lines_in_lists.max(1) as u64;
@@ -782,15 +1569,12 @@ fn draw_table(
     let frames_per_page =
         ((list_settings.table_duration_nr_ms * 1000000) / (FRAME_TIME_NS as u32)) + 1;
 
-    const NUMBER_SPACE_FRACTION: f32 = 0.08;
-    const IN_BETWEEN_SPACE_FRACTION: f32 = 0.015;
-    const RESULT_SPACE_FRACTION_TEMPLATE: f32 = 0.20;
     let result_space_fraction: f32 = match has_a_third_col {
-        true => RESULT_SPACE_FRACTION_TEMPLATE + IN_BETWEEN_SPACE_FRACTION,
+        true => result_space_fraction_template + IN_BETWEEN_SPACE_FRACTION,
         false => 0.0,
     };
     let identifier_space_fraction: f32 =
-        1.0 - NUMBER_SPACE_FRACTION - result_space_fraction - IN_BETWEEN_SPACE_FRACTION;
+        1.0 - number_space_fraction - result_space_fraction - IN_BETWEEN_SPACE_FRACTION;
 
     lines.sort_by(|a, b| a.number.cmp(&b.number));
 
@@ -820,14 +1604,14 @@ fn draw_table(
                 &format!("{}", line.number),
                 x,
                 line_y_start,
-                (width * NUMBER_SPACE_FRACTION) as usize,
+                (width * number_space_fraction) as usize,
                 (line_height) as usize,
                 &mut FontSizeChooserCache::new(), // TODO this is REALLY inefficient. But there si no time to store it currently... Sorry...
                 meta,
             );
             draw_text_scrolling_with_width(
                 &construct_list_name_repr(&line.athlete),
-                x + (width * NUMBER_SPACE_FRACTION),
+                x + (width * number_space_fraction),
                 line_y_start,
                 line_height * 0.85,
                 width * identifier_space_fraction,
@@ -855,8 +1639,8 @@ fn draw_table(
 
                 draw_text_as_big_as_possible(
                     &text,
-                    width
-                        * (NUMBER_SPACE_FRACTION
+                    x + width
+                        * (number_space_fraction
                             + identifier_space_fraction
                             + IN_BETWEEN_SPACE_FRACTION),
                     line_y_start,
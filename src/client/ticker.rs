@@ -0,0 +1,106 @@
+use rust_to_ts_types::TypescriptSerializable;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, TypescriptSerializable)]
+pub struct TickerMessage {
+    pub id: Uuid,
+    pub text: String,
+    pub priority: u32, // higher priorities are shown first, lower ones only if nothing else is queued
+    pub repeat: Option<u32>, // None keeps the message in the ticker until it is deleted
+}
+
+/// Playback of the ticker queue on one client. The queue itself is owned by the server
+pub struct TickerState {
+    queue: Vec<TickerMessage>,
+    times_shown: HashMap<Uuid, u32>,
+    current: Option<(Uuid, u64)>, // id and the frame the message started scrolling in
+}
+impl TickerState {
+    pub fn new() -> Self {
+        Self {
+            queue: Vec::new(),
+            times_shown: HashMap::new(),
+            current: None,
+        }
+    }
+
+    /// Keeps the progress of messages that are still queued, a message that is currently scrolling is not interrupted
+    pub fn set_queue(&mut self, queue: Vec<TickerMessage>) {
+        self.times_shown
+            .retain(|id, _| queue.iter().any(|m| &m.id == id));
+        if let Some((id, _)) = &self.current {
+            if !queue.iter().any(|m| &m.id == id) {
+                self.current = None;
+            }
+        }
+        self.queue = queue;
+    }
+
+    /// Text of the message that is scrolling and the number of frames since it started. Picks the next one if necessary
+    pub fn current(&mut self, frame: u64) -> Option<(String, u64)> {
+        if self.current.is_none() {
+            self.current = self.next_message_id().map(|id| (id, frame));
+        }
+
+        let (id, started_at) = self.current?;
+        let message = self.queue.iter().find(|m| m.id == id)?;
+
+        Some((message.text.clone(), frame.saturating_sub(started_at)))
+    }
+
+    /// The current message has left the band. Returns its id, if it has been shown as often as requested
+    pub fn current_passed(&mut self) -> Option<Uuid> {
+        let (id, _) = self.current.take()?;
+        let times_shown = self.times_shown.entry(id).or_insert(0);
+        *times_shown += 1;
+        let times_shown = *times_shown;
+
+        let finished = self
+            .queue
+            .iter()
+            .find(|m| m.id == id)
+            .and_then(|m| m.repeat)
+            .is_some_and(|repeat| times_shown >= repeat);
+
+        if finished {
+            // removed locally right away, the server sends the updated queue afterwards
+            self.queue.retain(|m| m.id != id);
+            self.times_shown.remove(&id);
+            Some(id)
+        } else {
+            None
+        }
+    }
+
+    /// Highest priority first, within one priority the message that was shown the least (queue order on ties)
+    fn next_message_id(&self) -> Option<Uuid> {
+        let shown = |m: &TickerMessage| self.times_shown.get(&m.id).copied().unwrap_or(0);
+
+        let mut best: Option<&TickerMessage> = None;
+        for message in &self.queue {
+            if message
+                .repeat
+                .is_some_and(|repeat| shown(message) >= repeat)
+            {
+                continue;
+            }
+
+            best = match best {
+                None => Some(message),
+                Some(b) => {
+                    if message.priority > b.priority
+                        || (message.priority == b.priority && shown(message) < shown(b))
+                    {
+                        Some(message)
+                    } else {
+                        Some(b)
+                    }
+                }
+            };
+        }
+
+        best.map(|m| m.id)
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
         bib_detection::DisplayEntry,
         camera_program_types::{HeatResult, HeatStartList},
     },
-    times::{DayTime, RaceTime, RaceWind},
+    times::{wind_relevant_for_distance, DayTime, RaceTime, RaceWind, WindLegality},
 };
 use async_channel::{Sender, TrySendError};
 use images_core::images::{Animation, AnimationPlayer, ImagesStorage};
@@ -23,6 +23,7 @@ pub enum TimingTimeDisplayMode {
     TimeBigAndHoldTop,
     TimeBigAndHoldWithRunName,
     TimeBigAndHoldTopWithRunName,
+    TimeBigWithSplits,
     StreetRun,
 }
 
@@ -252,6 +253,17 @@ impl RaceDistance {
         }
     }
 
+    /// Distance belonging to the nth (starting at 1) held time of a race. Past the last split, the finish is assumed
+    pub fn get_distance_at_hold(&self, nr_of_hold: usize) -> Option<u32> {
+        let split_distances = self.get_split_distances()?;
+
+        if nr_of_hold >= split_distances.len() + 1 {
+            Some(self.get_distance_as_number())
+        } else {
+            split_distances.get(nr_of_hold.checked_sub(1)?).copied()
+        }
+    }
+
     fn get_time_continues_running(&self) -> bool {
         match self {
             Self::Sprint15Meters
@@ -317,6 +329,7 @@ pub struct TimingStateMachine {
     race_finished: bool,
     run_display_entries: Vec<(i64, DisplayEntry)>,
     race_wind: Option<RaceWind>,
+    splits: Vec<(Option<u32>, RaceTime)>,
 }
 impl TimingStateMachine {
     pub fn new(
@@ -338,6 +351,7 @@ impl TimingStateMachine {
             timing_mode: TimingMode::StartList(TableMetaStorage::new()),
             run_display_entries: Vec::new(), // TODO limit this. It will overflow someday
             race_wind: None,
+            splits: Vec::new(),
         }
     }
 
@@ -449,6 +463,7 @@ impl TimingStateMachine {
                     self.race_wind = None; // make sure, to clear this
                     self.race_finished = false;
                     self.time_held_counter = 0;
+                    self.splits.clear();
                     match &mut self.meta {
                         Some(meta) => {
                             meta.result = None;
@@ -551,20 +566,14 @@ impl TimingStateMachine {
         self.timing_state = TimingState::Held;
         self.time_held_counter += 1;
 
-        let mut held_at_m = None; // TODO make possibility to re-set this and overwrite the split times etc.
-        if let Some(meta) = &self.meta {
-            if let Some(split_distances) = meta.distance.get_split_distances() {
-                if self.time_held_counter as usize >= split_distances.len() + 1 {
-                    held_at_m = Some(meta.distance.get_distance_as_number())
-                } else {
-                    if let Some(split_distance) =
-                        split_distances.get(self.time_held_counter as usize - 1)
-                    {
-                        held_at_m = Some(*split_distance);
-                    }
-                }
-            }
-        }
+        // TODO make possibility to re-set this and overwrite the split times etc.
+        let held_at_m = match &self.meta {
+            Some(meta) => meta
+                .distance
+                .get_distance_at_hold(self.time_held_counter as usize),
+            None => None,
+        };
+        self.splits.push((held_at_m, race_time.clone()));
 
         self.held_time_state = Some(HeldTimeState {
             settings: self.settings.clone(),
@@ -610,9 +619,25 @@ impl TimingStateMachine {
         return self.race_finished;
     }
 
+    /// With the "w" marker, if the heat is wind relevant and the wind was too strong
     pub fn race_wind(&self) -> Option<String> {
+        let distance = self
+            .meta
+            .as_ref()
+            .and_then(|m| m.start_list.as_ref())
+            .map(|l| l.distance_meters);
+
         match &self.race_wind {
-            Some(rw) => Some(rw.to_string()),
+            Some(rw) => {
+                let legality = WindLegality::evaluate(
+                    Some(rw),
+                    distance.is_some_and(wind_relevant_for_distance),
+                );
+                match legality {
+                    WindLegality::WindAided => Some(format!("{} {}", rw, legality.marker())),
+                    _ => Some(rw.to_string()),
+                }
+            }
             None => None,
         }
     }
@@ -643,6 +668,26 @@ impl TimingStateMachine {
         }
     }
 
+    /// All held times of the current race as "800m 2:04.31", the most recent first
+    pub fn get_split_texts(&self) -> Vec<String> {
+        self.splits
+            .iter()
+            .rev()
+            .map(|(held_at_m, race_time)| {
+                let time_string = race_time
+                    .optimize_representation_for_display(Some(
+                        self.settings.max_decimal_places_after_comma,
+                    ))
+                    .to_string();
+
+                match held_at_m {
+                    Some(distance) => format!("{}m {}", distance, time_string),
+                    None => time_string,
+                }
+            })
+            .collect()
+    }
+
     pub fn play_animation_over_top(&mut self, anim: AnimationPlayer) {
         self.over_top_animation = Some(anim);
     }
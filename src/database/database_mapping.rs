@@ -1,25 +1,34 @@
 // to avoid mapping this into the database for real (as we only basically process the datatypes in rust and make really no computations on the database) we just store everything serealized
 // TODO sqlite has a json type for efficiency
 
+#[cfg(test)]
+mod import_tests;
+
 use std::collections::HashMap;
 
 use crate::database::db::DatabaseError;
 use crate::database::schema::{
-    athletes, bib_data_points, bib_equivalences, database_state, heat_assignments,
-    heat_evaluations, heat_false_starts, heat_finishes, heat_intermediates, heat_results,
-    heat_start_lists, heat_starts, heat_wind_missings, heat_winds, internal_wind_measurements,
-    internal_wind_readings, pdf_settings, permanent_storage,
+    athletes, bib_data_points, bib_equivalences, competition_events, database_state,
+    field_event_attempts, field_events, heat_assignments, heat_evaluations, heat_false_starts,
+    heat_finishes, heat_intermediates, heat_results, heat_splits, heat_start_lists, heat_starts,
+    heat_wind_missings, heat_wind_server_winds, heat_winds, internal_wind_measurements,
+    internal_wind_readings, pdf_backgrounds, pdf_settings, permanent_storage,
 };
 use crate::database::DatabaseManager;
 use crate::productkey::today;
 use crate::server::bib_detection::{BibDataPoint, BibEquivalence, DisplayEntry};
 use crate::server::camera_program_types::{
-    Athlete, AthleteWithMetadata, CompetitorEvaluated, HeatAssignment, HeatData, HeatFalseStart,
-    HeatFinish, HeatIntermediate, HeatResult, HeatStart, HeatStartList, HeatWind, HeatWindMissing,
+    Athlete, AthleteWithMetadata, CompetitionEvent, CompetitorEvaluated, HeatAssignment, HeatData,
+    HeatFalseStart, HeatFinish, HeatIntermediate, HeatResult, HeatSplit, HeatSplits, HeatStart,
+    HeatStartList, HeatWind, HeatWindMissing, HeatWindServerWind, RelayTeam,
 };
 use crate::server::export_functions::MAIN_HEAT_KEY;
+use crate::server::field_events::{FieldEvent, FieldEventAttempt};
 use crate::times::DayTime;
-use crate::webserver::PDFConfigurationSetting;
+use crate::webserver::{
+    AthleteImportConflict, AthleteImportReport, PDFBackground, PDFConfigurationSetting,
+    PDFSettingFor,
+};
 use crate::wind::format::{StartedWindMeasurement, WindMeasurement};
 use chrono::Utc;
 use chrono::{Local, NaiveDate, NaiveDateTime, NaiveTime};
@@ -38,6 +47,12 @@ pub trait DatabaseSerializable: Serialize + for<'a> Deserialize<'a> {
 
     fn store_to_database(self, manager: &DatabaseManager) -> Result<(), DatabaseError>;
 
+    /// Same as store_to_database, but on a given connection (e.g. to store inside of a transaction)
+    fn store_to_database_with_connection(
+        self,
+        conn: &mut SqliteConnection,
+    ) -> Result<(), DatabaseError>;
+
     fn get_from_database_by_id(id: Uuid, manager: &DatabaseManager) -> Result<Self, DatabaseError>;
 
     fn get_all_from_database(manager: &DatabaseManager) -> Result<Vec<Self>, DatabaseError>;
@@ -63,13 +78,20 @@ macro_rules! impl_database_serializable {
 
             fn store_to_database(self, manager: &DatabaseManager) -> Result<(), DatabaseError> {
                 let mut conn = manager.get_connection()?;
+                self.store_to_database_with_connection(&mut conn)
+            }
+
+            fn store_to_database_with_connection(
+                self,
+                conn: &mut SqliteConnection,
+            ) -> Result<(), DatabaseError> {
                 let db_model = self.serialize_for_database()?;
                 diesel::insert_into(<$table>::table())
                     .values(&db_model)
                     .on_conflict($id)
                     .do_update()
                     .set(&db_model)
-                    .execute(&mut conn)?;
+                    .execute(conn)?;
                 // permanent storage
                 let name = String::from(
                     std::any::type_name::<$domain>()
@@ -86,7 +108,7 @@ macro_rules! impl_database_serializable {
                 diesel::insert_into(permanent_storage::table::table())
                     .values(&perm)
                     .on_conflict_do_nothing()
-                    .execute(&mut conn)?;
+                    .execute(conn)?;
 
                 Ok(())
             }
@@ -194,6 +216,23 @@ impl_database_serializable!(
     })
 );
 
+#[derive(Insertable, Queryable, Identifiable, AsChangeset)]
+#[diesel(table_name = heat_splits)]
+pub struct HeatSplitsDatabase {
+    id: String,
+    data: String,
+}
+impl_database_serializable!(
+    HeatSplits,
+    HeatSplitsDatabase,
+    heat_splits::table,
+    heat_splits::id,
+    |self_obj: &HeatSplits| Ok(HeatSplitsDatabase {
+        id: self_obj.id.to_string(),
+        data: serde_json::to_string(self_obj)?,
+    })
+);
+
 #[derive(Insertable, Queryable, Identifiable, AsChangeset)]
 #[diesel(table_name = heat_winds)]
 pub struct HeatWindDatabase {
@@ -211,6 +250,23 @@ impl_database_serializable!(
     })
 );
 
+#[derive(Insertable, Queryable, Identifiable, AsChangeset)]
+#[diesel(table_name = heat_wind_server_winds)]
+pub struct HeatWindServerWindDatabase {
+    id: String,
+    data: String,
+}
+impl_database_serializable!(
+    HeatWindServerWind,
+    HeatWindServerWindDatabase,
+    heat_wind_server_winds::table,
+    heat_wind_server_winds::id,
+    |self_obj: &HeatWindServerWind| Ok(HeatWindServerWindDatabase {
+        id: self_obj.id.to_string(),
+        data: serde_json::to_string(self_obj)?,
+    })
+);
+
 #[derive(Insertable, Queryable, Identifiable, AsChangeset)]
 #[diesel(table_name = heat_wind_missings)]
 pub struct HeatWindMissingDatabase {
@@ -482,18 +538,41 @@ pub fn get_log_limited(
         .collect::<Vec<PermanentlyStoredDataset>>())
 }
 
-pub fn get_heat_data(id: Uuid, manager: &DatabaseManager) -> Result<HeatData, DatabaseError> {
+fn get_heat_intermediates(
+    id: Uuid,
+    manager: &DatabaseManager,
+) -> Result<Vec<HeatIntermediate>, DatabaseError> {
     let mut conn = manager.get_connection()?;
-    let start_list = HeatStartList::get_from_database_by_id(id, manager)?;
-    let id_str = id.to_string();
-
     let data_intermediates = heat_intermediates::table::table()
-        .filter(heat_intermediates::belongs_to_id.eq(id_str.clone()))
+        .filter(heat_intermediates::belongs_to_id.eq(id.to_string()))
         .load::<HeatIntermediateDatabase>(&mut conn)?;
-    let intermediates_collected = data_intermediates
+
+    Ok(data_intermediates
         .into_iter()
         .filter_map(|h| HeatIntermediate::try_from(h).ok())
-        .collect::<Vec<HeatIntermediate>>();
+        .collect::<Vec<HeatIntermediate>>())
+}
+
+/// The splits from all intermediates of the heat so far, to be stored after a new intermediate was stored
+pub fn compute_heat_splits(
+    id: Uuid,
+    manager: &DatabaseManager,
+) -> Result<HeatSplits, DatabaseError> {
+    let start_list = HeatStartList::get_from_database_by_id(id, manager)?;
+    let intermediates = get_heat_intermediates(id, manager)?;
+
+    Ok(HeatSplits {
+        id,
+        splits: HeatSplit::from_intermediates(start_list.distance_meters, &intermediates),
+    })
+}
+
+pub fn get_heat_data(id: Uuid, manager: &DatabaseManager) -> Result<HeatData, DatabaseError> {
+    let mut conn = manager.get_connection()?;
+    let start_list = HeatStartList::get_from_database_by_id(id, manager)?;
+    let id_str = id.to_string();
+
+    let intermediates_collected = get_heat_intermediates(id, manager)?;
     let data_evaluations = heat_evaluations::table::table()
         .filter(heat_evaluations::belongs_to_id.eq(id_str))
         .load::<HeatEvaluationDatabase>(&mut conn)?;
@@ -507,11 +586,17 @@ pub fn get_heat_data(id: Uuid, manager: &DatabaseManager) -> Result<HeatData, Da
     let heat_finish = HeatFinish::get_from_database_by_id(id, manager).ok();
     let heat_result = HeatResult::get_from_database_by_id(id, manager).ok();
     let heat_wind = HeatWind::get_from_database_by_id(id, manager).ok();
+    let wind_server_wind = HeatWindServerWind::get_from_database_by_id(id, manager).ok();
+    let splits = HeatSplits::get_from_database_by_id(id, manager)
+        .ok()
+        .map(|s| s.splits)
+        .filter(|s| !s.is_empty());
 
     return Ok(HeatData {
         meta: start_list.clone().into(),
         start_list: start_list,
         start: heat_start,
+        splits: splits,
         intermediates: if intermediates_collected.is_empty() {
             None
         } else {
@@ -525,10 +610,11 @@ pub fn get_heat_data(id: Uuid, manager: &DatabaseManager) -> Result<HeatData, Da
         finish: heat_finish,
         result: heat_result,
         wind: heat_wind,
+        wind_server_wind,
     });
 }
 
-/// clear starts, intermediates, finish, results, winds, wind_missings, evaluations
+/// clear starts, intermediates, splits, finish, results, winds, wind server winds, wind_missings, evaluations
 pub fn purge_heat_data(id: Uuid, manager: &DatabaseManager) -> Result<(), DatabaseError> {
     let mut conn = manager.get_connection()?;
 
@@ -540,6 +626,8 @@ pub fn purge_heat_data(id: Uuid, manager: &DatabaseManager) -> Result<(), Databa
             .filter(heat_intermediates::belongs_to_id.eq(id.to_string())),
     )
     .execute(&mut conn)?;
+    diesel::delete(heat_splits::table::table().filter(heat_splits::id.eq(id.to_string())))
+        .execute(&mut conn)?;
     diesel::delete(heat_finishes::table::table().filter(heat_finishes::id.eq(id.to_string())))
         .execute(&mut conn)?;
     diesel::delete(heat_results::table::table().filter(heat_results::id.eq(id.to_string())))
@@ -550,6 +638,11 @@ pub fn purge_heat_data(id: Uuid, manager: &DatabaseManager) -> Result<(), Databa
         heat_wind_missings::table::table().filter(heat_wind_missings::id.eq(id.to_string())),
     )
     .execute(&mut conn)?;
+    diesel::delete(
+        heat_wind_server_winds::table::table()
+            .filter(heat_wind_server_winds::id.eq(id.to_string())),
+    )
+    .execute(&mut conn)?;
     diesel::delete(
         heat_evaluations::table::table().filter(heat_evaluations::belongs_to_id.eq(id.to_string())),
     )
@@ -714,6 +807,72 @@ pub fn delete_athlete(id: Uuid, manager: &DatabaseManager) -> Result<(), Databas
     Ok(())
 }
 
+/// An imported bib must neither be used by another stored athlete, nor twice in the import itself
+fn athlete_import_conflicts(
+    existing: &Vec<Athlete>,
+    importing: &Vec<Athlete>,
+) -> Vec<AthleteImportConflict> {
+    let mut conflicts = Vec::new();
+
+    for (i, athlete) in importing.iter().enumerate() {
+        if let Some(other) = existing
+            .iter()
+            .find(|a| a.bib == athlete.bib && a.id != athlete.id)
+        {
+            conflicts.push(AthleteImportConflict {
+                row: i as u32 + 1,
+                bib: athlete.bib,
+                reason: format!(
+                    "Bib is already used by {} {}",
+                    other.first_name, other.last_name
+                ),
+            });
+        } else if importing[..i].iter().any(|a| a.bib == athlete.bib) {
+            conflicts.push(AthleteImportConflict {
+                row: i as u32 + 1,
+                bib: athlete.bib,
+                reason: String::from("Bib is used more than once in the import"),
+            });
+        }
+    }
+
+    conflicts
+}
+
+/// Stores all athletes in one transaction. If there are conflicts, nothing gets stored
+pub fn import_athletes(
+    athletes: Vec<Athlete>,
+    manager: &DatabaseManager,
+) -> Result<AthleteImportReport, DatabaseError> {
+    let mut conn = manager.get_connection()?;
+
+    conn.transaction::<AthleteImportReport, DatabaseError, _>(|conn| {
+        let existing = athletes::table::table()
+            .load::<AthleteDatabase>(conn)?
+            .into_iter()
+            .map(|a| Athlete::try_from(a))
+            .collect::<Result<Vec<Athlete>, DatabaseError>>()?;
+
+        let conflicts = athlete_import_conflicts(&existing, &athletes);
+        if !conflicts.is_empty() {
+            return Ok(AthleteImportReport {
+                imported: 0,
+                conflicts,
+            });
+        }
+
+        let imported = athletes.len() as u32;
+        for athlete in athletes {
+            athlete.store_to_database_with_connection(conn)?;
+        }
+
+        Ok(AthleteImportReport {
+            imported,
+            conflicts,
+        })
+    })
+}
+
 /// this must have a special format, because we require being able to sort by creation (time/order)
 #[derive(Serialize, Deserialize)]
 struct HeatAssignmentDatabaseContent {
@@ -721,6 +880,8 @@ struct HeatAssignmentDatabaseContent {
     heat_id: Uuid,
     heat_descriminator: u8,
     athlete_ids: HashMap<u32, Uuid>,
+    #[serde(default)]
+    relay_teams: HashMap<u32, RelayTeam>,
 }
 
 /// ignores id and heat_id
@@ -761,6 +922,7 @@ pub fn create_heat_assignment(
             distance: deserealized.distance,
             heat_descriminator: deserealized.heat_descriminator,
             athlete_ids: deserealized.athlete_ids,
+            relay_teams: deserealized.relay_teams,
         })
     } else {
         Err(DatabaseError::new("Nothing was inserted...".into()))
@@ -791,6 +953,7 @@ pub fn get_all_heat_assignments(
                 distance: dat.distance,
                 heat_descriminator: dat.heat_descriminator,
                 athlete_ids: dat.athlete_ids,
+                relay_teams: dat.relay_teams,
             })
         })
         .collect::<Result<Vec<HeatAssignment>, DatabaseError>>()
@@ -816,6 +979,7 @@ pub fn get_all_athletes_meta_data(
 ) -> Result<Vec<AthleteWithMetadata>, DatabaseError> {
     let athletes = Athlete::get_all_from_database(manager)?;
     let heat_assignments = get_all_heat_assignments(manager)?;
+    let bibs: HashMap<Uuid, u32> = athletes.iter().map(|a| (a.id, a.bib)).collect();
 
     let mut res: Vec<AthleteWithMetadata> = Vec::new();
     for athlete in athletes {
@@ -823,17 +987,22 @@ pub fn get_all_athletes_meta_data(
         let mut heat_assignments_ath = Vec::new();
 
         for heat_assignment in &heat_assignments {
-            if heat_assignment
-                .athlete_ids
-                .iter()
-                .any(|(_, v)| *v == athlete.id)
-            {
+            if heat_assignment.contains_athlete(&athlete.id) {
                 heat_assignments_ath.push(heat_assignment.clone());
 
+                // relay teams run under the bib of their first member
+                let bib_in_heat = heat_assignment
+                    .relay_teams
+                    .values()
+                    .find(|team| team.member_ids.contains(&athlete.id))
+                    .and_then(|team| team.member_ids.first())
+                    .and_then(|id| bibs.get(id).cloned())
+                    .unwrap_or(athlete.bib);
+
                 match get_heat_data(heat_assignment.heat_id.clone(), manager) {
                     Ok(data) => {
                         let their_result = match &data.result {
-                            Some(r) => match r.competitors_evaluated.iter().find(|e|e.competitor.bib == athlete.bib) {
+                            Some(r) => match r.competitors_evaluated.iter().find(|e|e.competitor.bib == bib_in_heat) {
                                 None => None,
                                 Some(hcr) => Some(hcr.clone())
                             }
@@ -896,6 +1065,179 @@ pub fn delete_pdf_setting(id: Uuid, manager: &DatabaseManager) -> Result<(), Dat
     Ok(())
 }
 
+#[derive(Insertable, Queryable, Identifiable, AsChangeset)]
+#[diesel(table_name = pdf_backgrounds)]
+struct PDFBackgroundDatabase {
+    id: String,
+    data: String,
+}
+
+fn pdf_background_id(setting_for: &PDFSettingFor) -> String {
+    format!("{:?}", setting_for)
+}
+
+/// Not DatabaseSerializable on purpose: a copy of every uploaded image in the permanent storage would bloat the logs
+pub fn store_pdf_background(
+    background: &PDFBackground,
+    manager: &DatabaseManager,
+) -> Result<(), DatabaseError> {
+    let mut conn = manager.get_connection()?;
+    let db_model = PDFBackgroundDatabase {
+        id: pdf_background_id(&background.setting_for),
+        data: serde_json::to_string(background)?,
+    };
+
+    diesel::insert_into(pdf_backgrounds::table::table())
+        .values(&db_model)
+        .on_conflict(pdf_backgrounds::id)
+        .do_update()
+        .set(&db_model)
+        .execute(&mut conn)?;
+
+    Ok(())
+}
+
+pub fn get_pdf_background(
+    setting_for: &PDFSettingFor,
+    manager: &DatabaseManager,
+) -> Result<Option<PDFBackground>, DatabaseError> {
+    let mut conn = manager.get_connection()?;
+    let data = pdf_backgrounds::table::table()
+        .filter(pdf_backgrounds::id.eq(pdf_background_id(setting_for)))
+        .first::<PDFBackgroundDatabase>(&mut conn)
+        .optional()?;
+
+    match data {
+        Some(data) => Ok(Some(serde_json::from_str(&data.data)?)),
+        None => Ok(None),
+    }
+}
+
+pub fn delete_pdf_background(
+    setting_for: &PDFSettingFor,
+    manager: &DatabaseManager,
+) -> Result<(), DatabaseError> {
+    let mut conn = manager.get_connection()?;
+
+    diesel::delete(
+        pdf_backgrounds::table::table()
+            .filter(pdf_backgrounds::id.eq(pdf_background_id(setting_for))),
+    )
+    .execute(&mut conn)?;
+
+    Ok(())
+}
+
+#[derive(Insertable, Queryable, Identifiable, AsChangeset)]
+#[diesel(table_name = competition_events)]
+pub struct CompetitionEventDatabase {
+    id: String,
+    data: String,
+}
+impl_database_serializable!(
+    CompetitionEvent,
+    CompetitionEventDatabase,
+    competition_events::table,
+    competition_events::id,
+    |self_obj: &CompetitionEvent| Ok(CompetitionEventDatabase {
+        id: self_obj.id.to_string(),
+        data: serde_json::to_string(self_obj)?,
+    })
+);
+
+pub fn delete_competition_event(id: Uuid, manager: &DatabaseManager) -> Result<(), DatabaseError> {
+    let mut conn = manager.get_connection()?;
+
+    diesel::delete(
+        competition_events::table::table().filter(competition_events::id.eq(id.to_string())),
+    )
+    .execute(&mut conn)?;
+
+    Ok(())
+}
+
+#[derive(Insertable, Queryable, Identifiable, AsChangeset)]
+#[diesel(table_name = field_events)]
+pub struct FieldEventDatabase {
+    id: String,
+    data: String,
+}
+impl_database_serializable!(
+    FieldEvent,
+    FieldEventDatabase,
+    field_events::table,
+    field_events::id,
+    |self_obj: &FieldEvent| Ok(FieldEventDatabase {
+        id: self_obj.id.to_string(),
+        data: serde_json::to_string(self_obj)?,
+    })
+);
+
+#[derive(Insertable, Queryable, Identifiable, AsChangeset)]
+#[diesel(table_name = field_event_attempts)]
+pub struct FieldEventAttemptDatabase {
+    id: String,
+    belongs_to_id: String,
+    data: String,
+}
+impl_database_serializable!(
+    FieldEventAttempt,
+    FieldEventAttemptDatabase,
+    field_event_attempts::table,
+    field_event_attempts::id,
+    |self_obj: &FieldEventAttempt| Ok(FieldEventAttemptDatabase {
+        id: self_obj.id.to_string(),
+        belongs_to_id: self_obj.event_id.to_string(),
+        data: serde_json::to_string(self_obj)?,
+    })
+);
+
+pub fn get_field_event_attempts(
+    event_id: Uuid,
+    manager: &DatabaseManager,
+) -> Result<Vec<FieldEventAttempt>, DatabaseError> {
+    let mut conn = manager.get_connection()?;
+
+    let attempts_db = field_event_attempts::table::table()
+        .filter(field_event_attempts::belongs_to_id.eq(event_id.to_string()))
+        .load::<FieldEventAttemptDatabase>(&mut conn)?;
+
+    attempts_db
+        .into_iter()
+        .map(FieldEventAttempt::try_from)
+        .collect()
+}
+
+pub fn delete_field_event_attempt(
+    id: Uuid,
+    manager: &DatabaseManager,
+) -> Result<(), DatabaseError> {
+    let mut conn = manager.get_connection()?;
+
+    diesel::delete(
+        field_event_attempts::table::table().filter(field_event_attempts::id.eq(id.to_string())),
+    )
+    .execute(&mut conn)?;
+
+    Ok(())
+}
+
+/// Also removes all attempts of the event
+pub fn delete_field_event(id: Uuid, manager: &DatabaseManager) -> Result<(), DatabaseError> {
+    let mut conn = manager.get_connection()?;
+
+    conn.transaction::<_, DatabaseError, _>(|conn| {
+        diesel::delete(
+            field_event_attempts::table::table()
+                .filter(field_event_attempts::belongs_to_id.eq(id.to_string())),
+        )
+        .execute(conn)?;
+        diesel::delete(field_events::table::table().filter(field_events::id.eq(id.to_string())))
+            .execute(conn)?;
+        Ok(())
+    })
+}
+
 pub fn delete_evaluation(
     finish_time: DayTime,
     manager: &DatabaseManager,
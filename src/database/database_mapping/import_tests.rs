@@ -0,0 +1,82 @@
+//! Bib conflicts of the bulk athlete import, checked before anything is stored
+
+use super::athlete_import_conflicts;
+use crate::server::camera_program_types::{Athlete, Gender};
+use uuid::Uuid;
+
+fn athlete(bib: u32, last_name: &str) -> Athlete {
+    Athlete {
+        id: Uuid::new_v4(),
+        gender: Gender::Female,
+        bib,
+        club: String::new(),
+        first_name: String::from("Eva"),
+        last_name: String::from(last_name),
+        nation: String::from("GER"),
+        spk_guess: None,
+        street_run_rounds: None,
+        birth_date: None,
+    }
+}
+
+#[test]
+fn no_conflicts() {
+    let existing = vec![athlete(1, "Stored")];
+    let importing = vec![athlete(2, "New"), athlete(3, "Other")];
+
+    assert!(athlete_import_conflicts(&existing, &importing).is_empty());
+    assert!(athlete_import_conflicts(&Vec::new(), &Vec::new()).is_empty());
+}
+
+#[test]
+fn bib_of_a_stored_athlete() {
+    let existing = vec![athlete(1, "Stored"), athlete(7, "Meyer")];
+    let importing = vec![athlete(2, "New"), athlete(7, "Other")];
+
+    let conflicts = athlete_import_conflicts(&existing, &importing);
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].row, 2);
+    assert_eq!(conflicts[0].bib, 7);
+    assert_eq!(conflicts[0].reason, "Bib is already used by Eva Meyer");
+}
+
+#[test]
+fn reimporting_the_same_athlete_is_no_conflict() {
+    let stored = athlete(7, "Meyer");
+    let mut updated = stored.clone();
+    updated.club = String::from("LG Nord");
+
+    assert!(athlete_import_conflicts(&vec![stored], &vec![updated]).is_empty());
+}
+
+#[test]
+fn bib_twice_in_the_import() {
+    let importing = vec![
+        athlete(4, "First"),
+        athlete(5, "Second"),
+        athlete(4, "Third"),
+        athlete(4, "Fourth"),
+    ];
+
+    // the first one keeps the bib, every later one is reported
+    let conflicts = athlete_import_conflicts(&Vec::new(), &importing);
+    assert_eq!(
+        conflicts.iter().map(|c| c.row).collect::<Vec<_>>(),
+        vec![3, 4]
+    );
+    assert!(conflicts
+        .iter()
+        .all(|c| c.bib == 4 && c.reason == "Bib is used more than once in the import"));
+}
+
+#[test]
+fn stored_bib_is_reported_before_the_duplicate() {
+    let existing = vec![athlete(9, "Stored")];
+    let importing = vec![athlete(9, "First"), athlete(9, "Second")];
+
+    let conflicts = athlete_import_conflicts(&existing, &importing);
+    assert_eq!(conflicts.len(), 2);
+    assert!(conflicts
+        .iter()
+        .all(|c| c.reason == "Bib is already used by Eva Stored"));
+}
@@ -0,0 +1 @@
+DROP TABLE competition_events;
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE field_events;
\ No newline at end of file
@@ -0,0 +1,4 @@
+CREATE TABLE field_events (
+  id VARCHAR NOT NULL PRIMARY KEY,
+  data VARCHAR NOT NULL
+);
\ No newline at end of file
@@ -0,0 +1,3 @@
+DROP INDEX idx_field_event_attempts_belongs_to_id;
+
+DROP TABLE field_event_attempts;
\ No newline at end of file
@@ -0,0 +1,8 @@
+CREATE TABLE field_event_attempts (
+  id VARCHAR NOT NULL PRIMARY KEY,
+  belongs_to_id VARCHAR NOT NULL,
+  data VARCHAR NOT NULL
+);
+
+CREATE INDEX idx_field_event_attempts_belongs_to_id
+ON field_event_attempts(belongs_to_id);
\ No newline at end of file
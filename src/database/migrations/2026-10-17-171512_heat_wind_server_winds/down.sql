@@ -0,0 +1 @@
+DROP TABLE heat_wind_server_winds;
\ No newline at end of file
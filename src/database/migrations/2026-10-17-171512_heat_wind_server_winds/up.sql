@@ -0,0 +1,4 @@
+CREATE TABLE heat_wind_server_winds (
+  id VARCHAR NOT NULL PRIMARY KEY,
+  data VARCHAR NOT NULL
+);
\ No newline at end of file
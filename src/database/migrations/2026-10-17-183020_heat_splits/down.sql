@@ -0,0 +1 @@
+DROP TABLE heat_splits;
\ No newline at end of file
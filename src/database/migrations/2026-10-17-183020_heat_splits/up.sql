@@ -0,0 +1,4 @@
+CREATE TABLE heat_splits (
+  id VARCHAR NOT NULL PRIMARY KEY,
+  data VARCHAR NOT NULL
+);
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE pdf_backgrounds;
\ No newline at end of file
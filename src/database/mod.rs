@@ -3,11 +3,13 @@ mod db;
 mod schema;
 
 pub use database_mapping::{
-    create_heat_assignment, delete_athlete, delete_bib_equivalence, delete_evaluation,
-    delete_heat_assignment, delete_pdf_setting, get_all_athletes_meta_data,
-    get_all_heat_assignments, get_bib_data, get_database_static_state, get_heat_data,
-    get_log_limited, get_main_heat, get_wind_readings, init_database_static_state,
-    populate_display_from_bib, purge_heat_data, ApplicationMode, DatabaseSerializable,
-    DatabaseStaticState, PermanentlyStoredDataset,
+    compute_heat_splits, create_heat_assignment, delete_athlete, delete_bib_equivalence,
+    delete_competition_event, delete_evaluation, delete_field_event, delete_field_event_attempt,
+    delete_heat_assignment, delete_pdf_background, delete_pdf_setting, get_all_athletes_meta_data,
+    get_all_heat_assignments, get_bib_data, get_database_static_state, get_field_event_attempts,
+    get_heat_data, get_log_limited, get_main_heat, get_pdf_background, get_wind_readings,
+    import_athletes, init_database_static_state, populate_display_from_bib, purge_heat_data,
+    store_pdf_background, ApplicationMode, DatabaseSerializable, DatabaseStaticState,
+    PermanentlyStoredDataset,
 };
-pub use db::DatabaseManager;
+pub use db::{DatabaseError, DatabaseManager};
@@ -23,6 +23,13 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    competition_events (id) {
+        id -> Text,
+        data -> Text,
+    }
+}
+
 diesel::table! {
     database_state (id) {
         id -> Integer,
@@ -31,6 +38,21 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    field_event_attempts (id) {
+        id -> Text,
+        belongs_to_id -> Text,
+        data -> Text,
+    }
+}
+
+diesel::table! {
+    field_events (id) {
+        id -> Text,
+        data -> Text,
+    }
+}
+
 diesel::table! {
     heat_assignments (id) {
         id -> Integer,
@@ -75,6 +97,13 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    heat_splits (id) {
+        id -> Text,
+        data -> Text,
+    }
+}
+
 diesel::table! {
     heat_start_lists (id) {
         id -> Text,
@@ -96,6 +125,13 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    heat_wind_server_winds (id) {
+        id -> Text,
+        data -> Text,
+    }
+}
+
 diesel::table! {
     heat_winds (id) {
         id -> Text,
@@ -121,6 +157,13 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    pdf_backgrounds (id) {
+        id -> Text,
+        data -> Text,
+    }
+}
+
 diesel::table! {
     pdf_settings (id) {
         id -> Text,
@@ -138,4 +181,4 @@ diesel::table! {
 }
 
 diesel::allow_tables_to_appear_in_same_query!(
-    athletes,bib_data_points,bib_equivalences,database_state,heat_assignments,heat_evaluations,heat_false_starts,heat_finishes,heat_intermediates,heat_results,heat_start_lists,heat_starts,heat_wind_missings,heat_winds,internal_wind_measurements,internal_wind_readings,pdf_settings,permanent_storage,);
+    athletes,bib_data_points,bib_equivalences,competition_events,database_state,field_event_attempts,field_events,heat_assignments,heat_evaluations,heat_false_starts,heat_finishes,heat_intermediates,heat_results,heat_splits,heat_start_lists,heat_starts,heat_wind_missings,heat_wind_server_winds,heat_winds,internal_wind_measurements,internal_wind_readings,pdf_backgrounds,pdf_settings,permanent_storage,);
@@ -53,12 +53,15 @@ pub fn read_image_files(folder_path: &Path) -> Result<Vec<(String, Vec<u8>)>, St
     Ok(image_files)
 }
 
-pub fn create_file_if_not_there_and_write(path: &Path, content: &str) -> Result<(), String> {
+pub fn create_file_if_not_there_and_write<T: AsRef<[u8]>>(
+    path: &Path,
+    content: T,
+) -> Result<(), String> {
     match std::fs::File::create(path) {
         Err(e) => Err(format!("Could not create file: {}", e.to_string())),
         Ok(mut file) => {
             set_perms(path);
-            match write!(file, "{}", content) {
+            match file.write_all(content.as_ref()) {
                 Ok(()) => Ok(()),
                 Err(e) => Err(format!("Could not write to file: {}", e.to_string())),
             }
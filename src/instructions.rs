@@ -7,20 +7,21 @@ use crate::{
             CompetitorEvaluated, HeatFalseStart, HeatFinish, HeatIntermediate, HeatResult,
             HeatStart, HeatStartList, HeatWind, HeatWindMissing,
         },
+        timing_program_types::TimingProgramProperty,
     },
     times::{DayTime, RaceTime},
-    webserver::MessageFromWebControl,
+    webserver::{MessageFromWebControl, WebControlRole},
     wind::format::WindMessageBroadcast,
 };
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
 pub enum IncomingInstruction {
-    FromClient(MessageFromClientToServer),
+    FromClient(String, MessageFromClientToServer), // name of the display client
     FromTimingProgram(InstructionFromTimingProgram),
     FromExternalDisplayProgram(InstructionFromExternalDisplayProgram),
     FromCameraProgram(InstructionFromCameraProgram),
-    FromWebControl(MessageFromWebControl),
+    FromWebControl(WebControlRole, MessageFromWebControl), // role of the sending session
     FromWindServer(WindMessageBroadcast),
     FromBibServer(MessageFromBibServer),
     FromIdcaptureServer(IDCaptureMessage),
@@ -31,14 +32,16 @@ impl Display for IncomingInstruction {
             f,
             "{}",
             match self {
-                IncomingInstruction::FromClient(mfcts) => format!("FromClient: {}", mfcts),
+                IncomingInstruction::FromClient(name, mfcts) =>
+                    format!("FromClient ({}): {}", name, mfcts),
                 IncomingInstruction::FromTimingProgram(tci) =>
                     format!("FromTimingProgram: {}", tci),
                 IncomingInstruction::FromExternalDisplayProgram(dsi) =>
                     format!("FromExternalDisplayProgram: {}", dsi),
                 IncomingInstruction::FromCameraProgram(cpi) =>
                     format!("FromCameraProgram: {:?}", cpi),
-                IncomingInstruction::FromWebControl(wci) => format!("FromWebControl: {:?}", wci),
+                IncomingInstruction::FromWebControl(role, wci) =>
+                    format!("FromWebControl ({:?}): {:?}", role, wci),
                 IncomingInstruction::FromWindServer(wmb) => format!("FromWindServer: {:?}", wmb),
                 IncomingInstruction::FromBibServer(bm) => format!("FromBibServer: {:?}", bm),
                 IncomingInstruction::FromIdcaptureServer(idcm) =>
@@ -64,19 +67,21 @@ pub enum InstructionFromCameraProgram {
     HeatFinish(HeatFinish),
     CompetitorEvaluated(CompetitorEvaluated),
     HeatResult(HeatResult),
+    /// Data that was already there before we started reading it (lynx folder), it is only stored
+    Backfill(Box<InstructionFromCameraProgram>),
 }
 
 #[derive(Serialize, Deserialize)]
 pub enum InstructionFromTimingProgram {
     ClientInfo,
     Freetext(String),
-    Advertisements,
+    Advertisements(Vec<TimingProgramProperty>),
     Clear,
     StartList,
     Timing,
-    SetProperty,
-    Results,
-    ResultsUpdate,
+    SetProperty(Vec<TimingProgramProperty>),
+    Results(Vec<TimingProgramProperty>),
+    ResultsUpdate(Vec<TimingProgramProperty>),
 }
 impl Display for InstructionFromTimingProgram {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -86,13 +91,16 @@ impl Display for InstructionFromTimingProgram {
             match self {
                 InstructionFromTimingProgram::ClientInfo => String::from("ClientInfo"),
                 InstructionFromTimingProgram::Freetext(text) => format!("Freetext: {}", text),
-                InstructionFromTimingProgram::Advertisements => String::from("Advertisements"),
+                InstructionFromTimingProgram::Advertisements(_) => String::from("Advertisements"),
                 InstructionFromTimingProgram::Clear => String::from("Clear"),
                 InstructionFromTimingProgram::StartList => String::from("StartList"),
                 InstructionFromTimingProgram::Timing => String::from("Timing"),
-                InstructionFromTimingProgram::SetProperty => String::from("SetProperty"),
-                InstructionFromTimingProgram::Results => String::from("Results"),
-                InstructionFromTimingProgram::ResultsUpdate => String::from("ResultsUpdate"),
+                InstructionFromTimingProgram::SetProperty(properties) =>
+                    format!("SetProperty ({} properties)", properties.len()),
+                InstructionFromTimingProgram::Results(properties) =>
+                    format!("Results ({} properties)", properties.len()),
+                InstructionFromTimingProgram::ResultsUpdate(properties) =>
+                    format!("ResultsUpdate ({} properties)", properties.len()),
             }
         )
     }
@@ -1,9 +1,11 @@
 use crate::client::frametime::{FrametimeReport, FrametimeTracker};
 use crate::database::{
-    create_heat_assignment, delete_athlete, delete_bib_equivalence, delete_evaluation,
-    delete_heat_assignment, delete_pdf_setting, get_all_athletes_meta_data,
-    get_database_static_state, get_main_heat, init_database_static_state,
-    populate_display_from_bib, ApplicationMode, DatabaseStaticState,
+    compute_heat_splits, create_heat_assignment, delete_athlete, delete_bib_equivalence,
+    delete_competition_event, delete_evaluation, delete_field_event, delete_field_event_attempt,
+    delete_heat_assignment, delete_pdf_background, delete_pdf_setting, get_all_athletes_meta_data,
+    get_database_static_state, get_field_event_attempts, get_main_heat, import_athletes,
+    init_database_static_state, populate_display_from_bib, store_pdf_background, ApplicationMode,
+    DatabaseStaticState,
 };
 use crate::idcapture::format::IDCaptureMessage;
 use crate::instructions::InstructionFromExternalDisplayProgram::{Frame, ServerInfo};
@@ -16,18 +18,32 @@ use crate::server::bib_detection::{
     MessageToBibServer, RaceHasStartedBibServer, SeekForTimeBibServer,
 };
 use crate::server::camera_program_types::{
-    CompetitorEvaluated, HeatFalseStart, HeatFinish, HeatIntermediate, HeatResult, HeatStart,
-    HeatWind,
+    CompetitionEvent, CompetitorEvaluated, HeatFalseStart, HeatFinish, HeatIntermediate,
+    HeatResult, HeatStart, HeatWind, HeatWindMissing, HeatWindServerWind,
 };
 use crate::server::comm_channel::{ConnectionCheck, InstructionCommunicationChannel};
+use crate::server::display_clients::{
+    DisplayClient, DisplayClientConfig, DisplayClientMode, DisplayClientUpdate,
+};
 use crate::server::export_functions::{
-    fake_main_heat_start_list, generate_meet_data, write_to_xml_output_file,
+    fake_main_heat_start_list, generate_meet_data, start_lists_in_schedule_order,
+    write_lynx_schedule_files, write_pdf_exports, write_result_exports, write_to_xml_output_file,
+};
+use crate::server::field_events::{
+    FieldAttemptResult, FieldEvent, FieldEventAttempt, FieldEventData, FieldEventKind,
+};
+use crate::server::timing_program_types::{
+    heat_from_properties, heat_result_from_properties, matching_heat, settings_from_properties,
+    TimingProgramProperty, TimingProgramSetting,
 };
-use crate::times::RaceTime;
-use crate::webserver::{ConnectionState, PDFConfigurationSetting};
+use crate::times::{RaceTime, RaceWind};
+use crate::webserver::{ConnectionState, MeetExportReport, PDFConfigurationSetting};
 use crate::{
-    args::Args,
-    client::{ClockState, TimingSettings, TimingStateMachine, TimingUpdate},
+    args::{Args, TimingSourceBackend},
+    client::{
+        ClockState, QrCodePayload, QrCodeState, TickerMessage, TickerState, TimingSettings,
+        TimingStateMachine, TimingUpdate,
+    },
     database::{
         get_heat_data, get_log_limited, get_wind_readings, purge_heat_data, DatabaseManager,
         DatabaseSerializable,
@@ -37,17 +53,19 @@ use crate::{
         IncomingInstruction, InstructionFromCameraProgram, InstructionFromTimingProgram,
         InstructionToTimingProgram,
     },
-    server::camera_program_types::HeatStartList,
+    server::{camera_program_types::HeatStartList, live_feed::LiveFeedEvent},
     times::DayTime,
     webserver::{DisplayClientState, MessageFromWebControl, MessageToWebControl},
     wind::format::{
         MessageToWindServer::SetTime,
+        WindMeasurement, WindMeasurementType,
         WindMessageBroadcast::{Measured, Started},
     },
 };
 use async_channel::Sender;
 use clap::crate_version;
 use images_core::images::{IconsStorage, ImageMeta, ImagesStorage};
+use rust_to_ts_types::TypescriptSerializable;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::time::{Duration, Instant};
@@ -55,7 +73,13 @@ use std::{path::Path, sync::Arc};
 use tokio::sync::Mutex;
 use uuid::Uuid;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// A 200m wind measurement only starts, when the runners enter the straight
+const WIND_SERVER_HEAT_MATCH_WINDOW: Duration = Duration::from_secs(60);
+const WIND_DISCREPANCY_TOLERANCE_TENTHS: i32 = 1;
+/// The attempt is entered after the jump, a reading older than that belongs to another (or no stored) attempt
+const JUMP_WIND_ATTEMPT_WINDOW: Duration = Duration::from_secs(90);
+
+#[derive(Serialize, Deserialize, Debug, Clone, TypescriptSerializable)]
 pub struct ServerImposedSettings {
     pub position: (u32, u32, u32, u32),
     pub table_duration_nr_ms: u32,
@@ -65,7 +89,7 @@ pub struct ServerImposedSettings {
     pub scroll_text_deadzones_nr_ms: u32,
 }
 impl ServerImposedSettings {
-    fn new(args: &Args) -> Self {
+    pub fn new(args: &Args) -> Self {
         Self {
             position: (args.dp_pos_x, args.dp_pos_y, args.dp_width, args.dp_height),
             table_duration_nr_ms: args.table_duration_nr_ms,
@@ -85,6 +109,7 @@ pub enum ClientInternalMessageFromServerToClient {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum MessageFromServerToClient {
     DisplayText(String),
+    DisplayQrCode(QrCodePayload),
     RequestVersion,
     ProductKey(String),
     ServerImposedSettings(ServerImposedSettings),
@@ -99,12 +124,15 @@ pub enum MessageFromServerToClient {
     Clock(DayTime),
     ClientInternal(ClientInternalMessageFromServerToClient),
     PushDisplayEntry(DisplayEntry),
+    TickerQueue(Vec<TickerMessage>),
+    CallRoom(Vec<HeatStartList>),
+    FieldEvent(FieldEventData),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum ServerInternalMessageFromClientToServer {
-    SetMainDisplayState(bool),
-    MakeVersionRequestToAllClients,
+    SetDisplayState(bool),
+    MakeVersionRequest,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -114,6 +142,7 @@ pub enum MessageFromClientToServer {
     CurrentWindow(Vec<u8>),
     TimingSettingsState(TimingSettings),
     FrametimeReport(FrametimeReport),
+    TickerMessageFinished(Uuid),
 }
 impl Display for MessageFromClientToServer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -129,6 +158,8 @@ impl Display for MessageFromClientToServer {
                     format!("TimingSettingsState: {:?}", self),
                 MessageFromClientToServer::FrametimeReport(_) =>
                     format!("FrametimeReport: {:?}", self),
+                MessageFromClientToServer::TickerMessageFinished(_) =>
+                    format!("TickerMessageFinished: {:?}", self),
             }
         )
     }
@@ -201,7 +232,8 @@ pub struct ServerStateMachine {
     args: Args,
     pub state: ServerState,
     comm_channel: InstructionCommunicationChannel,
-    display_connected: bool,
+    display_clients: Vec<DisplayClient>, // the first one is the one whose frames are sent to the timing program
+    preview_display_client: Option<String>, // the frames of this client are shown in the web control
     database_manager: DatabaseManager,
     sound_engine: Option<AudioPlayer>,
     timing_settings_template: TimingSettings,
@@ -209,12 +241,18 @@ pub struct ServerStateMachine {
     database_version_mismatch: Option<(String, String)>,
     bib_heat_selection: Option<Uuid>,
     heat_start_time_instant: Option<(DayTime, Instant)>,
+    heat_start_id: Option<Uuid>,
+    ticker_messages: Vec<TickerMessage>,
+    field_event_on_display: Option<Uuid>,
+    unused_jump_wind: Option<(RaceWind, Instant)>, // the last 5s jump measurement, until it is attached to an attempt
+    default_slideshow_duration_nr_ms: u32, // what clients have, that were not set up individually
 }
 impl ServerStateMachine {
     pub fn new(
         args: &Args,
         comm_channel: InstructionCommunicationChannel,
         database_manager: DatabaseManager,
+        display_clients: Vec<DisplayClientConfig>,
     ) -> Self {
         let sound_engine = match AudioPlayer::new() {
             Err(e) => {
@@ -248,7 +286,11 @@ impl ServerStateMachine {
             args: args.clone(),
             state: ServerState::PassthroughClient,
             comm_channel, // only used to send instructions outwards. Rest is done via incoming commands (there is a handler that continously takes them out of the channel and forwards them into us)
-            display_connected: false,
+            preview_display_client: display_clients.first().map(|c| c.name.clone()),
+            display_clients: display_clients
+                .into_iter()
+                .map(|c| DisplayClient::new(c, args))
+                .collect(),
             database_manager,
             sound_engine,
             timing_settings_template: TimingSettings::new(args),
@@ -256,6 +298,11 @@ impl ServerStateMachine {
             database_version_mismatch,
             bib_heat_selection: None,
             heat_start_time_instant: None,
+            heat_start_id: None,
+            ticker_messages: Vec::new(),
+            field_event_on_display: None,
+            unused_jump_wind: None,
+            default_slideshow_duration_nr_ms: args.slideshow_duration_nr_ms,
         }
     }
 
@@ -277,6 +324,20 @@ impl ServerStateMachine {
             }
         };
 
+        // check permissions of the web control session
+        if let IncomingInstruction::FromWebControl(role, w) = &msg {
+            let required_role = w.required_role();
+            if *role < required_role {
+                warn!(
+                    "Web control session with role {:?} is not allowed to send {} (requires {:?})",
+                    role,
+                    w.type_name(),
+                    required_role
+                );
+                return;
+            }
+        }
+
         // check database
         let dbss = if let Some(dbss) = &self.static_state {
             dbss
@@ -293,14 +354,7 @@ impl ServerStateMachine {
 
             // static state not initialized
             let updated_successfully = match msg {
-                IncomingInstruction::FromWebControl(w) => match w {
-                    MessageFromWebControl::RequestPassword => {
-                        // this is still required, otherwise we cannot even init db
-                        self.send_message_to_web_control(MessageToWebControl::Password(
-                            self.args.webcontrol_password.clone(),
-                        ));
-                        None
-                    }
+                IncomingInstruction::FromWebControl(_, w) => match w {
                     MessageFromWebControl::InitStaticDatabaseState(mut init) => {
                         // overwrite setting with the local value
                         init.program_licensed_for = product_key.company_name.clone();
@@ -361,26 +415,32 @@ impl ServerStateMachine {
                     }
                 }
             }
-            IncomingInstruction::FromClient(inst) => match inst {
+            IncomingInstruction::FromClient(client_name, inst) => match inst {
                 MessageFromClientToServer::ServerInternal(internal) => match internal {
-                    ServerInternalMessageFromClientToServer::MakeVersionRequestToAllClients => {
-                        // this possibly must be changed for multiple clients (possible // TODO )
+                    ServerInternalMessageFromClientToServer::MakeVersionRequest => {
                         match self.args.product_key.as_ref() {
                             Some(key) => {
-                                self.send_message_to_client(MessageFromServerToClient::ProductKey(
-                                    key.clone(),
-                                ));
+                                self.send_message_to_display_client(
+                                    &client_name,
+                                    MessageFromServerToClient::ProductKey(key.clone()),
+                                );
                             }
                             None => {}
                         }
-                        self.send_message_to_client(MessageFromServerToClient::RequestVersion);
+                        self.send_message_to_display_client(
+                            &client_name,
+                            MessageFromServerToClient::RequestVersion,
+                        );
                     }
-                    ServerInternalMessageFromClientToServer::SetMainDisplayState(new_val) => {
-                        self.set_main_display_state(new_val);
+                    ServerInternalMessageFromClientToServer::SetDisplayState(new_val) => {
+                        self.set_display_state(&client_name, new_val);
                     }
                 },
                 MessageFromClientToServer::Version(version) => {
-                    info!("Client reported to have version: '{}'", version);
+                    info!(
+                        "Client '{}' reported to have version: '{}'",
+                        client_name, version
+                    );
                     let our_version = String::from(crate_version!());
                     if version == our_version {
                         info!("That is a version match. Communication established!");
@@ -397,21 +457,37 @@ impl ServerStateMachine {
                     }
 
                     // init-impose the server settings from the server
-                    let server_imposed_settings = ServerImposedSettings::new(&self.args);
-                    debug!(
-                        "Requesting window change on client: {} {} {} {}",
-                        server_imposed_settings.position.0,
-                        server_imposed_settings.position.1,
-                        server_imposed_settings.position.2,
-                        server_imposed_settings.position.3,
-                    );
-                    self.send_message_to_client(MessageFromServerToClient::ServerImposedSettings(
-                        server_imposed_settings,
-                    ));
+                    match self
+                        .display_clients
+                        .iter()
+                        .find(|c| c.config.name == client_name)
+                        .map(|c| c.server_imposed_settings.clone())
+                    {
+                        Some(server_imposed_settings) => {
+                            debug!(
+                                "Requesting window change on client '{}': {} {} {} {}",
+                                client_name,
+                                server_imposed_settings.position.0,
+                                server_imposed_settings.position.1,
+                                server_imposed_settings.position.2,
+                                server_imposed_settings.position.3,
+                            );
+                            self.send_message_to_display_client(
+                                &client_name,
+                                MessageFromServerToClient::ServerImposedSettings(
+                                    server_imposed_settings,
+                                ),
+                            );
+                        }
+                        None => error!("Version from unknown display client '{}'", client_name),
+                    }
                     // init-impose the timing settings from the server
-                    self.send_message_to_client(MessageFromServerToClient::TimingSettingsUpdate(
-                        self.timing_settings_template.clone(),
-                    ));
+                    self.send_message_to_display_client(
+                        &client_name,
+                        MessageFromServerToClient::TimingSettingsUpdate(
+                            self.timing_settings_template.clone(),
+                        ),
+                    );
 
                     // send client advertisement images
                     let folder_path = Path::new("advertisement_container");
@@ -422,14 +498,46 @@ impl ServerStateMachine {
                         }
                         Ok(data) => data,
                     };
-                    self.send_message_to_client(MessageFromServerToClient::AdvertisementImages(
-                        images_data,
-                    ));
+                    self.send_message_to_display_client(
+                        &client_name,
+                        MessageFromServerToClient::AdvertisementImages(images_data),
+                    );
+
+                    // a client that (re-)connects picks up the running ticker
+                    let ticker =
+                        MessageFromServerToClient::TickerQueue(self.ticker_messages.clone());
+                    if self
+                        .display_clients
+                        .iter()
+                        .any(|c| c.config.name == client_name && c.routing.allows(&ticker))
+                    {
+                        self.send_message_to_display_client(&client_name, ticker);
+                    }
+
+                    // a dedicated call room display does not wait for the next heat to show something
+                    if self
+                        .display_clients
+                        .iter()
+                        .any(|c| c.config.name == client_name && c.routing.call_room)
+                    {
+                        let upcoming = self.upcoming_heats();
+                        self.send_message_to_display_client(
+                            &client_name,
+                            MessageFromServerToClient::CallRoom(upcoming),
+                        );
+                    }
                 }
                 MessageFromClientToServer::CurrentWindow(data) => {
+                    let is_primary = self
+                        .display_clients
+                        .first()
+                        .is_some_and(|c| c.config.name == client_name);
+                    let is_preview = self.preview_display_client.as_ref() == Some(&client_name);
+
                     if self.state == ServerState::PassthroughClient {
                         // force our frame onto the timing program
-                        if self.args.listen_to_timing_program
+                        if is_primary
+                            && self.args.listen_to_timing_program
                             && self.comm_channel.timing_program_there_to_receive()
                         {
                             self.send_message_to_timing_program(
@@ -437,14 +545,14 @@ impl ServerStateMachine {
                             );
                         }
 
-                        if self.comm_channel.web_control_there_to_receive() {
+                        if is_preview && self.comm_channel.web_control_there_to_receive() {
                             self.send_message_to_web_control(
                                 MessageToWebControl::CurrentDisplayFrame(data),
                             );
                         }
                     }
                     // ping the state to the web control
-                    if self.comm_channel.web_control_there_to_receive() {
+                    if is_preview && self.comm_channel.web_control_there_to_receive() {
                         self.send_message_to_web_control(MessageToWebControl::DisplayClientState(
                             self.get_display_client_state(),
                         ));
@@ -455,10 +563,20 @@ impl ServerStateMachine {
                     self.send_message_to_web_control(MessageToWebControl::TimingSettingsState(set));
                 }
                 MessageFromClientToServer::FrametimeReport(ftr) => {
-                    if self.comm_channel.web_control_there_to_receive() {
+                    if self.preview_display_client.as_ref() == Some(&client_name)
+                        && self.comm_channel.web_control_there_to_receive()
+                    {
                         self.send_message_to_web_control(MessageToWebControl::FrametimeReport(ftr));
                     }
                 }
+                MessageFromClientToServer::TickerMessageFinished(id) => {
+                    // the first client to finish the repeats removes the message for all clients
+                    if self.ticker_messages.iter().any(|m| m.id == id) {
+                        debug!("Ticker message {} was shown often enough", id);
+                        self.ticker_messages.retain(|m| m.id != id);
+                        self.send_out_ticker_messages();
+                    }
+                }
             },
             IncomingInstruction::FromTimingProgram(inst) => match inst {
                 InstructionFromTimingProgram::ClientInfo => (),
@@ -484,7 +602,8 @@ impl ServerStateMachine {
                         self.state = ServerState::PassthroughClient;
                     }
                 }
-                InstructionFromTimingProgram::Advertisements => {
+                InstructionFromTimingProgram::Advertisements(properties) => {
+                    self.handle_timing_program_properties(&properties);
                     if self.state == ServerState::PassthroughClient {
                         self.send_message_to_client(MessageFromServerToClient::Advertisements);
                     }
@@ -497,14 +616,19 @@ impl ServerStateMachine {
                         ));
                     }
                 }
-                InstructionFromTimingProgram::Results
-                | InstructionFromTimingProgram::ResultsUpdate => {
+                InstructionFromTimingProgram::Results(properties)
+                | InstructionFromTimingProgram::ResultsUpdate(properties) => {
+                    let dbss = dbss.clone();
+                    self.handle_timing_program_properties(&properties);
                     if self.state == ServerState::PassthroughClient {
                         self.send_message_to_client(MessageFromServerToClient::Timing);
                         self.send_message_to_client(MessageFromServerToClient::TimingStateUpdate(
                             TimingUpdate::ResultList,
                         ));
                     }
+                    if self.try_work_with_timing_program_results() {
+                        self.handle_timing_program_results(dbss, &properties);
+                    }
                 }
                 InstructionFromTimingProgram::StartList => {
                     if self.state == ServerState::PassthroughClient {
@@ -514,8 +638,9 @@ impl ServerStateMachine {
                         ));
                     }
                 }
-                InstructionFromTimingProgram::SetProperty => {
-                    // this is purposefully ignored -> there is no info we can get from it
+                InstructionFromTimingProgram::SetProperty(properties) => {
+                    // most properties only concern the layouts of the timing program -> only settings are taken
+                    self.handle_timing_program_properties(&properties);
                 }
             },
             IncomingInstruction::FromCameraProgram(inst) => match inst {
@@ -535,6 +660,23 @@ impl ServerStateMachine {
                     self.handle_heat_wind(wind);
                 }
                 InstructionFromCameraProgram::HeatWindMissing(missing_wind) => {
+                    // otherwise the wind server wind gets attached, once it is measured
+                    if let Ok(wind_server_wind) = HeatWindServerWind::get_from_database_by_id(
+                        missing_wind.id,
+                        &self.database_manager,
+                    ) {
+                        info!(
+                            "Camera program has no wind for heat {}, using {} from the wind server",
+                            missing_wind.id, wind_server_wind.wind
+                        );
+                        self.handle_heat_wind(HeatWind {
+                            application: missing_wind.application.clone(),
+                            version: missing_wind.version.clone(),
+                            generated: missing_wind.generated,
+                            id: missing_wind.id,
+                            wind: wind_server_wind.wind,
+                        });
+                    }
                     store_to_database!(missing_wind, self); // this does not need to and can not be faked -> it is on and has no other purpose
                 }
                 InstructionFromCameraProgram::HeatFinish(finish) => {
@@ -563,8 +705,21 @@ impl ServerStateMachine {
                         self.send_message_to_client(MessageFromServerToClient::Clock(dt));
                     }
                 }
+                InstructionFromCameraProgram::Backfill(inst) => match *inst {
+                    // no displays, live feed or call room: this is not happening now
+                    InstructionFromCameraProgram::HeatStartList(list) => {
+                        store_to_database!(list, self);
+                    }
+                    InstructionFromCameraProgram::CompetitorEvaluated(evaluated) => {
+                        store_to_database!(evaluated, self);
+                    }
+                    InstructionFromCameraProgram::HeatResult(result) => {
+                        store_to_database!(result, self);
+                    }
+                    other => debug!("Not storing backfilled instruction {:?}", other),
+                },
             },
-            IncomingInstruction::FromWebControl(inst) => match inst {
+            IncomingInstruction::FromWebControl(_, inst) => match inst {
                 MessageFromWebControl::Advertisements => {
                     if self.state == ServerState::PassthroughClient {
                         self.send_message_to_client(MessageFromServerToClient::Advertisements);
@@ -575,6 +730,13 @@ impl ServerStateMachine {
                         self.send_message_to_client(MessageFromServerToClient::DisplayText(text))
                     }
                 }
+                MessageFromWebControl::DisplayQrCode(payload) => {
+                    if self.state == ServerState::PassthroughClient {
+                        self.send_message_to_client(MessageFromServerToClient::DisplayQrCode(
+                            payload,
+                        ))
+                    }
+                }
                 MessageFromWebControl::Idle => {
                     if self.state == ServerState::PassthroughClient {
                         self.send_message_to_client(MessageFromServerToClient::Clear)
@@ -788,9 +950,23 @@ impl ServerStateMachine {
                 MessageFromWebControl::ExportDataToFile => {
                     let file_name = format!("jta-dwa-{}.meetxml", dbss.date.to_string());
 
-                    let meet = generate_meet_data(&dbss, &self.database_manager);
+                    let (meet, problems) = generate_meet_data(&dbss, &self.database_manager);
+                    for problem in &problems {
+                        warn!("Export: {}", problem);
+                    }
 
+                    write_lynx_schedule_files(&self.args, &meet);
                     write_to_xml_output_file(&self.args, &file_name, meet);
+
+                    self.send_message_to_web_control(MessageToWebControl::MeetExportReport(
+                        MeetExportReport { problems },
+                    ));
+                }
+                MessageFromWebControl::ExportResultsToFile(heat_id) => {
+                    write_result_exports(&self.args, &dbss, heat_id, &self.database_manager);
+                }
+                MessageFromWebControl::ExportPDFsToFile => {
+                    write_pdf_exports(&self.args, &dbss, &self.database_manager);
                 }
                 MessageFromWebControl::CreateAthlete(ath) => {
                     store_to_database_ignore_date!(ath, self);
@@ -802,6 +978,28 @@ impl ServerStateMachine {
                         Err(e) => error!("Encountered error, after creation of athlete: {}", e),
                     }
                 }
+                MessageFromWebControl::ImportAthletes(athletes) => {
+                    match import_athletes(athletes, &self.database_manager) {
+                        Ok(report) => {
+                            debug!(
+                                "Imported {} athletes, {} conflicts",
+                                report.imported,
+                                report.conflicts.len()
+                            );
+                            self.send_message_to_web_control(
+                                MessageToWebControl::AthleteImportReport(report),
+                            );
+                        }
+                        Err(e) => error!("Encountered error, while importing athletes: {}", e),
+                    }
+
+                    match get_all_athletes_meta_data(&self.database_manager) {
+                        Ok(d) => {
+                            self.send_message_to_web_control(MessageToWebControl::AthletesData(d))
+                        }
+                        Err(e) => error!("Encountered error, after import of athletes: {}", e),
+                    }
+                }
                 MessageFromWebControl::DeleteAthlete(ath_id) => {
                     match delete_athlete(ath_id, &self.database_manager) {
                         Ok(_) => {
@@ -822,6 +1020,11 @@ impl ServerStateMachine {
                     }
                 }
                 MessageFromWebControl::CreateHeatAssignment(ha) => {
+                    if let Err(e) = ha.validate() {
+                        error!("Rejected heat assignment: {}", e);
+                        return;
+                    }
+
                     match create_heat_assignment(ha, &self.database_manager) {
                         Ok(a) => {
                             debug!("Created heat assignment: {:?}", a);
@@ -901,6 +1104,158 @@ impl ServerStateMachine {
                 MessageFromWebControl::RequestPDFConfigurationSettings => {
                     self.send_out_all_database_settings_to_webclient();
                 }
+                MessageFromWebControl::StorePDFBackground(background) => {
+                    match store_pdf_background(&background, &self.database_manager) {
+                        Ok(_) => debug!("Stored pdf background for {:?}", background.setting_for),
+                        Err(e) => {
+                            error!("Encountered error, while storing a pdf background: {}", e)
+                        }
+                    }
+                }
+                MessageFromWebControl::DeletePDFBackground(setting_for) => {
+                    match delete_pdf_background(&setting_for, &self.database_manager) {
+                        Ok(_) => debug!("Deleted pdf background for {:?}", setting_for),
+                        Err(e) => {
+                            error!("Encountered error, while deleting a pdf background: {}", e)
+                        }
+                    }
+                }
+                MessageFromWebControl::StoreCompetitionEvent(event) => {
+                    match event.store_to_database(&self.database_manager) {
+                        Ok(_) => {
+                            debug!("Upserted competition event");
+                            self.send_out_all_competition_events_to_webclient();
+                        }
+                        Err(e) => error!(
+                            "Encountered error, while upserting a competition event: {}",
+                            e.to_string()
+                        ),
+                    }
+                }
+                MessageFromWebControl::DeleteCompetitionEvent(id) => {
+                    match delete_competition_event(id, &self.database_manager) {
+                        Ok(_) => {
+                            debug!("Deleted competition event");
+                            self.send_out_all_competition_events_to_webclient();
+                        }
+                        Err(e) => error!(
+                            "Encountered error, while deleting a competition event: {}",
+                            e.to_string()
+                        ),
+                    }
+                }
+                MessageFromWebControl::RequestCompetitionEvents => {
+                    self.send_out_all_competition_events_to_webclient();
+                }
+                MessageFromWebControl::StoreTickerMessage(message) => {
+                    match self.ticker_messages.iter_mut().find(|m| m.id == message.id) {
+                        Some(existing) => *existing = message,
+                        None => self.ticker_messages.push(message),
+                    }
+                    self.send_out_ticker_messages();
+                }
+                MessageFromWebControl::DeleteTickerMessage(id) => {
+                    self.ticker_messages.retain(|m| m.id != id);
+                    self.send_out_ticker_messages();
+                }
+                MessageFromWebControl::RequestTickerMessages => {
+                    self.send_message_to_web_control(MessageToWebControl::TickerMessages(
+                        self.ticker_messages.clone(),
+                    ));
+                }
+                MessageFromWebControl::StoreFieldEvent(event) => {
+                    match event.store_to_database(&self.database_manager) {
+                        Ok(_) => {
+                            debug!("Upserted field event");
+                            self.send_out_field_events();
+                        }
+                        Err(e) => error!(
+                            "Encountered error, while upserting a field event: {}",
+                            e.to_string()
+                        ),
+                    }
+                }
+                MessageFromWebControl::DeleteFieldEvent(id) => {
+                    match delete_field_event(id, &self.database_manager) {
+                        Ok(_) => {
+                            debug!("Deleted field event with all attempts");
+                            if self.field_event_on_display == Some(id) {
+                                self.field_event_on_display = None;
+                            }
+                            self.send_out_field_events();
+                        }
+                        Err(e) => error!(
+                            "Encountered error, while deleting a field event: {}",
+                            e.to_string()
+                        ),
+                    }
+                }
+                MessageFromWebControl::RequestFieldEvents => {
+                    self.send_out_field_events();
+                }
+                MessageFromWebControl::StoreFieldEventAttempt(mut attempt) => {
+                    match FieldEvent::get_from_database_by_id(
+                        attempt.event_id,
+                        &self.database_manager,
+                    ) {
+                        Ok(event) => {
+                            if let Err(e) = attempt.validate(&event) {
+                                error!("Rejected field event attempt: {}", e);
+                                return;
+                            }
+
+                            // a pass has no jump, so the reading stays for the next real attempt.
+                            // Corrections of stored attempts must not take the reading of the next jump
+                            let is_new_attempt = FieldEventAttempt::get_from_database_by_id(
+                                attempt.id,
+                                &self.database_manager,
+                            )
+                            .is_err();
+                            if event.kind == FieldEventKind::Jump
+                                && is_new_attempt
+                                && attempt.wind.is_none()
+                                && attempt.result != FieldAttemptResult::Pass
+                            {
+                                attempt.wind = self.take_unused_jump_wind();
+                            }
+
+                            match attempt.store_to_database(&self.database_manager) {
+                                Ok(_) => {
+                                    debug!("Upserted field event attempt");
+                                    self.send_out_field_events();
+                                }
+                                Err(e) => error!(
+                                    "Encountered error, while upserting a field event attempt: {}",
+                                    e.to_string()
+                                ),
+                            }
+                        }
+                        Err(e) => error!(
+                            "Can not store attempt for unknown field event {}: {}",
+                            attempt.event_id, e
+                        ),
+                    }
+                }
+                MessageFromWebControl::DeleteFieldEventAttempt(attempt) => {
+                    match delete_field_event_attempt(attempt.id, &self.database_manager) {
+                        Ok(_) => {
+                            debug!("Deleted field event attempt");
+                            self.send_out_field_events();
+                        }
+                        Err(e) => error!(
+                            "Encountered error, while deleting a field event attempt: {}",
+                            e.to_string()
+                        ),
+                    }
+                }
+                MessageFromWebControl::ShowFieldEvent(id) => match self.field_event_data(id) {
+                    Some(data) => {
+                        self.field_event_on_display = Some(id);
+                        self.send_message_to_client(MessageFromServerToClient::FieldEvent(data));
+                        self.send_current_connection_state_to_webclient();
+                    }
+                    None => error!("Can not show unknown field event {}", id),
+                },
                 MessageFromWebControl::GetMainHeat => {
                     self.send_out_main_heat_to_webcontrol();
                 }
@@ -932,10 +1287,32 @@ impl ServerStateMachine {
                 MessageFromWebControl::RequestConnectionStates => {
                     self.send_current_connection_state_to_webclient();
                 }
-                MessageFromWebControl::RequestPassword => {
-                    self.send_message_to_web_control(MessageToWebControl::Password(
-                        self.args.webcontrol_password.clone(),
-                    ));
+                MessageFromWebControl::UpdateDisplayClient(update) => {
+                    self.handle_display_client_update(update);
+                    self.send_current_connection_state_to_webclient();
+                }
+                MessageFromWebControl::CallRoom(name) => {
+                    if self.display_clients.iter().any(|c| c.config.name == name) {
+                        let upcoming = self.upcoming_heats();
+                        self.send_message_to_display_client(
+                            &name,
+                            MessageFromServerToClient::CallRoom(upcoming),
+                        );
+                        self.send_current_connection_state_to_webclient();
+                    } else {
+                        error!(
+                            "Can not show the call room on unknown display client '{}'",
+                            name
+                        );
+                    }
+                }
+                MessageFromWebControl::SelectDisplayClientPreview(name) => {
+                    if self.display_clients.iter().any(|c| c.config.name == name) {
+                        self.preview_display_client = Some(name);
+                    } else {
+                        error!("Can not preview unknown display client '{}'", name);
+                    }
+                    self.send_current_connection_state_to_webclient();
                 }
                 MessageFromWebControl::SendHeatDataToDisplay(uuid) => {
                     let data = match get_heat_data(uuid, &self.database_manager) {
@@ -1023,6 +1400,15 @@ impl ServerStateMachine {
             },
             IncomingInstruction::FromWindServer(inst) => match inst {
                 Measured(wind_measurement) => {
+                    self.correlate_wind_measurement_with_heat(&wind_measurement);
+                    if wind_measurement.probable_measurement_type == WindMeasurementType::Jump5s {
+                        debug!(
+                            "Keeping jump wind {} for the next attempt",
+                            wind_measurement.wind
+                        );
+                        self.unused_jump_wind =
+                            Some((wind_measurement.wind.clone(), Instant::now()));
+                    }
                     store_to_database!(wind_measurement, self);
                 }
                 Started(started_wind_measurement) => {
@@ -1090,6 +1476,28 @@ impl ServerStateMachine {
         }
     }
 
+    fn handle_display_client_update(&mut self, update: DisplayClientUpdate) {
+        let client = match self
+            .display_clients
+            .iter_mut()
+            .find(|c| c.config.name == update.name)
+        {
+            Some(c) => c,
+            None => {
+                error!("Can not update unknown display client '{}'", update.name);
+                return;
+            }
+        };
+
+        client.routing = update.routing;
+        client.server_imposed_settings = update.server_imposed_settings.clone();
+
+        self.send_message_to_display_client(
+            &update.name,
+            MessageFromServerToClient::ServerImposedSettings(update.server_imposed_settings),
+        );
+    }
+
     fn handle_bib_mode_selection(&mut self) {
         self.send_message_to_web_control(MessageToWebControl::HeatDataSelectionForBibMode(
             generate_bib_data(self.bib_heat_selection.clone(), &self.database_manager),
@@ -1098,14 +1506,17 @@ impl ServerStateMachine {
 
     fn handle_heat_start_list(&mut self, list: HeatStartList) {
         store_to_database!(list.clone(), self);
+        self.send_message_to_live_feed(LiveFeedEvent::HeatStartList(list.clone()));
         self.send_message_to_client(MessageFromServerToClient::TimingStateUpdate(
             TimingUpdate::Meta(list),
         ));
+        self.send_out_call_room();
     }
 
     fn handle_heat_start(&mut self, start: HeatStart) {
         let now = Instant::now();
         self.heat_start_time_instant = Some((start.time.clone(), now));
+        self.heat_start_id = Some(start.id);
 
         // tell the bib server immediately (real time relevant)
         if self.try_work_with_bib_server() {
@@ -1126,8 +1537,12 @@ impl ServerStateMachine {
             self.play_sound(Sound::Beep1);
         }
 
+        self.send_message_to_live_feed(LiveFeedEvent::HeatStart(start.clone()));
+
         store_to_database!(start, self);
 
+        // the started heat drops out of the call room list
+        self.send_out_call_room();
         self.handle_bib_mode_selection();
     }
 
@@ -1144,15 +1559,19 @@ impl ServerStateMachine {
 
         self.handle_bib_mode_selection();
         self.send_out_main_heat_to_webcontrol();
+        // purged heat is upcoming again
+        self.send_out_call_room();
     }
 
     fn handle_heat_reset_display(&mut self, force: bool) {
+        self.send_message_to_live_feed(LiveFeedEvent::TimeReset);
         self.send_message_to_client(MessageFromServerToClient::TimingStateUpdate(
             TimingUpdate::Reset(force),
         ));
     }
 
     fn handle_race_time_display(&mut self, rt: RaceTime) {
+        self.send_message_to_live_feed(LiveFeedEvent::TimeRunning(rt.clone()));
         self.send_message_to_client(MessageFromServerToClient::TimingStateUpdate(
             TimingUpdate::Running(rt),
         ));
@@ -1163,10 +1582,18 @@ impl ServerStateMachine {
             self.play_sound(Sound::Beep2);
         }
 
+        let heat_id = intermediate.id;
         store_to_database!(intermediate, self);
+
+        // kept with the heat, so reading the heat data does not need to map the intermediates again
+        match compute_heat_splits(heat_id, &self.database_manager) {
+            Ok(splits) => store_to_database_log_conditionally!(splits, self, false, false),
+            Err(e) => error!("Could not compute the splits of heat {}: {}", heat_id, e),
+        }
     }
 
     fn handle_intermediate_time_display(&mut self, rt: RaceTime) {
+        self.send_message_to_live_feed(LiveFeedEvent::TimeHeld(rt.clone()));
         self.send_message_to_client(MessageFromServerToClient::TimingStateUpdate(
             TimingUpdate::Intermediate(rt),
         ));
@@ -1181,6 +1608,7 @@ impl ServerStateMachine {
     }
 
     fn handle_end_time_display(&mut self, rt: RaceTime) {
+        self.send_message_to_live_feed(LiveFeedEvent::TimeEnd(rt.clone()));
         self.send_message_to_client(MessageFromServerToClient::TimingStateUpdate(
             TimingUpdate::End(rt),
         ));
@@ -1192,6 +1620,7 @@ impl ServerStateMachine {
         evaluated: CompetitorEvaluated,
     ) {
         store_to_database!(evaluated.clone(), self);
+        self.send_message_to_live_feed(LiveFeedEvent::CompetitorEvaluated(evaluated.clone()));
         // we can assume, that the "always emit result list on change" setting is active in the camera program
         // for this reason, we ignore singular evaluation emits
 
@@ -1220,16 +1649,9 @@ impl ServerStateMachine {
         // this takes a DatabaseStaticState and not a &DatabaseStaticState because it is a self function that borrows mutable and we can not simultaneously borrow the dbss reference
         // I don't wanna extra unwrap the dbss though, because in the switch where this function is used, this is always done
 
-        if let Some(wind) = &result.wind {
-            // Can get wind here again, if it was missed
-            self.send_message_to_client(MessageFromServerToClient::TimingStateUpdate(
-                TimingUpdate::Wind(wind.clone()),
-            ));
-        }
-        self.send_message_to_client(MessageFromServerToClient::TimingStateUpdate(
-            TimingUpdate::ResultMeta(result.clone()),
-        ));
+        self.show_heat_result(&result);
 
+        self.send_message_to_live_feed(LiveFeedEvent::HeatResult(result.clone()));
         store_to_database!(result, self); // needs to be before athletes data read
 
         // used in Street run modes (might be used in Sprinterkönig - did not check) -> is quite unnecessary overhead in Track Mode, as there the heats come from external
@@ -1251,10 +1673,194 @@ impl ServerStateMachine {
         }
     }
 
+    fn show_heat_result(&mut self, result: &HeatResult) {
+        if let Some(wind) = &result.wind {
+            // Can get wind here again, if it was missed
+            self.send_message_to_client(MessageFromServerToClient::TimingStateUpdate(
+                TimingUpdate::Wind(wind.clone()),
+            ));
+        }
+        self.send_message_to_client(MessageFromServerToClient::TimingStateUpdate(
+            TimingUpdate::ResultMeta(result.clone()),
+        ));
+    }
+
+    fn handle_timing_program_properties(&mut self, properties: &[TimingProgramProperty]) {
+        for setting in settings_from_properties(properties) {
+            match setting {
+                TimingProgramSetting::SlideshowDuration(ms) => {
+                    // clients set up individually in the web control keep their duration
+                    let previous_default = self.default_slideshow_duration_nr_ms;
+                    self.default_slideshow_duration_nr_ms = ms;
+                    let changed: Vec<(String, ServerImposedSettings)> = self
+                        .display_clients
+                        .iter_mut()
+                        .filter(|c| {
+                            c.server_imposed_settings.slideshow_duration_nr_ms == previous_default
+                                && previous_default != ms
+                        })
+                        .map(|c| {
+                            c.server_imposed_settings.slideshow_duration_nr_ms = ms;
+                            (c.config.name.clone(), c.server_imposed_settings.clone())
+                        })
+                        .collect();
+                    for (name, settings) in changed {
+                        debug!(
+                            "Timing program changed the slideshow duration of '{}' to {}ms",
+                            name, ms
+                        );
+                        self.send_message_to_display_client(
+                            &name,
+                            MessageFromServerToClient::ServerImposedSettings(settings),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Without a timing source, the result lists of the timing program are the only results there are
+    fn handle_timing_program_results(
+        &mut self,
+        dbss: DatabaseStaticState,
+        properties: &[TimingProgramProperty],
+    ) {
+        let start_lists = match HeatStartList::get_all_from_database(&self.database_manager) {
+            Ok(start_lists) => start_lists,
+            Err(e) => {
+                error!("Could not read heats for the timing program results: {}", e);
+                return;
+            }
+        };
+        let Some(heat) = matching_heat(properties, &start_lists) else {
+            let unlisted = heat_from_properties(properties);
+            let start_time = unlisted.scheduled_start_time.clone();
+            match heat_result_from_properties(properties, &unlisted, start_time) {
+                Some(result) => {
+                    debug!(
+                        "Result list of the timing program does not belong to a known heat, showing it without storing"
+                    );
+                    self.show_heat_result(&result);
+                }
+                None => debug!("Result list of the timing program contains no table"),
+            }
+            return;
+        };
+
+        // database error here basically always is not found error -> the start was not recorded
+        let start_time = match HeatStart::get_from_database_by_id(heat.id, &self.database_manager) {
+            Ok(start) => start.time,
+            Err(_) => heat.scheduled_start_time.clone(),
+        };
+
+        match heat_result_from_properties(properties, heat, start_time) {
+            Some(result) => self.handle_heat_result(dbss, result),
+            None => debug!("Result list of the timing program contains no table"),
+        }
+    }
+
+    /// Jump measurements are only attached to an attempt entered within JUMP_WIND_ATTEMPT_WINDOW
+    fn take_unused_jump_wind(&mut self) -> Option<RaceWind> {
+        let (wind, measured) = self.unused_jump_wind.take()?;
+        if measured.elapsed() > JUMP_WIND_ATTEMPT_WINDOW {
+            debug!("Dropping jump wind {}, it is too old for the attempt", wind);
+            return None;
+        }
+        Some(wind)
+    }
+
+    /// Race measurements are taken during the heat that started last: measured at most WIND_SERVER_HEAT_MATCH_WINDOW after the start
+    fn correlate_wind_measurement_with_heat(&mut self, measurement: &WindMeasurement) {
+        if !matches!(
+            measurement.probable_measurement_type,
+            WindMeasurementType::Race10s | WindMeasurementType::Race13s
+        ) {
+            return;
+        }
+        let (Some((start_time, start_instant)), Some(heat_id)) =
+            (&self.heat_start_time_instant, self.heat_start_id)
+        else {
+            debug!("Race wind measurement without a started heat, can not be attached");
+            return;
+        };
+
+        let since_start = match &measurement.time {
+            // the wind server clock is synced on every heat start, so the times are comparable
+            Some(time) => {
+                Duration::from(time.clone()).checked_sub(Duration::from(start_time.clone()))
+            }
+            None => Some(start_instant.elapsed()),
+        };
+        if since_start.is_none_or(|since_start| since_start > WIND_SERVER_HEAT_MATCH_WINDOW) {
+            debug!(
+                "Race wind measurement {} does not belong to the last started heat",
+                measurement.wind
+            );
+            return;
+        }
+
+        debug!(
+            "Attaching wind server measurement {} to heat {}",
+            measurement.wind, heat_id
+        );
+        store_to_database!(
+            HeatWindServerWind {
+                id: heat_id,
+                wind: measurement.wind.clone(),
+                differs_from_camera_program: false,
+            },
+            self
+        );
+
+        // the camera program might have sent its wind already, or reported that it has none
+        if let Ok(heat_wind) = HeatWind::get_from_database_by_id(heat_id, &self.database_manager) {
+            self.check_wind_discrepancy(&heat_wind);
+        } else if let Ok(missing_wind) =
+            HeatWindMissing::get_from_database_by_id(heat_id, &self.database_manager)
+        {
+            info!(
+                "Camera program had no wind for heat {}, using {} from the wind server",
+                heat_id, measurement.wind
+            );
+            self.handle_heat_wind(HeatWind {
+                application: missing_wind.application,
+                version: missing_wind.version,
+                generated: missing_wind.generated,
+                id: heat_id,
+                wind: measurement.wind.clone(),
+            });
+        }
+    }
+
+    /// The result is stored with the wind server wind, so the web control shows it with the heat
+    fn check_wind_discrepancy(&mut self, wind: &HeatWind) {
+        let Ok(mut wind_server_wind) =
+            HeatWindServerWind::get_from_database_by_id(wind.id, &self.database_manager)
+        else {
+            return;
+        };
+
+        let differs = (wind_server_wind.wind.to_tenths() - wind.wind.to_tenths()).abs()
+            > WIND_DISCREPANCY_TOLERANCE_TENTHS;
+        if differs {
+            warn!(
+                "Wind of heat {} differs: camera program has {}, wind server measured {}",
+                wind.id, wind.wind, wind_server_wind.wind
+            );
+        }
+        if differs != wind_server_wind.differs_from_camera_program {
+            wind_server_wind.differs_from_camera_program = differs;
+            store_to_database!(wind_server_wind, self);
+        }
+    }
+
     fn handle_heat_wind(&mut self, wind: HeatWind) {
+        self.check_wind_discrepancy(&wind);
+
         self.send_message_to_client(MessageFromServerToClient::TimingStateUpdate(
             TimingUpdate::Wind(wind.wind.clone()),
         ));
+        self.send_message_to_live_feed(LiveFeedEvent::Wind(wind.clone()));
 
         store_to_database!(wind, self);
     }
@@ -1292,6 +1898,118 @@ impl ServerStateMachine {
         }
     }
 
+    fn send_out_all_competition_events_to_webclient(&mut self) {
+        match CompetitionEvent::get_all_from_database(&self.database_manager) {
+            Ok(data) => {
+                self.send_message_to_web_control(MessageToWebControl::CompetitionEventsData(data));
+            }
+            Err(e) => {
+                error!("Database loading error for competition events: {}", e);
+            }
+        }
+    }
+
+    fn field_event_data(&self, id: Uuid) -> Option<FieldEventData> {
+        let event = FieldEvent::get_from_database_by_id(id, &self.database_manager).ok()?;
+        match get_field_event_attempts(id, &self.database_manager) {
+            Ok(attempts) => Some(FieldEventData::build(event, attempts)),
+            Err(e) => {
+                error!("Database loading error for field event attempts: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Sends all events with standings to the web control and refreshes the clients that show a field event
+    fn send_out_field_events(&mut self) {
+        let events = match FieldEvent::get_all_from_database(&self.database_manager) {
+            Ok(events) => events,
+            Err(e) => {
+                error!("Database loading error for field events: {}", e);
+                return;
+            }
+        };
+        let mut data: Vec<FieldEventData> = events
+            .into_iter()
+            .filter_map(|event| self.field_event_data(event.id))
+            .collect();
+        data.sort_by(|a, b| {
+            Duration::from(a.event.scheduled_start_time.clone())
+                .cmp(&Duration::from(b.event.scheduled_start_time.clone()))
+        });
+
+        if let Some(on_display) = self
+            .field_event_on_display
+            .and_then(|id| data.iter().find(|d| d.event.id == id))
+        {
+            let field_event_clients: Vec<String> = self
+                .display_clients
+                .iter()
+                .filter(|c| c.mode == DisplayClientMode::FieldEvent)
+                .map(|c| c.config.name.clone())
+                .collect();
+            for name in field_event_clients {
+                self.send_message_to_display_client(
+                    &name,
+                    MessageFromServerToClient::FieldEvent(on_display.clone()),
+                );
+            }
+        }
+
+        self.send_message_to_web_control(MessageToWebControl::FieldEventsData(data));
+    }
+
+    /// Heats in schedule order, that have not been started yet
+    fn upcoming_heats(&self) -> Vec<HeatStartList> {
+        let start_lists = match start_lists_in_schedule_order(&self.database_manager) {
+            Ok(start_lists) => start_lists,
+            Err(e) => {
+                error!("Could not read the upcoming heats: {}", e);
+                return Vec::new();
+            }
+        };
+
+        start_lists
+            .into_iter()
+            // database error here basically always is not found error -> heat has not started
+            .filter(|list| {
+                HeatStart::get_from_database_by_id(list.id, &self.database_manager).is_err()
+            })
+            .take(self.args.call_room_heats)
+            .collect()
+    }
+
+    /// Only clients that were put into call room mode follow the schedule
+    fn send_out_call_room(&mut self) {
+        let call_room_clients: Vec<String> = self
+            .display_clients
+            .iter()
+            .filter(|c| c.routing.call_room || c.mode == DisplayClientMode::CallRoom)
+            .map(|c| c.config.name.clone())
+            .collect();
+        if call_room_clients.is_empty() {
+            return;
+        }
+
+        let upcoming = self.upcoming_heats();
+        for name in call_room_clients {
+            self.send_message_to_display_client(
+                &name,
+                MessageFromServerToClient::CallRoom(upcoming.clone()),
+            );
+        }
+    }
+
+    /// The queue is not stored in the database, a restart of the server empties the ticker
+    fn send_out_ticker_messages(&mut self) {
+        self.send_message_to_client(MessageFromServerToClient::TickerQueue(
+            self.ticker_messages.clone(),
+        ));
+        self.send_message_to_web_control(MessageToWebControl::TickerMessages(
+            self.ticker_messages.clone(),
+        ));
+    }
+
     fn try_work_with_bib_server(&self) -> bool {
         self.args.address_bib_server.is_some()
     }
@@ -1300,6 +2018,14 @@ impl ServerStateMachine {
         self.args.address_wind_server.is_some()
     }
 
+    fn try_work_with_timing_program_results(&self) -> bool {
+        match self.args.timing_source {
+            TimingSourceBackend::CameraProgram => self.args.address_camera_program.is_none(),
+            TimingSourceBackend::FinishLynx => self.args.lynx_folder_path.is_none(),
+            TimingSourceBackend::None => true,
+        }
+    }
+
     fn send_current_connection_state_to_webclient(&mut self) {
         let args = &self.args;
 
@@ -1320,21 +2046,25 @@ impl ServerStateMachine {
         self.send_message_to_web_control(MessageToWebControl::ConnectionState(ConnectionState {
             // tries
             try_connect_to_wind: self.try_work_with_wind_server(),
-            try_conect_to_display_client: args.address_display_client.is_some(),
+            try_conect_to_display_client: !self.display_clients.is_empty(),
             try_connect_to_bib: self.try_work_with_bib_server(),
             try_to_connect_to_camera_program: args.address_camera_program.is_some(),
             try_to_connect_to_idcapture: args.address_idcapture_server.is_some(),
             try_to_connect_to_display_passthrough: args.passthrough_to_display_program,
             listening_to_timing_program: args.listen_to_timing_program,
             // ports/addresses
-            display_client_address_with_port: format!(
-                "{}:{}",
-                args.address_display_client
-                    .as_ref()
-                    .unwrap_or(&String::from(""))
-                    .clone(),
-                args.display_client_communication_port
-            ),
+            display_client_address_with_port: self
+                .display_clients
+                .iter()
+                .map(|c| c.config.address.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+            display_clients: self
+                .display_clients
+                .iter()
+                .map(|c| c.connection())
+                .collect(),
+            preview_display_client: self.preview_display_client.clone(),
             camera_program_timing_port: args.camera_exchange_timing_port.clone(),
             camera_program_data_port: args.camera_exchange_data_port.clone(),
             camera_program_xml_port: args.camera_exchange_xml_port.clone(),
@@ -1397,7 +2127,9 @@ impl ServerStateMachine {
 
     fn get_display_client_state(&self) -> DisplayClientState {
         DisplayClientState {
-            alive: self.display_connected,
+            alive: self.display_clients.iter().any(|c| {
+                Some(&c.config.name) == self.preview_display_client.as_ref() && c.connected
+            }),
             external_passthrough_mode: self.state == ServerState::PassthroughDisplayProgram,
             can_switch_mode: self.args.passthrough_to_display_program
                 && self.args.listen_to_timing_program,
@@ -1415,8 +2147,25 @@ impl ServerStateMachine {
         }
     }
 
+    /// Sends to all display clients, whose routing allows the message
     fn send_message_to_client(&mut self, inst: MessageFromServerToClient) {
-        match self.comm_channel.send_out_command_to_client(inst) {
+        let mode = DisplayClientMode::after_message(&inst);
+        let mut targets = Vec::new();
+        for client in self.display_clients.iter_mut() {
+            if client.routing.allows(&inst) {
+                if let Some(mode) = &mode {
+                    client.mode = mode.clone();
+                }
+                targets.push(client.config.name.clone());
+            }
+        }
+
+        if targets.is_empty() {
+            trace!("No display client is routed to receive this message. Skipping sending");
+            return;
+        }
+
+        match self.comm_channel.send_out_command_to_clients(targets, inst) {
             Ok(()) => (),
             Err(e) => error!(
                 "Failed to send out instruction to client: {}",
@@ -1425,6 +2174,31 @@ impl ServerStateMachine {
         }
     }
 
+    /// Sends to exactly one display client, ignoring its routing
+    fn send_message_to_display_client(&mut self, name: &str, inst: MessageFromServerToClient) {
+        if let Some(mode) = DisplayClientMode::after_message(&inst) {
+            if let Some(client) = self
+                .display_clients
+                .iter_mut()
+                .find(|c| c.config.name == name)
+            {
+                client.mode = mode;
+            }
+        }
+
+        match self
+            .comm_channel
+            .send_out_command_to_clients(vec![String::from(name)], inst)
+        {
+            Ok(()) => (),
+            Err(e) => error!(
+                "Failed to send out instruction to client '{}': {}",
+                name,
+                e.to_string()
+            ),
+        }
+    }
+
     fn send_message_to_bib_server(&mut self, inst: MessageToBibServer) {
         if self.comm_channel.bib_server_there_to_receive() {
             match self.comm_channel.send_out_command_to_bib_server(inst) {
@@ -1439,6 +2213,13 @@ impl ServerStateMachine {
         }
     }
 
+    fn send_message_to_live_feed(&mut self, event: LiveFeedEvent) {
+        match self.comm_channel.send_out_live_feed_event(event) {
+            Ok(()) => (),
+            Err(e) => error!("Failed to send out event to live feed: {}", e.to_string()),
+        }
+    }
+
     fn send_message_to_web_control(&mut self, inst: MessageToWebControl) {
         if self.comm_channel.web_control_there_to_receive() {
             match self.comm_channel.send_out_command_to_web_control(inst) {
@@ -1470,8 +2251,20 @@ impl ServerStateMachine {
         }
     }
 
-    fn set_main_display_state(&mut self, state: bool) {
-        self.display_connected = state;
+    fn set_display_state(&mut self, name: &str, state: bool) {
+        match self
+            .display_clients
+            .iter_mut()
+            .find(|c| c.config.name == name)
+        {
+            Some(client) => {
+                if !state {
+                    client.mode = DisplayClientMode::Created;
+                }
+                client.connected = state;
+            }
+            None => error!("Connection state for unknown display client '{}'", name),
+        }
     }
 
     pub fn allows_external_connections(&self) -> bool {
@@ -1484,6 +2277,9 @@ pub enum ClientState {
     Idle,
     DisplayText(String),
     DisplayExternalFrame(ImageMeta),
+    QrCode(QrCodeState),
+    CallRoom(Vec<HeatStartList>),
+    FieldEvent(FieldEventData),
     Advertisements,
     Timing(TimingStateMachine),
     TimingEmptyInit, // will immediately switch to Timing, but read the state machine from self.timing_state_machine_storage
@@ -1503,6 +2299,7 @@ pub struct ClientStateMachine {
     pub permanent_icons_storage: IconsStorage,
     pub current_frame_dimensions: Option<(u32, u32)>,
     pub server_imposed_settings: ServerImposedSettings,
+    pub ticker: TickerState,
     timing_state_machine_storage: Option<TimingStateMachine>,
     timing_settings_template: TimingSettings,
     outbound_connection_open: bool,
@@ -1526,6 +2323,7 @@ impl ClientStateMachine {
             permanent_icons_storage: icons_storage,
             current_frame_dimensions: None,
             server_imposed_settings: ServerImposedSettings::new(args),
+            ticker: TickerState::new(),
             timing_state_machine_storage: None,
             timing_settings_template: TimingSettings::new(args),
             outbound_connection_open: false,
@@ -1562,6 +2360,15 @@ impl ClientStateMachine {
                 debug!("Server requested display mode to be switched to text");
                 self.switch_mode_with_stashing_timing_state(ClientState::DisplayText(text));
             }
+            MessageFromServerToClient::DisplayQrCode(payload) => {
+                debug!("Server requested display mode to be switched to a QR code");
+                match QrCodeState::new(payload) {
+                    Ok(qr_code) => {
+                        self.switch_mode_with_stashing_timing_state(ClientState::QrCode(qr_code))
+                    }
+                    Err(e) => error!("{}", e),
+                }
+            }
             MessageFromServerToClient::ServerImposedSettings(settings) => {
                 // size/position properties of the window are not reflected in internal state but by the real window -> needs instructions to change
                 let (x, y, w, h) = settings.position;
@@ -1615,6 +2422,7 @@ impl ClientStateMachine {
                         .purge_from_cache(item_currently_in_cache);
                 }
                 self.permanent_images_storage.advertisement_images = Vec::new();
+                self.permanent_images_storage.advertisement_image_names = Vec::new();
 
                 // write new image data
                 for (new_name, new_data) in new_images {
@@ -1626,6 +2434,9 @@ impl ClientStateMachine {
                         Ok(img) => {
                             info!("Loaded advertisement image: {}", new_name);
                             self.permanent_images_storage.advertisement_images.push(img);
+                            self.permanent_images_storage
+                                .advertisement_image_names
+                                .push(new_name);
                         }
                     }
                 }
@@ -1705,6 +2516,18 @@ impl ClientStateMachine {
                     _ => {}
                 };
             }
+            MessageFromServerToClient::CallRoom(heats) => {
+                debug!("Server requested the call room with {} heats", heats.len());
+                self.switch_mode_with_stashing_timing_state(ClientState::CallRoom(heats));
+            }
+            MessageFromServerToClient::FieldEvent(data) => {
+                debug!("Server requested field event {}", data.event.name);
+                self.switch_mode_with_stashing_timing_state(ClientState::FieldEvent(data));
+            }
+            MessageFromServerToClient::TickerQueue(queue) => {
+                debug!("Server updated the ticker to {} messages", queue.len());
+                self.ticker.set_queue(queue);
+            }
             MessageFromServerToClient::ClientInternal(client_internal_message) => {
                 match client_internal_message {
                     ClientInternalMessageFromServerToClient::EmitTimingSettingsUpdate(set) => {
@@ -12,6 +12,7 @@ mod instructions;
 mod interface;
 mod json;
 mod nrbf;
+mod nrbf_records;
 mod productkey;
 mod server;
 mod times;
@@ -30,7 +31,10 @@ pub use client::run_client;
 pub use idcapture::run_idcapture_server;
 pub use productkey::initialize_product_key_system;
 pub use server::run_server;
+pub use webserver::print_webcontrol_password_hash;
 pub use wind::run_wind_server;
 
 // export data for code generation
-pub use webserver::{MessageFromWebControl, MessageToWebControl};
+pub use webserver::{
+    MessageFromWebControl, MessageToWebControl, WebControlLoginRequest, WebControlLoginResponse,
+};
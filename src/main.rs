@@ -3,8 +3,8 @@ extern crate log;
 
 use clap::{crate_version, Parser};
 use jta_display_wall_adapter::{
-    initialize_product_key_system, open_webcontrol, run_client, run_idcapture_server, run_server,
-    run_wind_server, Args, Mode,
+    initialize_product_key_system, open_webcontrol, print_webcontrol_password_hash, run_client,
+    run_idcapture_server, run_server, run_wind_server, Args, Mode,
 };
 use std::net::TcpListener;
 
@@ -84,7 +84,11 @@ async fn main() -> std::io::Result<()> {
         Mode::Client => run_client(&args).await,
         Mode::Wind => run_wind_server(&args).await,
         Mode::IDCapture => run_idcapture_server(&args).await,
-        Mode::Key => initialize_product_key_system(&args),
+        Mode::Key => {
+            if !print_webcontrol_password_hash(&args) {
+                initialize_product_key_system(&args)
+            }
+        }
     }
 
     Ok(())
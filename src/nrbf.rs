@@ -1,14 +1,16 @@
-use crate::hex::{get_hex_repr, take_until_and_consume};
-use crate::hex::{NomErr, NomError, NomErrorKind, NomFailure};
+use crate::hex::get_hex_repr;
 use crate::idcapture::format::IDCaptureMessage;
+use crate::nrbf_records::{decode_nrbf_records, NrbfObject, NrbfValue};
+use crate::server::timing_program_types::{
+    TimingProgramProperty, TimingProgramPropertyValue, TimingProgramTable,
+};
 use crate::times::DayTime;
-use nom::bytes::complete::{tag, take, take_until};
-use nom::combinator::{not, peek};
-use nom::Parser;
-use nom::{branch::alt, IResult};
 use std::fmt::Display;
 use std::time::Duration;
 
+#[cfg(test)]
+mod decoding_tests;
+
 use crate::{
     args::Args,
     instructions::{
@@ -193,324 +195,280 @@ fn check_nrbf_termination_bytes(possible_packet: &[u8]) -> bool {
 
 /// Decode the message custom action
 fn decode_single_nrbf(args: &Args, packet: &[u8]) -> Result<NrbfDecodedInstruction, String> {
-    match parse_any_known_command(packet) {
-        Err(e) => warn!("Nom parser Error: {}", e.to_string()),
-        Ok((_, command)) => return Ok(command),
-    }
-
-    if args.hexdump_incoming_communication {
-        warn!("No command could be parsed from the following:");
-        text_log_bytes(packet);
+    match decode_nrbf_records(packet).and_then(|root| instruction_from_records(&root)) {
+        Ok(command) => Ok(command),
+        Err(e) => {
+            warn!("NRBF message could not be decoded: {}", e);
+            if args.hexdump_incoming_communication {
+                text_log_bytes(packet);
+            }
+            Err(e)
+        }
     }
-
-    Err("No matching command could be parsed".into())
-}
-
-fn i32_from_bytes(bytes: &[u8]) -> i32 {
-    return i32::from_le_bytes(bytes.try_into().unwrap_or([0x00, 0x00, 0x00, 0x00]));
 }
 
-fn leb128_u32(input: &[u8]) -> IResult<&[u8], u32> {
-    let mut result: u32 = 0;
-    let mut shift = 0;
-    let mut consumed = 0;
+/// Maps the object graph of a decoded message onto the instructions we know
+fn instruction_from_records(root: &NrbfValue) -> Result<NrbfDecodedInstruction, String> {
+    let packet = root
+        .as_object()
+        .ok_or(String::from("NRBF root is not an object"))?;
 
-    for &byte in input.iter() {
-        let value = (byte & 0x7F) as u32;
-        result |= value << shift;
-        consumed += 1;
+    // the timing program bundles layout changes into a List<object> of packets
+    if let Some(items) = root.list_items() {
+        return instruction_from_packet_list(&items);
+    }
 
-        if byte & 0x80 == 0 {
-            // MSB not set → last byte
-            return Ok((&input[consumed..], result));
+    match packet.short_class_name() {
+        "ClientInfo" => Ok(NrbfDecodedInstruction::FromTimingProgram(
+            InstructionFromTimingProgram::ClientInfo,
+        )),
+        "Clear" => Ok(NrbfDecodedInstruction::FromTimingProgram(
+            InstructionFromTimingProgram::Clear,
+        )),
+        "SetProperty" => Ok(NrbfDecodedInstruction::FromTimingProgram(
+            InstructionFromTimingProgram::SetProperty(vec![property_from_record(packet)]),
+        )),
+        "ServerInfo" => Ok(NrbfDecodedInstruction::FromExternalDisplayProgram(
+            InstructionFromExternalDisplayProgram::ServerInfo,
+        )),
+        "CurrentView" => {
+            let image_data = match packet.member("ImagesSerialized") {
+                Some(NrbfValue::Bytes(bytes)) => bytes,
+                _ => return Err(String::from("CurrentView packet without image data")),
+            };
+            // the bitmap starts with 'BM', everything in front is not part of the image
+            let image_start = image_data
+                .windows(2)
+                .position(|w| w == b"BM")
+                .ok_or(String::from("CurrentView packet does not contain a bitmap"))?;
+
+            Ok(NrbfDecodedInstruction::FromExternalDisplayProgram(
+                InstructionFromExternalDisplayProgram::Frame(image_data[image_start..].to_vec()),
+            ))
         }
-
-        shift += 7;
-
-        // u32 can only be up to 32 bits → max 5 bytes
-        if shift >= 32 {
-            return Err(NomFailure(NomError::new(
-                input,
-                nom::error::ErrorKind::TooLarge,
-            )));
+        "SeekTimestamp" => {
+            let ticks = match packet.member("Timestamp") {
+                Some(NrbfValue::TimeSpan(ticks)) if *ticks >= 0 => *ticks,
+                _ => return Err(String::from("SeekTimestamp packet without valid timestamp")),
+            };
+            let duration = Duration::from_nanos_u128(ticks as u128 * 100);
+
+            Ok(NrbfDecodedInstruction::FromIdcaptureServer(
+                IDCaptureMessage::JumpToTime(DayTime::from(duration)),
+            ))
         }
+        other => Err(format!("Unknown NRBF packet '{}'", other)),
     }
-
-    Err(nom::Err::Incomplete(nom::Needed::new(1)))
 }
 
-fn parse_failing_command(input: &[u8]) -> IResult<&[u8], NrbfDecodedInstruction> {
-    Err(NomErr(NomError::new(input, NomErrorKind::Tag))) // test, this can never be parsed
-}
-
-const CLIENT_INFO_MARKER: [u8; 87] = [
-    // Offset 0x00000034 to 0x0000008A
-    0x43, 0x75, 0x6C, 0x74, 0x75, 0x72, 0x65, 0x3D, 0x6E, 0x65, 0x75, 0x74, 0x72, 0x61, 0x6C, 0x2C,
-    0x20, 0x50, 0x75, 0x62, 0x6C, 0x69, 0x63, 0x4B, 0x65, 0x79, 0x54, 0x6F, 0x6B, 0x65, 0x6E, 0x3D,
-    0x6E, 0x75, 0x6C, 0x6C, 0x05, 0x01, 0x00, 0x00, 0x00, 0x2D, 0x44, 0x69, 0x73, 0x70, 0x6C, 0x61,
-    0x79, 0x42, 0x6F, 0x61, 0x72, 0x64, 0x2E, 0x43, 0x6F, 0x6D, 0x6D, 0x75, 0x6E, 0x69, 0x63, 0x61,
-    0x74, 0x69, 0x6F, 0x6E, 0x2E, 0x50, 0x61, 0x63, 0x6B, 0x65, 0x74, 0x73, 0x2E, 0x43, 0x6C, 0x69,
-    0x65, 0x6E, 0x74, 0x49, 0x6E, 0x66, 0x6F,
-];
-fn parse_client_info_command(input: &[u8]) -> IResult<&[u8], NrbfDecodedInstruction> {
-    let (input, _) = take_until_and_consume(&CLIENT_INFO_MARKER[..], input)?;
-
-    // 0C0200000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05010000002D446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E436C69656E74496E666F04000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C64153C4E616D653E6B5F5F4261636B696E674669656C64203C4170706C69636174696F6E4E616D653E6B5F5F4261636B696E674669656C64233C41706C6C69636174696F6E56657273696F6E3E6B5F5F4261636B696E674669656C6400010101080200000000000000060300000007544146205469430A0A0B0101020203030000
-
-    Ok((
-        input,
-        NrbfDecodedInstruction::FromTimingProgram(InstructionFromTimingProgram::ClientInfo),
-    ))
+fn string_member<'a>(packet: &'a NrbfObject, name: &str) -> Option<&'a str> {
+    packet.member(name).and_then(|v| v.as_str())
 }
 
-const FREETEXT_MARKER: [u8; 45] = [
-    0x42, 0x61, 0x63, 0x6B, 0x69, 0x6E, 0x67, 0x46, 0x69, 0x65, 0x6C, 0x64, 0x00, 0x01, 0x01, 0x00,
-    0x08, 0x01, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x07, 0x00, 0x00, 0x00, 0x08,
-    0x46, 0x72, 0x65, 0x65, 0x74, 0x65, 0x78, 0x74, 0x06, 0x08, 0x00, 0x00, 0x00,
-];
-fn parse_text_command(input: &[u8]) -> IResult<&[u8], NrbfDecodedInstruction> {
-    const TEXT_MARKER: [u8; 51] = [
-        0x42, 0x61, 0x63, 0x6B, 0x69, 0x6E, 0x67, 0x46, 0x69, 0x65, 0x6C, 0x64, 0x00, 0x01, 0x01,
-        0x01, 0x02, 0x08, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x09, 0x08, 0x00, 0x00,
-        0x00, 0x06, 0x09, 0x00, 0x00, 0x00, 0x04, 0x54, 0x65, 0x78, 0x74, 0x09, 0x09, 0x00, 0x00,
-        0x00, 0x06, 0x0A, 0x00, 0x00, 0x00,
-    ];
-
-    let (input, _) = take_until_and_consume(&FREETEXT_MARKER[..], input)?;
-    let (input, _) = take_until_and_consume(&TEXT_MARKER[..], input)?;
-    let (input, text_len) = leb128_u32(input)?;
-    debug!("Message Length {}", text_len);
-    let (input, text_bytes) = take(text_len)(input)?;
-
-    let text = str::from_utf8(text_bytes)
-        .map(|s| s.to_string())
-        .map_err(|_| NomFailure(NomError::new(input, NomErrorKind::Alpha)))?;
-
-    // 04010000007F53797374656D2E436F6C6C656374696F6E732E47656E657269632E4C69737460315B5B53797374656D2E4F626A6563742C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D5D03000000065F6974656D73055F73697A65085F76657273696F6E0500000808090200000003000000240000001002000000040000000903000000090400000009050000000A0C0600000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05030000002D446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E4C6F61644C61796F757404000000234C61796F7574426173652B3C4368616E6E656C3E6B5F5F4261636B696E674669656C64244C61796F7574426173652B3C46696C654E616D653E6B5F5F4261636B696E674669656C64204C61796F7574426173652B3C4E616D653E6B5F5F4261636B696E674669656C642A4C61796F7574426173652B3C53656E6443757272656E566965773E6B5F5F4261636B696E674669656C640001010008010600000000000000060700000008467265657465787406080000002B4C61796F75745F66353665353433312D663132632D346261322D626136382D3063666131383339633930650005040000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C6400010101020806000000000000000908000000060900000004546578740909000000060A00000014746573747465737474657374746573747465737405050000002D446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53686F774C61796F7574050000000B436C6561724F7468657273234C61796F7574426173652B3C4368616E6E656C3E6B5F5F4261636B696E674669656C64244C61796F7574426173652B3C46696C654E616D653E6B5F5F4261636B696E674669656C64204C61796F7574426173652B3C4E616D653E6B5F5F4261636B696E674669656C642A4C61796F7574426173652B3C53656E6443757272656E566965773E6B5F5F4261636B696E674669656C6400000101000108010600000001000000000A0908000000010B0101020203030000
-
-    Ok((
-        input,
-        NrbfDecodedInstruction::FromTimingProgram(InstructionFromTimingProgram::Freetext(text)),
-    ))
-}
-
-const CLEAR_MARKER: [u8; 40] = [
-    0x44, 0x69, 0x73, 0x70, 0x6C, 0x61, 0x79, 0x42, 0x6F, 0x61, 0x72, 0x64, 0x2E, 0x43, 0x6F, 0x6D,
-    0x6D, 0x75, 0x6E, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6F, 0x6E, 0x2E, 0x50, 0x61, 0x63, 0x6B, 0x65,
-    0x74, 0x73, 0x2E, 0x43, 0x6C, 0x65, 0x61, 0x72,
-];
-fn parse_clear_command(input: &[u8]) -> IResult<&[u8], NrbfDecodedInstruction> {
-    let (input, _) = take_until_and_consume(&CLEAR_MARKER[..], input)?;
-
-    // 0C0200000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C050100000028446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E436C65617202000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641F3C53656E6443757272656E566965773E6B5F5F4261636B696E674669656C64000008010200000000000000010B0101020203030000
-
-    Ok((
-        input,
-        NrbfDecodedInstruction::FromTimingProgram(InstructionFromTimingProgram::Clear),
-    ))
-}
-
-const SLIDESHOW_MARKER: [u8; 41] = [
-    0x42, 0x61, 0x63, 0x6B, 0x69, 0x6E, 0x67, 0x46, 0x69, 0x65, 0x6C, 0x64, 0x00, 0x01, 0x01, 0x00,
-    0x08, 0x01, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x0A, 0x00, 0x00, 0x00, 0x09,
-    0x53, 0x6C, 0x69, 0x64, 0x65, 0x73, 0x68, 0x6F, 0x77,
-];
-fn parse_slideshow_command(input: &[u8]) -> IResult<&[u8], NrbfDecodedInstruction> {
-    let (input, _) = take_until_and_consume(&SLIDESHOW_MARKER[..], input)?;
-
-    // 04010000007F53797374656D2E436F6C6C656374696F6E732E47656E657269632E4C69737460315B5B53797374656D2E4F626A6563742C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D5D03000000065F6974656D73055F73697A65085F76657273696F6E0500000808090200000006000000FA0000001002000000080000000903000000090400000009050000000906000000090700000009080000000D020C0900000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05030000002D446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E4C6F61644C61796F757404000000234C61796F7574426173652B3C4368616E6E656C3E6B5F5F4261636B696E674669656C64244C61796F7574426173652B3C46696C654E616D653E6B5F5F4261636B696E674669656C64204C61796F7574426173652B3C4E616D653E6B5F5F4261636B696E674669656C642A4C61796F7574426173652B3C53656E6443757272656E566965773E6B5F5F4261636B696E674669656C640001010008010900000000000000060A00000009536C69646573686F77060B0000002B4C61796F75745F61613432663431312D626330392D343830372D383964352D3832303232393637326365360005040000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C640001010102080900000000000000090B000000090A000000060D0000000E4368616E6765496E74657276616C080C80F0FA020000000001050000000400000000000000090B000000090A00000006100000000E5472616E736974696F6E4D6F6465091100000001060000000400000000000000090B000000090A0000000614000000045061746806150000000D4C6F676F735C317C4C6F676F7301070000000400000000000000090B000000090A00000006180000000652657065617408010105080000002D446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53686F774C61796F7574050000000B436C6561724F7468657273234C61796F7574426173652B3C4368616E6E656C3E6B5F5F4261636B696E674669656C64244C61796F7574426173652B3C46696C654E616D653E6B5F5F4261636B696E674669656C64204C61796F7574426173652B3C4E616D653E6B5F5F4261636B696E674669656C642A4C61796F7574426173652B3C53656E6443757272656E566965773E6B5F5F4261636B696E674669656C6400000101000108010900000001000000000A090B0000000105110000002A446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5472616E736974696F6E5479706573010000000776616C75655F5F000809000000010000000B0101020203030000
-
-    Ok((
-        input,
-        NrbfDecodedInstruction::FromTimingProgram(InstructionFromTimingProgram::Advertisements),
-    ))
-}
-
-const START_LIST_MARKER: [u8; 41] = [
-    0x42, 0x61, 0x63, 0x6B, 0x69, 0x6E, 0x67, 0x46, 0x69, 0x65, 0x6C, 0x64, 0x00, 0x01, 0x01, 0x00,
-    0x08, 0x01, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x15, 0x00, 0x00, 0x00, 0x09,
-    0x53, 0x74, 0x61, 0x72, 0x74, 0x6C, 0x69, 0x73, 0x74,
-];
-fn parse_startlist_command(input: &[u8]) -> IResult<&[u8], NrbfDecodedInstruction> {
-    let (input, _) = take_until_and_consume(&START_LIST_MARKER[..], input)?;
-
-    // 04010000007F53797374656D2E436F6C6C656374696F6E732E47656E657269632E4C69737460315B5B53797374656D2E4F626A6563742C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D5D03000000065F6974656D73055F73697A65085F76657273696F6E0500000808090200000011000000690100001002000000200000000903000000090400000009050000000906000000090700000009080000000909000000090A000000090B000000090C000000090D000000090E000000090F00000009100000000911000000091200000009130000000D0F0C1400000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05030000002D446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E4C6F61644C61796F757404000000234C61796F7574426173652B3C4368616E6E656C3E6B5F5F4261636B696E674669656C64244C61796F7574426173652B3C46696C654E616D653E6B5F5F4261636B696E674669656C64204C61796F7574426173652B3C4E616D653E6B5F5F4261636B696E674669656C642A4C61796F7574426173652B3C53656E6443757272656E566965773E6B5F5F4261636B696E674669656C64000101000801140000000000000006150000000953746172746C69737406160000002B4C61796F75745F65393231373733372D366539352D343334362D616137362D6333363562303331656430640005040000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C640001010102081400000000000000091600000006170000000A4576656E745469746C65061800000004546578740619000000133130306D2048C3BC7264656E2046726175656E010500000004000000000000000916000000061B000000124576656E744E616D6557697468436C61737309180000000919000000010600000004000000000000000916000000061F00000014556E6974446973706C61794E616D6553686F727409180000000A01070000000400000000000000091600000006220000000F556E6974446973706C61794E616D6509180000000A0108000000040000000000000009160000000625000000155068617365446973706C61794E616D6553686F727409180000000A0109000000040000000000000009160000000628000000105068617365446973706C61794E616D6509180000000A010A00000004000000000000000916000000062B000000154576656E74446973706C61794E616D6553686F727409180000000A010B00000004000000000000000916000000062E000000104576656E74446973706C61794E616D6509180000000A010C000000040000000000000009160000000631000000094576656E744E616D65091800000006330000000C3130306D2048C3BC7264656E010D00000004000000000000000916000000063500000009436C6173734E616D65091800000006370000000646726175656E010E00000004000000000000000916000000063900000012496E7465726E6174696F6E616C53686F7274063A0000000556616C7565063B00000003313048010F00000004000000000000000916000000063D00000007486561646572310918000000063F000000133130306D2048C3BC7264656E2046726175656E01100000000400000000000000091600000006410000000748656164657232091800000006430000000A5A6569746CC3A47566650111000000040000000000000009160000000645000000094C6973745469746C65091800000006470000000A53746172746C697374650112000000040000000000000009160000000649000000044C697374064A0000000444617461094B00000005130000002D446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53686F774C61796F7574050000000B436C6561724F7468657273234C61796F7574426173652B3C4368616E6E656C3E6B5F5F4261636B696E674669656C64244C61796F7574426173652B3C46696C654E616D653E6B5F5F4261636B696E674669656C64204C61796F7574426173652B3C4E616D653E6B5F5F4261636B696E674669656C642A4C61796F7574426173652B3C53656E6443757272656E566965773E6B5F5F4261636B696E674669656C6400000101000108011400000001000000000A0916000000010C4D0000004E53797374656D2E446174612C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D62373761356335363139333465303839054B00000023446973706C6179426F6172642E436F6D6D756E69636174696F6E2E4C6973744461746101000000163C5461626C653E6B5F5F4261636B696E674669656C64041553797374656D2E446174612E446174615461626C654D00000014000000094E000000054E0000001553797374656D2E446174612E446174615461626C65CB01000019446174615461626C652E52656D6F74696E6756657273696F6E18446174615461626C652E52656D6F74696E67466F726D617413446174615461626C652E5461626C654E616D6513446174615461626C652E4E616D65737061636510446174615461626C652E50726566697817446174615461626C652E4361736553656E7369746976651E446174615461626C652E6361736553656E736974697665416D6269656E7414446174615461626C652E4C6F63616C654C43494419446174615461626C652E4D696E696D756D436170616369747919446174615461626C652E4E6573746564496E4461746153657412446174615461626C652E547970654E616D651B446174615461626C652E52657065617461626C65456C656D656E741C446174615461626C652E457874656E64656450726F7065727469657317446174615461626C652E436F6C756D6E732E436F756E7421446174615461626C652E44617461436F6C756D6E5F302E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F302E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F302E50726566697824446174615461626C652E44617461436F6C756D6E5F302E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F302E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F302E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F302E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F302E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F302E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F302E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F302E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F302E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F302E446174615479706522446174615461626C652E44617461436F6C756D6E5F302E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F302E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F302E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F302E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F302E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F302E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F312E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F312E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F312E50726566697824446174615461626C652E44617461436F6C756D6E5F312E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F312E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F312E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F312E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F312E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F312E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F312E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F312E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F312E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F312E446174615479706522446174615461626C652E44617461436F6C756D6E5F312E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F312E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F312E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F312E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F312E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F312E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F322E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F322E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F322E50726566697824446174615461626C652E44617461436F6C756D6E5F322E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F322E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F322E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F322E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F322E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F322E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F322E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F322E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F322E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F322E446174615479706522446174615461626C652E44617461436F6C756D6E5F322E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F322E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F322E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F322E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F322E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F322E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F332E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F332E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F332E50726566697824446174615461626C652E44617461436F6C756D6E5F332E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F332E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F332E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F332E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F332E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F332E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F332E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F332E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F332E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F332E446174615479706522446174615461626C652E44617461436F6C756D6E5F332E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F332E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F332E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F332E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F332E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F332E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F342E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F342E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F342E50726566697824446174615461626C652E44617461436F6C756D6E5F342E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F342E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F342E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F342E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F342E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F342E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F342E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F342E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F342E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F342E446174615479706522446174615461626C652E44617461436F6C756D6E5F342E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F342E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F342E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F342E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F342E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F342E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F352E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F352E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F352E50726566697824446174615461626C652E44617461436F6C756D6E5F352E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F352E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F352E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F352E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F352E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F352E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F352E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F352E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F352E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F352E446174615479706522446174615461626C652E44617461436F6C756D6E5F352E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F352E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F352E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F352E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F352E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F352E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F362E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F362E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F362E50726566697824446174615461626C652E44617461436F6C756D6E5F362E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F362E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F362E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F362E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F362E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F362E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F362E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F362E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F362E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F362E446174615479706522446174615461626C652E44617461436F6C756D6E5F362E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F362E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F362E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F362E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F362E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F362E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F372E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F372E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F372E50726566697824446174615461626C652E44617461436F6C756D6E5F372E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F372E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F372E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F372E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F372E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F372E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F372E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F372E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F372E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F372E446174615479706522446174615461626C652E44617461436F6C756D6E5F372E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F372E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F372E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F372E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F372E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F372E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F382E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F382E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F382E50726566697824446174615461626C652E44617461436F6C756D6E5F382E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F382E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F382E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F382E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F382E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F382E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F382E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F382E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F382E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F382E446174615479706522446174615461626C652E44617461436F6C756D6E5F382E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F382E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F382E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F382E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F382E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F382E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F392E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F392E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F392E50726566697824446174615461626C652E44617461436F6C756D6E5F392E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F392E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F392E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F392E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F392E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F392E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F392E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F392E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F392E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F392E446174615479706522446174615461626C652E44617461436F6C756D6E5F392E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F392E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F392E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F392E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F392E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F392E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31302E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31302E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31302E50726566697825446174615461626C652E44617461436F6C756D6E5F31302E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31302E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31302E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31302E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31302E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31302E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31302E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31302E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31302E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31302E446174615479706523446174615461626C652E44617461436F6C756D6E5F31302E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31302E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31302E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31302E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31302E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31302E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31312E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31312E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31312E50726566697825446174615461626C652E44617461436F6C756D6E5F31312E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31312E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31312E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31312E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31312E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31312E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31312E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31312E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31312E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31312E446174615479706523446174615461626C652E44617461436F6C756D6E5F31312E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31312E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31312E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31312E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31312E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31312E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31322E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31322E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31322E50726566697825446174615461626C652E44617461436F6C756D6E5F31322E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31322E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31322E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31322E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31322E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31322E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31322E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31322E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31322E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31322E446174615479706523446174615461626C652E44617461436F6C756D6E5F31322E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31322E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31322E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31322E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31322E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31322E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31332E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31332E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31332E50726566697825446174615461626C652E44617461436F6C756D6E5F31332E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31332E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31332E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31332E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31332E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31332E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31332E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31332E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31332E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31332E446174615479706523446174615461626C652E44617461436F6C756D6E5F31332E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31332E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31332E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31332E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31332E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31332E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31342E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31342E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31342E50726566697825446174615461626C652E44617461436F6C756D6E5F31342E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31342E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31342E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31342E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31342E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31342E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31342E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31342E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31342E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31342E446174615479706523446174615461626C652E44617461436F6C756D6E5F31342E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31342E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31342E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31342E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31342E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31342E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31352E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31352E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31352E50726566697825446174615461626C652E44617461436F6C756D6E5F31352E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31352E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31352E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31352E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31352E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31352E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31352E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31352E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31352E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31352E446174615479706523446174615461626C652E44617461436F6C756D6E5F31352E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31352E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31352E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31352E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31352E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31352E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31362E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31362E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31362E50726566697825446174615461626C652E44617461436F6C756D6E5F31362E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31362E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31362E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31362E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31362E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31362E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31362E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31362E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31362E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31362E446174615479706523446174615461626C652E44617461436F6C756D6E5F31362E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31362E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31362E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31362E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31362E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31362E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31372E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31372E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31372E50726566697825446174615461626C652E44617461436F6C756D6E5F31372E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31372E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31372E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31372E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31372E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31372E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31372E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31372E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31372E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31372E446174615479706523446174615461626C652E44617461436F6C756D6E5F31372E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31372E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31372E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31372E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31372E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31372E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31382E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31382E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31382E50726566697825446174615461626C652E44617461436F6C756D6E5F31382E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31382E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31382E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31382E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31382E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31382E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31382E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31382E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31382E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31382E446174615479706523446174615461626C652E44617461436F6C756D6E5F31382E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31382E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31382E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31382E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31382E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31382E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31392E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31392E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31392E50726566697825446174615461626C652E44617461436F6C756D6E5F31392E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31392E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31392E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31392E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31392E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31392E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31392E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31392E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31392E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31392E446174615479706523446174615461626C652E44617461436F6C756D6E5F31392E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31392E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31392E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31392E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31392E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31392E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F32302E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F32302E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F32302E50726566697825446174615461626C652E44617461436F6C756D6E5F32302E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F32302E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F32302E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F32302E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F32302E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F32302E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F32302E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F32302E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F32302E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F32302E446174615479706523446174615461626C652E44617461436F6C756D6E5F32302E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F32302E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F32302E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F32302E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F32302E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F32302E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F32312E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F32312E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F32312E50726566697825446174615461626C652E44617461436F6C756D6E5F32312E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F32312E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F32312E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F32312E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F32312E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F32312E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F32312E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F32312E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F32312E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F32312E446174615479706523446174615461626C652E44617461436F6C756D6E5F32312E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F32312E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F32312E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F32312E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F32312E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F32312E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F32322E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F32322E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F32322E50726566697825446174615461626C652E44617461436F6C756D6E5F32322E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F32322E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F32322E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F32322E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F32322E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F32322E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F32322E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F32322E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F32322E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F32322E446174615479706523446174615461626C652E44617461436F6C756D6E5F32322E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F32322E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F32322E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F32322E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F32322E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F32322E457874656E64656450726F7065727469657317446174615461626C655F302E436F6E73747261696E747316446174615461626C655F302E526F77732E436F756E7419446174615461626C655F302E5265636F7264732E436F756E7415446174615461626C655F302E526F7753746174657313446174615461626C655F302E5265636F72647314446174615461626C655F302E4E756C6C4269747315446174615461626C655F302E526F774572726F727318446174615461626C655F302E436F6C756D6E4572726F72730304010101000000000001000400010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010203000003030303030E53797374656D2E56657273696F6E1F53797374656D2E446174612E53657269616C697A6174696F6E466F726D61744D0000000101080801011E53797374656D2E446174612E50726F7065727479436F6C6C656374696F6E4D000000081753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091753797374656D2E446174612E4D617070696E67547970654D000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D654D000000091C53797374656D2E436F6C6C656374696F6E732E41727261794C69737408081B53797374656D2E436F6C6C656374696F6E732E42697441727261791C53797374656D2E436F6C6C656374696F6E732E41727261794C6973741C53797374656D2E436F6C6C656374696F6E732E41727261794C6973741C53797374656D2E436F6C6C656374696F6E732E486173687461626C651C53797374656D2E436F6C6C656374696F6E732E486173687461626C654D000000094F00000005B0FFFFFF1F53797374656D2E446174612E53657269616C697A6174696F6E466F726D6174010000000776616C75655F5F00084D00000001000000065100000000095100000009510000000001070400003200000001095100000000095200000017000000065300000005496E6465780A095100000005ABFFFFFF1753797374656D2E446174612E4D617070696E6754797065010000000776616C75655F5F00084D000000010000000100010000000000000000000000000000000953000000095700000000FFFFFFFF095800000009510000000A05A6FFFFFF1B53797374656D2E446174612E446174615365744461746554696D65010000000776616C75655F5F00084D00000003000000000000000000000009510000000A065B0000000853656C65637465640A095100000001A3FFFFFFABFFFFFF01000000010001000000000000000000000000000000095B000000095700000000FFFFFFFF096000000009510000000A019EFFFFFFA6FFFFFF03000000000000000000000009510000000A0663000000034269620A0951000000019BFFFFFFABFFFFFF010000000100010000000000000000000000000000000963000000095700000000FFFFFFFF096800000009510000000A0196FFFFFFA6FFFFFF03000000000000000000000009510000000A066B000000084C6173746E616D650A09510000000193FFFFFFABFFFFFF01000000010001000000000000000000000000000000096B000000095700000000FFFFFFFF096800000009510000000A018EFFFFFFA6FFFFFF03000000000000000000000009510000000A06730000000946697273746E616D650A0951000000018BFFFFFFABFFFFFF010000000100010000000000000000000000000000000973000000095700000000FFFFFFFF096800000009510000000A0186FFFFFFA6FFFFFF03000000000000000000000009510000000A067B00000005496E6669780A09510000000183FFFFFFABFFFFFF01000000010001000000000000000000000000000000097B000000095700000000FFFFFFFF096800000009510000000A017EFFFFFFA6FFFFFF03000000000000000000000009510000000A0683000000044E616D650A0951000000017BFFFFFFABFFFFFF010000000100010000000000000000000000000000000983000000095700000000FFFFFFFF096800000009510000000A0176FFFFFFA6FFFFFF03000000000000000000000009510000000A068B0000001646697273746E616D654C6173746E616D6555707065720A09510000000173FFFFFFABFFFFFF01000000010001000000000000000000000000000000098B000000095700000000FFFFFFFF096800000009510000000A016EFFFFFFA6FFFFFF03000000000000000000000009510000000A06930000000B446973706C61794E616D650A0951000000016BFFFFFFABFFFFFF010000000100010000000000000000000000000000000993000000095700000000FFFFFFFF096800000009510000000A0166FFFFFFA6FFFFFF03000000000000000000000009510000000A069B00000010446973706C61794E616D6553686F72740A09510000000163FFFFFFABFFFFFF01000000010001000000000000000000000000000000099B000000095700000000FFFFFFFF096800000009510000000A015EFFFFFFA6FFFFFF03000000000000000000000009510000000A06A30000000647656E6465720A0951000000015BFFFFFFABFFFFFF0100000001000100000000000000000000000000000009A3000000095700000000FFFFFFFF096800000009510000000A0156FFFFFFA6FFFFFF03000000000000000000000009510000000A06AB00000004436C75620A09510000000153FFFFFFABFFFFFF0100000001000100000000000000000000000000000009AB000000095700000000FFFFFFFF096800000009510000000A014EFFFFFFA6FFFFFF03000000000000000000000009510000000A06B3000000064E6174696F6E0A0951000000014BFFFFFFABFFFFFF0100000001000100000000000000000000000000000009B3000000095700000000FFFFFFFF096800000009510000000A0146FFFFFFA6FFFFFF03000000000000000000000009510000000A06BB00000004466C61670A09510000000143FFFFFFABFFFFFF0100000001000100000000000000000000000000000009BB000000095700000000FFFFFFFF096800000009510000000A013EFFFFFFA6FFFFFF03000000000000000000000009510000000A06C30000000D436C7562416E644E6174696F6E0A0951000000013BFFFFFFABFFFFFF0100000001000100000000000000000000000000000009C3000000095700000000FFFFFFFF096800000009510000000A0136FFFFFFA6FFFFFF03000000000000000000000009510000000A06CB00000005436C6173730A09510000000133FFFFFFABFFFFFF0100000001000100000000000000000000000000000009CB000000095700000000FFFFFFFF096800000009510000000A012EFFFFFFA6FFFFFF03000000000000000000000009510000000A06D300000008506F736974696F6E0A0951000000012BFFFFFFABFFFFFF0100000001000100000000000000000000000000000009D3000000095700000000FFFFFFFF096800000009510000000A0126FFFFFFA6FFFFFF03000000000000000000000009510000000A06DB0000000253420A09510000000123FFFFFFABFFFFFF0100000001000100000000000000000000000000000009DB000000095700000000FFFFFFFF096800000009510000000A011EFFFFFFA6FFFFFF03000000000000000000000009510000000A06E30000000753424C6162656C0A0951000000011BFFFFFFABFFFFFF0100000001000100000000000000000000000000000009E3000000095700000000FFFFFFFF096800000009510000000A0116FFFFFFA6FFFFFF03000000000000000000000009510000000A06EB0000000250420A09510000000113FFFFFFABFFFFFF0100000001000100000000000000000000000000000009EB000000095700000000FFFFFFFF096800000009510000000A010EFFFFFFA6FFFFFF03000000000000000000000009510000000A06F30000000750424C6162656C0A0951000000010BFFFFFFABFFFFFF0100000001000100000000000000000000000000000009F3000000095700000000FFFFFFFF096800000009510000000A0106FFFFFFA6FFFFFF03000000000000000000000009510000000A06FB0000000349524D0A09510000000103FFFFFFABFFFFFF0100000001000100000000000000000000000000000009FB000000095700000000FFFFFFFF096800000009510000000A01FEFEFFFFA6FFFFFF03000000000000000000000009510000000A06030100000648617349524D0A095100000001FBFEFFFFABFFFFFF010000000100010000000000000000000000000000000903010000095700000000FFFFFFFF096000000009510000000A01F6FEFFFFA6FFFFFF03000000000000000000000009510000000A090B0100000300000003000000090C010000090D010000090E010000090F0100000910010000044F0000000E53797374656D2E56657273696F6E04000000065F4D616A6F72065F4D696E6F72065F4275696C64095F5265766973696F6E00000000080808080200000000000000FFFFFFFFFFFFFFFF05520000001E53797374656D2E446174612E50726F7065727479436F6C6C656374696F6E070000000A4C6F6164466163746F720756657273696F6E08436F6D70617265721048617368436F646550726F7669646572084861736853697A65044B6579730656616C756573000003030005050B081C53797374656D2E436F6C6C656374696F6E732E49436F6D70617265722453797374656D2E436F6C6C656374696F6E732E4948617368436F646550726F7669646572084D000000EC51383F000000000A0A030000000911010000091201000004570000001F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657203000000044461746109556E697479547970650C417373656D626C794E616D65010001080A02000000095100000001580000005700000006140100000C53797374656D2E496E7433320400000006150100004B6D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6237376135633536313933346530383901600000005700000006160100000E53797374656D2E426F6F6C65616E04000000091501000001680000005700000006180100000D53797374656D2E537472696E67040000000915010000040B0100001C53797374656D2E436F6C6C656374696F6E732E41727261794C69737403000000065F6974656D73055F73697A65085F76657273696F6E0500000808091A0100000000000000000000040C0100001B53797374656D2E436F6C6C656374696F6E732E426974417272617903000000076D5F6172726179086D5F6C656E677468085F76657273696F6E070000080808091B0100000900000003000000010D0100000B010000091C0100001700000017000000010E0100000B010000091D0100001700000017000000040F0100001C53797374656D2E436F6C6C656374696F6E732E486173687461626C65070000000A4C6F6164466163746F720756657273696F6E08436F6D70617265721048617368436F646550726F7669646572084861736853697A65044B6579730656616C756573000003030005050B081C53797374656D2E436F6C6C656374696F6E732E49436F6D70617265722453797374656D2E436F6C6C656374696F6E732E4948617368436F646550726F766964657208EC51383F000000000A0A03000000091E010000091F01000001100100000F010000EC51383F000000000A0A0300000009200100000921010000101101000000000000101201000000000000101A010000000000000F1B010000010000000892000000101C0100002000000009220100000923010000092401000009250100000926010000092701000009280100000929010000092A010000092B010000092C010000092D010000092E010000092F0100000930010000093101000009320100000933010000093401000009350100000936010000093701000009380100000D09101D010000200000000939010000093A010000093B010000093C010000093D010000093E010000093F0100000940010000094101000009420100000943010000094401000009450100000946010000094701000009480100000949010000094A010000094B010000094C010000094D010000094E010000094F0100000D09101E01000000000000101F010000000000001020010000000000001021010000000000000F2201000003000000080000000001000000020000000F2301000003000000010000001124010000030000000650010000033437310651010000033436390652010000033437301125010000030000000653010000084E6163686E616D6506540100000454657374065501000003446F65112601000003000000065601000007566F726E616D650657010000084D656E736368696E0658010000044A616E651127010000030000000D031128010000030000000659010000104E4143484E414D4520566F726E616D65065A0100000D54455354204D656E736368696E065B01000008444F45204A616E65112901000003000000065C01000010566F726E616D65204E4143484E414D45065D0100000D4D656E736368696E2054455354065E010000084A616E6520444F45112A010000030000000D03112B010000030000000D03112C01000003000000065F0100000157095F010000095F010000112D0100000300000006600100000B56664C20427563686C6F65066101000012545356205363687761626DC3BC6E6368656E0662010000084C47205A7573616D112E01000003000000066301000003474552066401000003474552066501000003474552112F0100000300000009630100000964010000096501000011300100000300000006690100001056664C20427563686C6F652C20474552066A01000017545356205363687761626DC3BC6E6368656E2C20474552066B0100000D4C47205A7573616D2C20474552113101000003000000066C0100000157096C010000096C010000113201000003000000066D0100000133066E0100000134066F01000001351133010000030000000951000000095100000009510000001134010000030000000D031135010000030000000951000000095100000009510000001136010000030000000D031137010000030000000D030F38010000030000000100000001390100000C01000009710100000300000003000000013A0100000C01000009720100000300000003000000013B0100000C01000009730100000300000003000000013C0100000C01000009740100000300000003000000013D0100000C01000009750100000300000003000000013E0100000C01000009760100000300000003000000013F0100000C0100000977010000030000000300000001400100000C0100000978010000030000000300000001410100000C0100000979010000030000000300000001420100000C010000097A010000030000000300000001430100000C010000097B010000030000000300000001440100000C010000097C010000030000000300000001450100000C010000097D010000030000000300000001460100000C010000097E010000030000000300000001470100000C010000097F010000030000000300000001480100000C0100000980010000030000000300000001490100000C01000009810100000300000003000000014A0100000C01000009820100000300000003000000014B0100000C01000009830100000300000003000000014C0100000C01000009840100000300000003000000014D0100000C01000009850100000300000003000000014E0100000C01000009860100000300000003000000014F0100000C010000098701000003000000030000000F710100000100000008000000000F720100000100000008070000000F730100000100000008000000000F740100000100000008000000000F750100000100000008000000000F760100000100000008070000000F770100000100000008000000000F780100000100000008000000000F790100000100000008070000000F7A0100000100000008070000000F7B0100000100000008000000000F7C0100000100000008000000000F7D0100000100000008000000000F7E0100000100000008000000000F7F0100000100000008000000000F800100000100000008000000000F810100000100000008000000000F820100000100000008000000000F830100000100000008070000000F840100000100000008000000000F850100000100000008070000000F860100000100000008070000000F870100000100000008070000000B0101020203030000
-
-    Ok((
-        input,
-        NrbfDecodedInstruction::FromTimingProgram(InstructionFromTimingProgram::StartList),
-    ))
-}
-
-const TIMING_MARKER: [u8; 56] = [
-    0x53, 0x65, 0x6E, 0x64, 0x43, 0x75, 0x72, 0x72, 0x65, 0x6E, 0x56, 0x69, 0x65, 0x77, 0x3E, 0x6B,
-    0x5F, 0x5F, 0x42, 0x61, 0x63, 0x6B, 0x69, 0x6E, 0x67, 0x46, 0x69, 0x65, 0x6C, 0x64, 0x00, 0x01,
-    0x01, 0x00, 0x08, 0x01, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x21, 0x00, 0x00,
-    0x00, 0x06, 0x54, 0x69, 0x6D, 0x69, 0x6E, 0x67,
-];
-fn parse_timing_command(input: &[u8]) -> IResult<&[u8], NrbfDecodedInstruction> {
-    let (input, _) = take_until_and_consume(&TIMING_MARKER[..], input)?;
-
-    // 04010000007F53797374656D2E436F6C6C656374696F6E732E47656E657269632E4C69737460315B5B53797374656D2E4F626A6563742C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D5D03000000065F6974656D73055F73697A65085F76657273696F6E050000080809020000001D000000990100001002000000200000000903000000090400000009050000000906000000090700000009080000000909000000090A000000090B000000090C000000090D000000090E000000090F0000000910000000091100000009120000000913000000091400000009150000000916000000091700000009180000000919000000091A000000091B000000091C000000091D000000091E000000091F0000000D030C2000000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05030000002D446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E4C6F61644C61796F757404000000234C61796F7574426173652B3C4368616E6E656C3E6B5F5F4261636B696E674669656C64244C61796F7574426173652B3C46696C654E616D653E6B5F5F4261636B696E674669656C64204C61796F7574426173652B3C4E616D653E6B5F5F4261636B696E674669656C642A4C61796F7574426173652B3C53656E6443757272656E566965773E6B5F5F4261636B696E674669656C64000101000801200000000000000006210000000654696D696E6706220000002B4C61796F75745F36383335353263612D356334302D346331362D613031642D3465363631376461343962340005040000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C640001010102082000000000000000092200000006230000000A4576656E745469746C65062400000004546578740625000000133130306D2048C3BC7264656E2046726175656E0105000000040000000000000009220000000627000000124576656E744E616D6557697468436C61737309240000000925000000010600000004000000000000000922000000062B00000014556E6974446973706C61794E616D6553686F727409240000000A010700000004000000000000000922000000062E0000000F556E6974446973706C61794E616D6509240000000A0108000000040000000000000009220000000631000000155068617365446973706C61794E616D6553686F727409240000000A0109000000040000000000000009220000000634000000105068617365446973706C61794E616D6509240000000A010A000000040000000000000009220000000637000000154576656E74446973706C61794E616D6553686F727409240000000A010B00000004000000000000000922000000063A000000104576656E74446973706C61794E616D6509240000000A010C00000004000000000000000922000000063D000000094576656E744E616D650924000000063F0000000C3130306D2048C3BC7264656E010D00000004000000000000000922000000064100000009436C6173734E616D65092400000006430000000646726175656E010E00000004000000000000000922000000064500000012496E7465726E6174696F6E616C53686F727406460000000556616C7565064700000003313048010F00000004000000000000000922000000064900000007486561646572310924000000064B000000133130306D2048C3BC7264656E2046726175656E011000000004000000000000000922000000064D00000007486561646572320924000000064F0000000A5A6569746CC3A475666501110000000400000000000000092200000006510000000454696D650924000000065300000003302E3001120000000400000000000000092200000009510000000924000000065700000003302E3001130000000400000000000000092200000006590000000848617354696D65320946000000080100011400000004000000000000000922000000065C0000000554696D65320924000000065E00000000011500000004000000000000000922000000066000000007486173496E666F0946000000080100011600000004000000000000000922000000066300000004496E666F0924000000095E00000001170000000400000000000000092200000006670000000748617357696E640946000000080100011800000004000000000000000922000000066A0000000957696E644C6162656C0924000000095E000000011900000004000000000000000922000000066E0000000457696E640924000000095E000000011A0000000400000000000000092200000006720000000B4861734C617073546F476F0946000000080100011B0000000400000000000000092200000006750000000E4C617073546F476F4E756D6265720924000000095E000000011C0000000400000000000000092200000006790000000C4C617073546F476F496E666F0924000000095E000000011D00000004000000000000000922000000067D0000000A497350726553746172740946000000080101011E00000004000000000000000922000000068000000011486173436F6E74696E756F757357696E640946000000080100051F0000002D446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53686F774C61796F7574050000000B436C6561724F7468657273234C61796F7574426173652B3C4368616E6E656C3E6B5F5F4261636B696E674669656C64244C61796F7574426173652B3C46696C654E616D653E6B5F5F4261636B696E674669656C64204C61796F7574426173652B3C4E616D653E6B5F5F4261636B696E674669656C642A4C61796F7574426173652B3C53656E6443757272656E566965773E6B5F5F4261636B696E674669656C6400000101000108012000000001000000000A0922000000010B0101020203030000
-
-    Ok((
-        input,
-        NrbfDecodedInstruction::FromTimingProgram(InstructionFromTimingProgram::Timing),
-    ))
-}
-
-fn parse_set_property_command(input: &[u8]) -> IResult<&[u8], NrbfDecodedInstruction> {
-    not(peek(alt((
-        tag(&RESULT_LIST_MARKER[..]),
-        tag(&TIMING_MARKER[..]),
-        tag(&START_LIST_MARKER[..]),
-        tag(&SLIDESHOW_MARKER[..]),
-        tag(&CLEAR_MARKER[..]),
-        tag(&FREETEXT_MARKER[..]),
-        tag(&CLIENT_INFO_MARKER[..]),
-        tag(&SERVER_INFO_MARKER[..]),
-        tag(&SEND_FRAME_MARKER[..]),
-    ))))
-    .parse(input)?;
-
-    const SET_PROPERTY_MARKER: [u8; 46] = [
-        0x44, 0x69, 0x73, 0x70, 0x6C, 0x61, 0x79, 0x42, 0x6F, 0x61, 0x72, 0x64, 0x2E, 0x43, 0x6F,
-        0x6D, 0x6D, 0x75, 0x6E, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6F, 0x6E, 0x2E, 0x50, 0x61, 0x63,
-        0x6B, 0x65, 0x74, 0x73, 0x2E, 0x53, 0x65, 0x74, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74,
-        0x79,
-    ];
-
-    let (input, _) = take_until_and_consume(&SET_PROPERTY_MARKER[..], input)?;
-
-    // 0C0200000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05010000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C64000101010208020000000000000006030000002B4C61796F75745F36383335353263612D356334302D346331362D613031642D34653636313764613439623406040000000454696D6506050000000454657874060600000003302E300B01010202030300000001000000FFFFFFFF01000000000000000C0200000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05010000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C64000101010208020000000000000006030000002B4C61796F75745F36383335353263612D356334302D346331362D613031642D34653636313764613439623406040000000554696D6532060500000004546578740606000000000B01010202030300000001000000FFFFFFFF01000000000000000C0200000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05010000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C64000101010208020000000000000006030000002B4C61796F75745F36383335353263612D356334302D346331362D613031642D346536363137646134396234060400000007486173496E666F06050000000556616C75650801000B01010202030300000001000000FFFFFFFF01000000000000000C0200000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05010000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C64000101010208020000000000000006030000002B4C61796F75745F36383335353263612D356334302D346331362D613031642D34653636313764613439623406040000000848617354696D653206050000000556616C75650801000B01010202030300000001000000FFFFFFFF01000000000000000C0200000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05010000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C64000101010208020000000000000006030000002B4C61796F75745F36383335353263612D356334302D346331362D613031642D346536363137646134396234060400000004496E666F060500000004546578740606000000000B0101020203030000
-    // 0C0200000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05010000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C64000101010208020000000000000006030000002B4C61796F75745F36383335353263612D356334302D346331362D613031642D34653636313764613439623406040000000748617357696E6406050000000556616C75650801000B01010202030300000001000000FFFFFFFF01000000000000000C0200000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05010000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C64000101010208020000000000000006030000002B4C61796F75745F36383335353263612D356334302D346331362D613031642D34653636313764613439623406040000000957696E644C6162656C060500000004546578740606000000000B01010202030300000001000000FFFFFFFF01000000000000000C0200000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05010000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C64000101010208020000000000000006030000002B4C61796F75745F36383335353263612D356334302D346331362D613031642D34653636313764613439623406040000000457696E64060500000004546578740606000000000B01010202030300000001000000FFFFFFFF01000000000000000C0200000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05010000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C64000101010208020000000000000006030000002B4C61796F75745F36383335353263612D356334302D346331362D613031642D34653636313764613439623406040000000E4C617073546F476F4E756D626572060500000004546578740606000000000B01010202030300000001000000FFFFFFFF01000000000000000C0200000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05010000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C64000101010208020000000000000006030000002B4C61796F75745F36383335353263612D356334302D346331362D613031642D34653636313764613439623406040000000B4861734C617073546F476F06050000000556616C75650801000B01010202030300000001000000FFFFFFFF01000000000000000C0200000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05010000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C64000101010208020000000000000006030000002B4C61796F75745F36383335353263612D356334302D346331362D613031642D34653636313764613439623406040000000C4C617073546F476F496E666F060500000004546578740606000000000B01010202030300000001000000FFFFFFFF01000000000000000C0200000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05010000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C64000101010208020000000000000006030000002B4C61796F75745F36383335353263612D356334302D346331362D613031642D346536363137646134396234060400000011486173436F6E74696E756F757357696E6406050000000556616C75650801000B01010202030300000001000000FFFFFFFF01000000000000000C0200000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05010000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C64000101010208020000000000000006030000002B4C61796F75745F36383335353263612D356334302D346331362D613031642D34653636313764613439623406040000000A4973507265537461727406050000000556616C75650801010B01010202030300000001000000FFFFFFFF010000000000000004010000007F53797374656D2E436F6C6C656374696F6E732E47656E657269632E4C69737460315B5B53797374656D2E4F626A6563742C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D5D03000000065F6974656D73055F73697A65085F76657273696F6E05000008080902000000020000009C010000100200000020000000090300000009040000000D1E0C0500000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05030000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C64000101010208050000000000000006060000002B4C61796F75745F36383335353263612D356334302D346331362D613031642D34653636313764613439623406070000000454696D6506080000000454657874060900000003302E30010400000003000000000000000906000000060B0000000A49735072655374617274060C0000000556616C75650801000B0101020203030000
-    // info text (maybe somewhat interesting to parse...)
-    // 0C0200000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05010000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C64000101010208020000000000000006030000002B4C61796F75745F64623030333234622D613137352D343931612D626334352D336438373639313362663064060400000004496E666F06050000000454657874060600000005313230306D0B01010202030300000001000000FFFFFFFF01000000000000000C0200000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05010000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C64000101010208020000000000000006030000002B4C61796F75745F64623030333234622D613137352D343931612D626334352D336438373639313362663064060400000007486173496E666F06050000000556616C75650801010B0101020203030000
-    // results update
-    // 04010000007F53797374656D2E436F6C6C656374696F6E732E47656E657269632E4C69737460315B5B53797374656D2E4F626A6563742C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D5D03000000065F6974656D73055F73697A65085F76657273696F6E050000080809020000000F0000005F0400001002000000200000000903000000090400000009050000000906000000090700000009080000000909000000090A000000090B000000090C000000090D000000090E000000090F000000091000000009110000000D110C1200000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05030000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C64000101010208120000000000000006130000002B4C61796F75745F33373563336233372D353061622D346262302D616336322D61633836326563353537636506140000000A4576656E745469746C65061500000004546578740616000000133130306D2048C3BC7264656E2046726175656E0104000000030000000000000009130000000618000000124576656E744E616D6557697468436C61737309150000000916000000010500000003000000000000000913000000061C00000014556E6974446973706C61794E616D6553686F727409150000000A010600000003000000000000000913000000061F0000000F556E6974446973706C61794E616D6509150000000A0107000000030000000000000009130000000622000000155068617365446973706C61794E616D6553686F727409150000000A0108000000030000000000000009130000000625000000105068617365446973706C61794E616D6509150000000A0109000000030000000000000009130000000628000000154576656E74446973706C61794E616D6553686F727409150000000A010A00000003000000000000000913000000062B000000104576656E74446973706C61794E616D6509150000000A010B00000003000000000000000913000000062E000000094576656E744E616D65091500000006300000000C3130306D2048C3BC7264656E010C00000003000000000000000913000000063200000009436C6173734E616D65091500000006340000000646726175656E010D00000003000000000000000913000000063600000012496E7465726E6174696F6E616C53686F727406370000000556616C7565063800000003313048010E00000003000000000000000913000000063A0000000957696E644C6162656C0915000000063C00000000010F00000003000000000000000913000000063E0000000457696E640915000000093C00000001100000000300000000000000091300000006420000000748617357696E6409370000000801000111000000030000000000000009130000000645000000044C6973740646000000044461746109470000000C480000004E53797374656D2E446174612C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D62373761356335363139333465303839054700000023446973706C6179426F6172642E436F6D6D756E69636174696F6E2E4C6973744461746101000000163C5461626C653E6B5F5F4261636B696E674669656C64041553797374656D2E446174612E446174615461626C654800000012000000094900000005490000001553797374656D2E446174612E446174615461626C659201000019446174615461626C652E52656D6F74696E6756657273696F6E18446174615461626C652E52656D6F74696E67466F726D617413446174615461626C652E5461626C654E616D6513446174615461626C652E4E616D65737061636510446174615461626C652E50726566697817446174615461626C652E4361736553656E7369746976651E446174615461626C652E6361736553656E736974697665416D6269656E7414446174615461626C652E4C6F63616C654C43494419446174615461626C652E4D696E696D756D436170616369747919446174615461626C652E4E6573746564496E4461746153657412446174615461626C652E547970654E616D651B446174615461626C652E52657065617461626C65456C656D656E741C446174615461626C652E457874656E64656450726F7065727469657317446174615461626C652E436F6C756D6E732E436F756E7421446174615461626C652E44617461436F6C756D6E5F302E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F302E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F302E50726566697824446174615461626C652E44617461436F6C756D6E5F302E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F302E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F302E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F302E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F302E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F302E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F302E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F302E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F302E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F302E446174615479706522446174615461626C652E44617461436F6C756D6E5F302E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F302E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F302E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F302E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F302E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F302E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F312E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F312E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F312E50726566697824446174615461626C652E44617461436F6C756D6E5F312E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F312E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F312E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F312E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F312E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F312E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F312E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F312E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F312E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F312E446174615479706522446174615461626C652E44617461436F6C756D6E5F312E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F312E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F312E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F312E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F312E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F312E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F322E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F322E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F322E50726566697824446174615461626C652E44617461436F6C756D6E5F322E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F322E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F322E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F322E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F322E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F322E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F322E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F322E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F322E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F322E446174615479706522446174615461626C652E44617461436F6C756D6E5F322E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F322E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F322E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F322E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F322E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F322E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F332E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F332E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F332E50726566697824446174615461626C652E44617461436F6C756D6E5F332E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F332E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F332E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F332E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F332E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F332E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F332E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F332E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F332E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F332E446174615479706522446174615461626C652E44617461436F6C756D6E5F332E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F332E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F332E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F332E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F332E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F332E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F342E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F342E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F342E50726566697824446174615461626C652E44617461436F6C756D6E5F342E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F342E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F342E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F342E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F342E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F342E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F342E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F342E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F342E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F342E446174615479706522446174615461626C652E44617461436F6C756D6E5F342E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F342E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F342E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F342E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F342E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F342E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F352E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F352E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F352E50726566697824446174615461626C652E44617461436F6C756D6E5F352E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F352E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F352E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F352E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F352E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F352E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F352E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F352E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F352E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F352E446174615479706522446174615461626C652E44617461436F6C756D6E5F352E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F352E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F352E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F352E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F352E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F352E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F362E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F362E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F362E50726566697824446174615461626C652E44617461436F6C756D6E5F362E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F362E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F362E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F362E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F362E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F362E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F362E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F362E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F362E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F362E446174615479706522446174615461626C652E44617461436F6C756D6E5F362E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F362E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F362E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F362E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F362E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F362E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F372E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F372E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F372E50726566697824446174615461626C652E44617461436F6C756D6E5F372E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F372E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F372E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F372E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F372E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F372E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F372E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F372E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F372E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F372E446174615479706522446174615461626C652E44617461436F6C756D6E5F372E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F372E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F372E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F372E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F372E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F372E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F382E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F382E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F382E50726566697824446174615461626C652E44617461436F6C756D6E5F382E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F382E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F382E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F382E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F382E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F382E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F382E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F382E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F382E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F382E446174615479706522446174615461626C652E44617461436F6C756D6E5F382E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F382E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F382E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F382E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F382E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F382E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F392E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F392E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F392E50726566697824446174615461626C652E44617461436F6C756D6E5F392E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F392E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F392E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F392E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F392E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F392E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F392E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F392E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F392E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F392E446174615479706522446174615461626C652E44617461436F6C756D6E5F392E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F392E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F392E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F392E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F392E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F392E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31302E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31302E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31302E50726566697825446174615461626C652E44617461436F6C756D6E5F31302E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31302E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31302E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31302E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31302E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31302E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31302E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31302E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31302E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31302E446174615479706523446174615461626C652E44617461436F6C756D6E5F31302E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31302E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31302E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31302E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31302E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31302E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31312E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31312E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31312E50726566697825446174615461626C652E44617461436F6C756D6E5F31312E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31312E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31312E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31312E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31312E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31312E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31312E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31312E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31312E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31312E446174615479706523446174615461626C652E44617461436F6C756D6E5F31312E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31312E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31312E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31312E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31312E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31312E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31322E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31322E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31322E50726566697825446174615461626C652E44617461436F6C756D6E5F31322E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31322E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31322E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31322E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31322E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31322E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31322E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31322E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31322E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31322E446174615479706523446174615461626C652E44617461436F6C756D6E5F31322E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31322E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31322E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31322E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31322E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31322E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31332E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31332E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31332E50726566697825446174615461626C652E44617461436F6C756D6E5F31332E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31332E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31332E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31332E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31332E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31332E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31332E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31332E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31332E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31332E446174615479706523446174615461626C652E44617461436F6C756D6E5F31332E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31332E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31332E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31332E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31332E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31332E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31342E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31342E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31342E50726566697825446174615461626C652E44617461436F6C756D6E5F31342E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31342E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31342E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31342E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31342E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31342E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31342E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31342E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31342E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31342E446174615479706523446174615461626C652E44617461436F6C756D6E5F31342E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31342E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31342E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31342E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31342E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31342E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31352E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31352E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31352E50726566697825446174615461626C652E44617461436F6C756D6E5F31352E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31352E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31352E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31352E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31352E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31352E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31352E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31352E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31352E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31352E446174615479706523446174615461626C652E44617461436F6C756D6E5F31352E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31352E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31352E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31352E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31352E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31352E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31362E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31362E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31362E50726566697825446174615461626C652E44617461436F6C756D6E5F31362E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31362E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31362E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31362E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31362E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31362E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31362E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31362E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31362E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31362E446174615479706523446174615461626C652E44617461436F6C756D6E5F31362E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31362E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31362E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31362E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31362E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31362E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31372E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31372E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31372E50726566697825446174615461626C652E44617461436F6C756D6E5F31372E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31372E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31372E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31372E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31372E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31372E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31372E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31372E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31372E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31372E446174615479706523446174615461626C652E44617461436F6C756D6E5F31372E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31372E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31372E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31372E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31372E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31372E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31382E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31382E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31382E50726566697825446174615461626C652E44617461436F6C756D6E5F31382E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31382E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31382E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31382E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31382E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31382E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31382E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31382E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31382E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31382E446174615479706523446174615461626C652E44617461436F6C756D6E5F31382E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31382E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31382E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31382E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31382E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31382E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31392E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31392E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31392E50726566697825446174615461626C652E44617461436F6C756D6E5F31392E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31392E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31392E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31392E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31392E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31392E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31392E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31392E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31392E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31392E446174615479706523446174615461626C652E44617461436F6C756D6E5F31392E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31392E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31392E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31392E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31392E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31392E457874656E64656450726F7065727469657317446174615461626C655F302E436F6E73747261696E747316446174615461626C655F302E526F77732E436F756E7419446174615461626C655F302E5265636F7264732E436F756E7415446174615461626C655F302E526F7753746174657313446174615461626C655F302E5265636F72647314446174615461626C655F302E4E756C6C4269747315446174615461626C655F302E526F774572726F727318446174615461626C655F302E436F6C756D6E4572726F72730304010101000000000001000400010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010203000003030303030E53797374656D2E56657273696F6E1F53797374656D2E446174612E53657269616C697A6174696F6E466F726D6174480000000101080801011E53797374656D2E446174612E50726F7065727479436F6C6C656374696F6E48000000081753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091753797374656D2E446174612E4D617070696E675479706548000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D6548000000091C53797374656D2E436F6C6C656374696F6E732E41727261794C69737408081B53797374656D2E436F6C6C656374696F6E732E42697441727261791C53797374656D2E436F6C6C656374696F6E732E41727261794C6973741C53797374656D2E436F6C6C656374696F6E732E41727261794C6973741C53797374656D2E436F6C6C656374696F6E732E486173687461626C651C53797374656D2E436F6C6C656374696F6E732E486173687461626C6548000000094A00000005B5FFFFFF1F53797374656D2E446174612E53657269616C697A6174696F6E466F726D6174010000000776616C75655F5F00084800000001000000093C000000093C000000093C0000000001070400003200000001093C00000000094D00000014000000064E00000005496E6465780A093C00000005B0FFFFFF1753797374656D2E446174612E4D617070696E6754797065010000000776616C75655F5F00084800000001000000010001000000000000000000000000000000094E000000095200000000FFFFFFFF0953000000093C0000000A05ABFFFFFF1B53797374656D2E446174612E446174615365744461746554696D65010000000776616C75655F5F000848000000030000000000000000000000093C0000000A06560000000853656C65637465640A093C00000001A8FFFFFFB0FFFFFF010000000100010000000000000000000000000000000956000000095200000000FFFFFFFF095B000000093C0000000A01A3FFFFFFABFFFFFF030000000000000000000000093C0000000A065E000000034269620A093C00000001A0FFFFFFB0FFFFFF01000000010001000000000000000000000000000000095E000000095200000000FFFFFFFF0963000000093C0000000A019BFFFFFFABFFFFFF030000000000000000000000093C0000000A0666000000084C6173746E616D650A093C0000000198FFFFFFB0FFFFFF010000000100010000000000000000000000000000000966000000095200000000FFFFFFFF0963000000093C0000000A0193FFFFFFABFFFFFF030000000000000000000000093C0000000A066E0000000946697273746E616D650A093C0000000190FFFFFFB0FFFFFF01000000010001000000000000000000000000000000096E000000095200000000FFFFFFFF0963000000093C0000000A018BFFFFFFABFFFFFF030000000000000000000000093C0000000A067600000005496E6669780A093C0000000188FFFFFFB0FFFFFF010000000100010000000000000000000000000000000976000000095200000000FFFFFFFF0963000000093C0000000A0183FFFFFFABFFFFFF030000000000000000000000093C0000000A067E000000044E616D650A093C0000000180FFFFFFB0FFFFFF01000000010001000000000000000000000000000000097E000000095200000000FFFFFFFF0963000000093C0000000A017BFFFFFFABFFFFFF030000000000000000000000093C0000000A06860000001646697273746E616D654C6173746E616D6555707065720A093C0000000178FFFFFFB0FFFFFF010000000100010000000000000000000000000000000986000000095200000000FFFFFFFF0963000000093C0000000A0173FFFFFFABFFFFFF030000000000000000000000093C0000000A068E0000000B446973706C61794E616D650A093C0000000170FFFFFFB0FFFFFF01000000010001000000000000000000000000000000098E000000095200000000FFFFFFFF0963000000093C0000000A016BFFFFFFABFFFFFF030000000000000000000000093C0000000A069600000010446973706C61794E616D6553686F72740A093C0000000168FFFFFFB0FFFFFF010000000100010000000000000000000000000000000996000000095200000000FFFFFFFF0963000000093C0000000A0163FFFFFFABFFFFFF030000000000000000000000093C0000000A069E0000000647656E6465720A093C0000000160FFFFFFB0FFFFFF01000000010001000000000000000000000000000000099E000000095200000000FFFFFFFF0963000000093C0000000A015BFFFFFFABFFFFFF030000000000000000000000093C0000000A06A600000004436C75620A093C0000000158FFFFFFB0FFFFFF0100000001000100000000000000000000000000000009A6000000095200000000FFFFFFFF0963000000093C0000000A0153FFFFFFABFFFFFF030000000000000000000000093C0000000A06AE000000064E6174696F6E0A093C0000000150FFFFFFB0FFFFFF0100000001000100000000000000000000000000000009AE000000095200000000FFFFFFFF0963000000093C0000000A014BFFFFFFABFFFFFF030000000000000000000000093C0000000A06B600000004466C61670A093C0000000148FFFFFFB0FFFFFF0100000001000100000000000000000000000000000009B6000000095200000000FFFFFFFF0963000000093C0000000A0143FFFFFFABFFFFFF030000000000000000000000093C0000000A06BE0000000D436C7562416E644E6174696F6E0A093C0000000140FFFFFFB0FFFFFF0100000001000100000000000000000000000000000009BE000000095200000000FFFFFFFF0963000000093C0000000A013BFFFFFFABFFFFFF030000000000000000000000093C0000000A06C600000005436C6173730A093C0000000138FFFFFFB0FFFFFF0100000001000100000000000000000000000000000009C6000000095200000000FFFFFFFF0963000000093C0000000A0133FFFFFFABFFFFFF030000000000000000000000093C0000000A06CE0000000452616E6B0A093C0000000130FFFFFFB0FFFFFF0100000001000100000000000000000000000000000009CE000000095200000000FFFFFFFF0963000000093C0000000A012BFFFFFFABFFFFFF030000000000000000000000093C0000000A06D600000006526573756C740A093C0000000128FFFFFFB0FFFFFF0100000001000100000000000000000000000000000009D6000000095200000000FFFFFFFF0963000000093C0000000A0123FFFFFFABFFFFFF030000000000000000000000093C0000000A06DE0000000D5175616C696669636174696F6E0A093C0000000120FFFFFFB0FFFFFF0100000001000100000000000000000000000000000009DE000000095200000000FFFFFFFF0963000000093C0000000A011BFFFFFFABFFFFFF030000000000000000000000093C0000000A06E60000000C53696E676C65506F696E74730A093C0000000118FFFFFFB0FFFFFF0100000001000100000000000000000000000000000009E6000000095200000000FFFFFFFF0963000000093C0000000A0113FFFFFFABFFFFFF030000000000000000000000093C0000000A09EE000000000000000000000009EF00000009F000000009F100000009F200000009F3000000044A0000000E53797374656D2E56657273696F6E04000000065F4D616A6F72065F4D696E6F72065F4275696C64095F5265766973696F6E00000000080808080200000000000000FFFFFFFFFFFFFFFF054D0000001E53797374656D2E446174612E50726F7065727479436F6C6C656374696F6E070000000A4C6F6164466163746F720756657273696F6E08436F6D70617265721048617368436F646550726F7669646572084861736853697A65044B6579730656616C756573000003030005050B081C53797374656D2E436F6C6C656374696F6E732E49436F6D70617265722453797374656D2E436F6C6C656374696F6E732E4948617368436F646550726F76696465720848000000EC51383F000000000A0A0300000009F400000009F500000004520000001F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657203000000044461746109556E697479547970650C417373656D626C794E616D65010001080A02000000093C00000001530000005200000006F70000000C53797374656D2E496E7433320400000006F80000004B6D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D62373761356335363139333465303839015B0000005200000006F90000000E53797374656D2E426F6F6C65616E0400000009F800000001630000005200000006FB0000000D53797374656D2E537472696E670400000009F800000004EE0000001C53797374656D2E436F6C6C656374696F6E732E41727261794C69737403000000065F6974656D73055F73697A65085F76657273696F6E050000080809FD000000000000000000000004EF0000001B53797374656D2E436F6C6C656374696F6E732E426974417272617903000000076D5F6172726179086D5F6C656E677468085F76657273696F6E07000008080809FE000000000000000000000001F0000000EE00000009FD000000000000000000000001F1000000EE00000009FD000000000000000000000004F20000001C53797374656D2E436F6C6C656374696F6E732E486173687461626C65070000000A4C6F6164466163746F720756657273696F6E08436F6D70617265721048617368436F646550726F7669646572084861736853697A65044B6579730656616C756573000003030005050B081C53797374656D2E436F6C6C656374696F6E732E49436F6D70617265722453797374656D2E436F6C6C656374696F6E732E4948617368436F646550726F766964657208EC51383F000000000A0A030000000900010000090101000001F3000000F2000000EC51383F000000000A0A030000000902010000090301000010F40000000000000010F50000000000000010FD000000000000000FFE00000000000000081000010000000000001001010000000000001002010000000000001003010000000000000B0101020203030000
+/// A list starts with LoadLayout, the layout file tells what is shown.
+/// Lists of only SetProperty packets update the currently shown layout
+fn instruction_from_packet_list(items: &[&NrbfValue]) -> Result<NrbfDecodedInstruction, String> {
+    let packets: Vec<&NrbfObject> = items.iter().filter_map(|i| i.as_object()).collect();
+
+    let layout_file = packets
+        .iter()
+        .find(|p| p.short_class_name() == "LoadLayout")
+        .and_then(|p| string_member(p, "FileName"));
+    let properties: Vec<TimingProgramProperty> = packets
+        .iter()
+        .filter(|p| p.short_class_name() == "SetProperty")
+        .map(|p| property_from_record(p))
+        .collect();
+
+    let instruction = match layout_file {
+        Some("Freetext") => {
+            let text = properties
+                .iter()
+                .find(|p| p.element_name == "Text")
+                .and_then(|p| match &p.value {
+                    TimingProgramPropertyValue::Text(text) => Some(text.clone()),
+                    _ => None,
+                })
+                .unwrap_or_default();
+            InstructionFromTimingProgram::Freetext(text)
+        }
+        Some("Slideshow") => InstructionFromTimingProgram::Advertisements(properties),
+        Some("Startlist") => InstructionFromTimingProgram::StartList,
+        Some("Timing") => InstructionFromTimingProgram::Timing,
+        Some("Resultlist") => InstructionFromTimingProgram::Results(properties),
+        Some(other) => return Err(format!("Unknown layout '{}' is loaded", other)),
+        None => {
+            if packets.is_empty() || properties.len() != packets.len() {
+                return Err(String::from("Packet list without a layout to load"));
+            }
+            // only the result list is updated with a new table while it is shown
+            if properties.iter().any(|p| p.element_name == "List") {
+                InstructionFromTimingProgram::ResultsUpdate(properties)
+            } else {
+                InstructionFromTimingProgram::SetProperty(properties)
+            }
+        }
+    };
 
-    Ok((
-        input,
-        NrbfDecodedInstruction::FromTimingProgram(InstructionFromTimingProgram::SetProperty),
-    ))
+    Ok(NrbfDecodedInstruction::FromTimingProgram(instruction))
 }
 
-const RESULT_LIST_MARKER: [u8; 42] = [
-    0x42, 0x61, 0x63, 0x6B, 0x69, 0x6E, 0x67, 0x46, 0x69, 0x65, 0x6C, 0x64, 0x00, 0x01, 0x01, 0x00,
-    0x08, 0x01, 0x17, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x18, 0x00, 0x00, 0x00, 0x0A,
-    0x52, 0x65, 0x73, 0x75, 0x6C, 0x74, 0x6C, 0x69, 0x73, 0x74,
-];
-fn parse_results_command(input: &[u8]) -> IResult<&[u8], NrbfDecodedInstruction> {
-    let (input, _) = take_until_and_consume(&RESULT_LIST_MARKER[..], input)?;
-
-    // 04010000007F53797374656D2E436F6C6C656374696F6E732E47656E657269632E4C69737460315B5B53797374656D2E4F626A6563742C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D5D03000000065F6974656D73055F73697A65085F76657273696F6E05000008080902000000140000004F0400001002000000200000000903000000090400000009050000000906000000090700000009080000000909000000090A000000090B000000090C000000090D000000090E000000090F00000009100000000911000000091200000009130000000914000000091500000009160000000D0C0C1700000052446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E31372C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05030000002D446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E4C6F61644C61796F757404000000234C61796F7574426173652B3C4368616E6E656C3E6B5F5F4261636B696E674669656C64244C61796F7574426173652B3C46696C654E616D653E6B5F5F4261636B696E674669656C64204C61796F7574426173652B3C4E616D653E6B5F5F4261636B696E674669656C642A4C61796F7574426173652B3C53656E6443757272656E566965773E6B5F5F4261636B696E674669656C64000101000801170000000000000006180000000A526573756C746C69737406190000002B4C61796F75745F33373563336233372D353061622D346262302D616336322D6163383632656335353763650005040000002E446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53657450726F706572747905000000183C4368616E6E656C3E6B5F5F4261636B696E674669656C641B3C4C61796F75744E616D653E6B5F5F4261636B696E674669656C641C3C456C656D656E744E616D653E6B5F5F4261636B696E674669656C641D3C50726F70657274794E616D653E6B5F5F4261636B696E674669656C64163C56616C75653E6B5F5F4261636B696E674669656C6400010101020817000000000000000919000000061A0000000748656164657231061B0000000454657874061C000000133130306D2048C3BC7264656E2046726175656E010500000004000000000000000919000000061E0000000748656164657232091B00000006200000000A5A6569746CC3A475666501060000000400000000000000091900000006220000000A4576656E745469746C65091B0000000624000000133130306D2048C3BC7264656E2046726175656E0107000000040000000000000009190000000626000000124576656E744E616D6557697468436C617373091B0000000924000000010800000004000000000000000919000000062A00000014556E6974446973706C61794E616D6553686F7274091B0000000A010900000004000000000000000919000000062D0000000F556E6974446973706C61794E616D65091B0000000A010A000000040000000000000009190000000630000000155068617365446973706C61794E616D6553686F7274091B0000000A010B000000040000000000000009190000000633000000105068617365446973706C61794E616D65091B0000000A010C000000040000000000000009190000000636000000154576656E74446973706C61794E616D6553686F7274091B0000000A010D000000040000000000000009190000000639000000104576656E74446973706C61794E616D65091B0000000A010E00000004000000000000000919000000063C000000094576656E744E616D65091B000000063E0000000C3130306D2048C3BC7264656E010F00000004000000000000000919000000064000000009436C6173734E616D65091B00000006420000000646726175656E011000000004000000000000000919000000064400000012496E7465726E6174696F6E616C53686F727406450000000556616C756506460000000331304801110000000400000000000000091900000006480000000957696E644C6162656C091B000000064A00000000011200000004000000000000000919000000064C0000000457696E64091B000000094A00000001130000000400000000000000091900000006500000000748617357696E6409450000000801000114000000040000000000000009190000000653000000094C6973745469746C65091B00000006550000000A45726765626E697373650115000000040000000000000009190000000657000000044C69737406580000000444617461095900000005160000002D446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E53686F774C61796F7574050000000B436C6561724F7468657273234C61796F7574426173652B3C4368616E6E656C3E6B5F5F4261636B696E674669656C64244C61796F7574426173652B3C46696C654E616D653E6B5F5F4261636B696E674669656C64204C61796F7574426173652B3C4E616D653E6B5F5F4261636B696E674669656C642A4C61796F7574426173652B3C53656E6443757272656E566965773E6B5F5F4261636B696E674669656C6400000101000108011700000001000000000A0919000000010C5B0000004E53797374656D2E446174612C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D62373761356335363139333465303839055900000023446973706C6179426F6172642E436F6D6D756E69636174696F6E2E4C6973744461746101000000163C5461626C653E6B5F5F4261636B696E674669656C64041553797374656D2E446174612E446174615461626C655B00000017000000095C000000055C0000001553797374656D2E446174612E446174615461626C659201000019446174615461626C652E52656D6F74696E6756657273696F6E18446174615461626C652E52656D6F74696E67466F726D617413446174615461626C652E5461626C654E616D6513446174615461626C652E4E616D65737061636510446174615461626C652E50726566697817446174615461626C652E4361736553656E7369746976651E446174615461626C652E6361736553656E736974697665416D6269656E7414446174615461626C652E4C6F63616C654C43494419446174615461626C652E4D696E696D756D436170616369747919446174615461626C652E4E6573746564496E4461746153657412446174615461626C652E547970654E616D651B446174615461626C652E52657065617461626C65456C656D656E741C446174615461626C652E457874656E64656450726F7065727469657317446174615461626C652E436F6C756D6E732E436F756E7421446174615461626C652E44617461436F6C756D6E5F302E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F302E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F302E50726566697824446174615461626C652E44617461436F6C756D6E5F302E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F302E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F302E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F302E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F302E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F302E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F302E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F302E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F302E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F302E446174615479706522446174615461626C652E44617461436F6C756D6E5F302E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F302E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F302E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F302E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F302E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F302E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F312E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F312E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F312E50726566697824446174615461626C652E44617461436F6C756D6E5F312E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F312E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F312E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F312E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F312E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F312E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F312E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F312E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F312E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F312E446174615479706522446174615461626C652E44617461436F6C756D6E5F312E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F312E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F312E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F312E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F312E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F312E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F322E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F322E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F322E50726566697824446174615461626C652E44617461436F6C756D6E5F322E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F322E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F322E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F322E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F322E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F322E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F322E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F322E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F322E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F322E446174615479706522446174615461626C652E44617461436F6C756D6E5F322E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F322E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F322E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F322E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F322E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F322E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F332E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F332E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F332E50726566697824446174615461626C652E44617461436F6C756D6E5F332E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F332E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F332E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F332E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F332E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F332E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F332E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F332E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F332E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F332E446174615479706522446174615461626C652E44617461436F6C756D6E5F332E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F332E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F332E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F332E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F332E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F332E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F342E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F342E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F342E50726566697824446174615461626C652E44617461436F6C756D6E5F342E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F342E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F342E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F342E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F342E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F342E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F342E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F342E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F342E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F342E446174615479706522446174615461626C652E44617461436F6C756D6E5F342E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F342E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F342E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F342E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F342E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F342E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F352E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F352E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F352E50726566697824446174615461626C652E44617461436F6C756D6E5F352E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F352E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F352E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F352E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F352E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F352E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F352E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F352E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F352E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F352E446174615479706522446174615461626C652E44617461436F6C756D6E5F352E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F352E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F352E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F352E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F352E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F352E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F362E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F362E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F362E50726566697824446174615461626C652E44617461436F6C756D6E5F362E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F362E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F362E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F362E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F362E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F362E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F362E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F362E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F362E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F362E446174615479706522446174615461626C652E44617461436F6C756D6E5F362E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F362E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F362E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F362E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F362E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F362E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F372E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F372E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F372E50726566697824446174615461626C652E44617461436F6C756D6E5F372E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F372E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F372E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F372E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F372E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F372E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F372E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F372E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F372E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F372E446174615479706522446174615461626C652E44617461436F6C756D6E5F372E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F372E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F372E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F372E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F372E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F372E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F382E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F382E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F382E50726566697824446174615461626C652E44617461436F6C756D6E5F382E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F382E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F382E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F382E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F382E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F382E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F382E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F382E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F382E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F382E446174615479706522446174615461626C652E44617461436F6C756D6E5F382E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F382E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F382E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F382E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F382E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F382E457874656E64656450726F7065727469657321446174615461626C652E44617461436F6C756D6E5F392E436F6C756D6E4E616D6520446174615461626C652E44617461436F6C756D6E5F392E4E616D6573706163651D446174615461626C652E44617461436F6C756D6E5F392E50726566697824446174615461626C652E44617461436F6C756D6E5F392E436F6C756D6E4D617070696E6722446174615461626C652E44617461436F6C756D6E5F392E416C6C6F7744424E756C6C24446174615461626C652E44617461436F6C756D6E5F392E4175746F496E6372656D656E7428446174615461626C652E44617461436F6C756D6E5F392E4175746F496E6372656D656E745374657028446174615461626C652E44617461436F6C756D6E5F392E4175746F496E6372656D656E74536565641E446174615461626C652E44617461436F6C756D6E5F392E43617074696F6E23446174615461626C652E44617461436F6C756D6E5F392E44656661756C7456616C75651F446174615461626C652E44617461436F6C756D6E5F392E526561644F6E6C7920446174615461626C652E44617461436F6C756D6E5F392E4D61784C656E6774681F446174615461626C652E44617461436F6C756D6E5F392E446174615479706522446174615461626C652E44617461436F6C756D6E5F392E586D6C446174615479706521446174615461626C652E44617461436F6C756D6E5F392E53696D706C655479706523446174615461626C652E44617461436F6C756D6E5F392E4461746554696D654D6F64652B446174615461626C652E44617461436F6C756D6E5F392E4175746F496E6372656D656E7443757272656E7421446174615461626C652E44617461436F6C756D6E5F392E45787072657373696F6E29446174615461626C652E44617461436F6C756D6E5F392E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31302E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31302E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31302E50726566697825446174615461626C652E44617461436F6C756D6E5F31302E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31302E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31302E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31302E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31302E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31302E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31302E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31302E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31302E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31302E446174615479706523446174615461626C652E44617461436F6C756D6E5F31302E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31302E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31302E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31302E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31302E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31302E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31312E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31312E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31312E50726566697825446174615461626C652E44617461436F6C756D6E5F31312E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31312E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31312E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31312E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31312E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31312E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31312E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31312E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31312E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31312E446174615479706523446174615461626C652E44617461436F6C756D6E5F31312E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31312E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31312E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31312E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31312E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31312E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31322E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31322E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31322E50726566697825446174615461626C652E44617461436F6C756D6E5F31322E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31322E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31322E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31322E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31322E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31322E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31322E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31322E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31322E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31322E446174615479706523446174615461626C652E44617461436F6C756D6E5F31322E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31322E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31322E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31322E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31322E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31322E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31332E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31332E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31332E50726566697825446174615461626C652E44617461436F6C756D6E5F31332E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31332E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31332E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31332E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31332E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31332E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31332E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31332E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31332E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31332E446174615479706523446174615461626C652E44617461436F6C756D6E5F31332E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31332E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31332E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31332E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31332E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31332E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31342E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31342E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31342E50726566697825446174615461626C652E44617461436F6C756D6E5F31342E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31342E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31342E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31342E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31342E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31342E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31342E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31342E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31342E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31342E446174615479706523446174615461626C652E44617461436F6C756D6E5F31342E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31342E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31342E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31342E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31342E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31342E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31352E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31352E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31352E50726566697825446174615461626C652E44617461436F6C756D6E5F31352E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31352E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31352E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31352E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31352E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31352E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31352E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31352E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31352E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31352E446174615479706523446174615461626C652E44617461436F6C756D6E5F31352E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31352E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31352E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31352E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31352E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31352E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31362E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31362E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31362E50726566697825446174615461626C652E44617461436F6C756D6E5F31362E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31362E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31362E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31362E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31362E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31362E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31362E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31362E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31362E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31362E446174615479706523446174615461626C652E44617461436F6C756D6E5F31362E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31362E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31362E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31362E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31362E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31362E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31372E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31372E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31372E50726566697825446174615461626C652E44617461436F6C756D6E5F31372E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31372E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31372E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31372E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31372E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31372E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31372E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31372E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31372E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31372E446174615479706523446174615461626C652E44617461436F6C756D6E5F31372E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31372E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31372E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31372E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31372E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31372E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31382E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31382E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31382E50726566697825446174615461626C652E44617461436F6C756D6E5F31382E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31382E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31382E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31382E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31382E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31382E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31382E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31382E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31382E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31382E446174615479706523446174615461626C652E44617461436F6C756D6E5F31382E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31382E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31382E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31382E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31382E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31382E457874656E64656450726F7065727469657322446174615461626C652E44617461436F6C756D6E5F31392E436F6C756D6E4E616D6521446174615461626C652E44617461436F6C756D6E5F31392E4E616D6573706163651E446174615461626C652E44617461436F6C756D6E5F31392E50726566697825446174615461626C652E44617461436F6C756D6E5F31392E436F6C756D6E4D617070696E6723446174615461626C652E44617461436F6C756D6E5F31392E416C6C6F7744424E756C6C25446174615461626C652E44617461436F6C756D6E5F31392E4175746F496E6372656D656E7429446174615461626C652E44617461436F6C756D6E5F31392E4175746F496E6372656D656E745374657029446174615461626C652E44617461436F6C756D6E5F31392E4175746F496E6372656D656E74536565641F446174615461626C652E44617461436F6C756D6E5F31392E43617074696F6E24446174615461626C652E44617461436F6C756D6E5F31392E44656661756C7456616C756520446174615461626C652E44617461436F6C756D6E5F31392E526561644F6E6C7921446174615461626C652E44617461436F6C756D6E5F31392E4D61784C656E67746820446174615461626C652E44617461436F6C756D6E5F31392E446174615479706523446174615461626C652E44617461436F6C756D6E5F31392E586D6C446174615479706522446174615461626C652E44617461436F6C756D6E5F31392E53696D706C655479706524446174615461626C652E44617461436F6C756D6E5F31392E4461746554696D654D6F64652C446174615461626C652E44617461436F6C756D6E5F31392E4175746F496E6372656D656E7443757272656E7422446174615461626C652E44617461436F6C756D6E5F31392E45787072657373696F6E2A446174615461626C652E44617461436F6C756D6E5F31392E457874656E64656450726F7065727469657317446174615461626C655F302E436F6E73747261696E747316446174615461626C655F302E526F77732E436F756E7419446174615461626C655F302E5265636F7264732E436F756E7415446174615461626C655F302E526F7753746174657313446174615461626C655F302E5265636F72647314446174615461626C655F302E4E756C6C4269747315446174615461626C655F302E526F774572726F727318446174615461626C655F302E436F6C756D6E4572726F72730304010101000000000001000400010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010201020104000000000103000003010204000102010201040000000001030000030102040001020102010400000000010300000301020400010203000003030303030E53797374656D2E56657273696F6E1F53797374656D2E446174612E53657269616C697A6174696F6E466F726D61745B0000000101080801011E53797374656D2E446174612E50726F7065727479436F6C6C656374696F6E5B000000081753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091753797374656D2E446174612E4D617070696E67547970655B000000010109091F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657201081F53797374656D2E556E69747953657269616C697A6174696F6E486F6C6465721B53797374656D2E446174612E446174615365744461746554696D655B000000091C53797374656D2E436F6C6C656374696F6E732E41727261794C69737408081B53797374656D2E436F6C6C656374696F6E732E42697441727261791C53797374656D2E436F6C6C656374696F6E732E41727261794C6973741C53797374656D2E436F6C6C656374696F6E732E41727261794C6973741C53797374656D2E436F6C6C656374696F6E732E486173687461626C651C53797374656D2E436F6C6C656374696F6E732E486173687461626C655B000000095D00000005A2FFFFFF1F53797374656D2E446174612E53657269616C697A6174696F6E466F726D6174010000000776616C75655F5F00085B00000001000000094A000000094A000000094A0000000001070400003200000001094A00000000096000000014000000066100000005496E6465780A094A000000059DFFFFFF1753797374656D2E446174612E4D617070696E6754797065010000000776616C75655F5F00085B000000010000000100010000000000000000000000000000000961000000096500000000FFFFFFFF0966000000094A0000000A0598FFFFFF1B53797374656D2E446174612E446174615365744461746554696D65010000000776616C75655F5F00085B000000030000000000000000000000094A0000000A06690000000853656C65637465640A094A0000000195FFFFFF9DFFFFFF010000000100010000000000000000000000000000000969000000096500000000FFFFFFFF096E000000094A0000000A0190FFFFFF98FFFFFF030000000000000000000000094A0000000A0671000000034269620A094A000000018DFFFFFF9DFFFFFF010000000100010000000000000000000000000000000971000000096500000000FFFFFFFF0976000000094A0000000A0188FFFFFF98FFFFFF030000000000000000000000094A0000000A0679000000084C6173746E616D650A094A0000000185FFFFFF9DFFFFFF010000000100010000000000000000000000000000000979000000096500000000FFFFFFFF0976000000094A0000000A0180FFFFFF98FFFFFF030000000000000000000000094A0000000A06810000000946697273746E616D650A094A000000017DFFFFFF9DFFFFFF010000000100010000000000000000000000000000000981000000096500000000FFFFFFFF0976000000094A0000000A0178FFFFFF98FFFFFF030000000000000000000000094A0000000A068900000005496E6669780A094A0000000175FFFFFF9DFFFFFF010000000100010000000000000000000000000000000989000000096500000000FFFFFFFF0976000000094A0000000A0170FFFFFF98FFFFFF030000000000000000000000094A0000000A0691000000044E616D650A094A000000016DFFFFFF9DFFFFFF010000000100010000000000000000000000000000000991000000096500000000FFFFFFFF0976000000094A0000000A0168FFFFFF98FFFFFF030000000000000000000000094A0000000A06990000001646697273746E616D654C6173746E616D6555707065720A094A0000000165FFFFFF9DFFFFFF010000000100010000000000000000000000000000000999000000096500000000FFFFFFFF0976000000094A0000000A0160FFFFFF98FFFFFF030000000000000000000000094A0000000A06A10000000B446973706C61794E616D650A094A000000015DFFFFFF9DFFFFFF0100000001000100000000000000000000000000000009A1000000096500000000FFFFFFFF0976000000094A0000000A0158FFFFFF98FFFFFF030000000000000000000000094A0000000A06A900000010446973706C61794E616D6553686F72740A094A0000000155FFFFFF9DFFFFFF0100000001000100000000000000000000000000000009A9000000096500000000FFFFFFFF0976000000094A0000000A0150FFFFFF98FFFFFF030000000000000000000000094A0000000A06B10000000647656E6465720A094A000000014DFFFFFF9DFFFFFF0100000001000100000000000000000000000000000009B1000000096500000000FFFFFFFF0976000000094A0000000A0148FFFFFF98FFFFFF030000000000000000000000094A0000000A06B900000004436C75620A094A0000000145FFFFFF9DFFFFFF0100000001000100000000000000000000000000000009B9000000096500000000FFFFFFFF0976000000094A0000000A0140FFFFFF98FFFFFF030000000000000000000000094A0000000A06C1000000064E6174696F6E0A094A000000013DFFFFFF9DFFFFFF0100000001000100000000000000000000000000000009C1000000096500000000FFFFFFFF0976000000094A0000000A0138FFFFFF98FFFFFF030000000000000000000000094A0000000A06C900000004466C61670A094A0000000135FFFFFF9DFFFFFF0100000001000100000000000000000000000000000009C9000000096500000000FFFFFFFF0976000000094A0000000A0130FFFFFF98FFFFFF030000000000000000000000094A0000000A06D10000000D436C7562416E644E6174696F6E0A094A000000012DFFFFFF9DFFFFFF0100000001000100000000000000000000000000000009D1000000096500000000FFFFFFFF0976000000094A0000000A0128FFFFFF98FFFFFF030000000000000000000000094A0000000A06D900000005436C6173730A094A0000000125FFFFFF9DFFFFFF0100000001000100000000000000000000000000000009D9000000096500000000FFFFFFFF0976000000094A0000000A0120FFFFFF98FFFFFF030000000000000000000000094A0000000A06E10000000452616E6B0A094A000000011DFFFFFF9DFFFFFF0100000001000100000000000000000000000000000009E1000000096500000000FFFFFFFF0976000000094A0000000A0118FFFFFF98FFFFFF030000000000000000000000094A0000000A06E900000006526573756C740A094A0000000115FFFFFF9DFFFFFF0100000001000100000000000000000000000000000009E9000000096500000000FFFFFFFF0976000000094A0000000A0110FFFFFF98FFFFFF030000000000000000000000094A0000000A06F10000000D5175616C696669636174696F6E0A094A000000010DFFFFFF9DFFFFFF0100000001000100000000000000000000000000000009F1000000096500000000FFFFFFFF0976000000094A0000000A0108FFFFFF98FFFFFF030000000000000000000000094A0000000A06F90000000C53696E676C65506F696E74730A094A0000000105FFFFFF9DFFFFFF0100000001000100000000000000000000000000000009F9000000096500000000FFFFFFFF0976000000094A0000000A0100FFFFFF98FFFFFF030000000000000000000000094A0000000A0901010000000000000000000009020100000903010000090401000009050100000906010000045D0000000E53797374656D2E56657273696F6E04000000065F4D616A6F72065F4D696E6F72065F4275696C64095F5265766973696F6E00000000080808080200000000000000FFFFFFFFFFFFFFFF05600000001E53797374656D2E446174612E50726F7065727479436F6C6C656374696F6E070000000A4C6F6164466163746F720756657273696F6E08436F6D70617265721048617368436F646550726F7669646572084861736853697A65044B6579730656616C756573000003030005050B081C53797374656D2E436F6C6C656374696F6E732E49436F6D70617265722453797374656D2E436F6C6C656374696F6E732E4948617368436F646550726F7669646572085B000000EC51383F000000000A0A030000000907010000090801000004650000001F53797374656D2E556E69747953657269616C697A6174696F6E486F6C64657203000000044461746109556E697479547970650C417373656D626C794E616D65010001080A02000000094A000000016600000065000000060A0100000C53797374656D2E496E74333204000000060B0100004B6D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D62373761356335363139333465303839016E00000065000000060C0100000E53797374656D2E426F6F6C65616E04000000090B010000017600000065000000060E0100000D53797374656D2E537472696E6704000000090B01000004010100001C53797374656D2E436F6C6C656374696F6E732E41727261794C69737403000000065F6974656D73055F73697A65085F76657273696F6E05000008080910010000000000000000000004020100001B53797374656D2E436F6C6C656374696F6E732E426974417272617903000000076D5F6172726179086D5F6C656E677468085F76657273696F6E07000008080809110100000000000000000000010301000001010000091001000000000000000000000104010000010100000910010000000000000000000004050100001C53797374656D2E436F6C6C656374696F6E732E486173687461626C65070000000A4C6F6164466163746F720756657273696F6E08436F6D70617265721048617368436F646550726F7669646572084861736853697A65044B6579730656616C756573000003030005050B081C53797374656D2E436F6C6C656374696F6E732E49436F6D70617265722453797374656D2E436F6C6C656374696F6E732E4948617368436F646550726F766964657208EC51383F000000000A0A0300000009130100000914010000010601000005010000EC51383F000000000A0A03000000091501000009160100001007010000000000001008010000000000001010010000000000000F1101000000000000081013010000000000001014010000000000001015010000000000001016010000000000000B0101020203030000
-
-    Ok((
-        input,
-        NrbfDecodedInstruction::FromTimingProgram(InstructionFromTimingProgram::Results),
-    ))
+fn property_from_record(packet: &NrbfObject) -> TimingProgramProperty {
+    TimingProgramProperty {
+        element_name: string_member(packet, "ElementName")
+            .unwrap_or_default()
+            .to_string(),
+        property_name: string_member(packet, "PropertyName")
+            .unwrap_or_default()
+            .to_string(),
+        value: packet
+            .member("Value")
+            .map(property_value_from_record)
+            .unwrap_or(TimingProgramPropertyValue::Null),
+    }
 }
 
-const SERVER_INFO_MARKER: [u8; 45] = [
-    0x44, 0x69, 0x73, 0x70, 0x6C, 0x61, 0x79, 0x42, 0x6F, 0x61, 0x72, 0x64, 0x2E, 0x43, 0x6F, 0x6D,
-    0x6D, 0x75, 0x6E, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6F, 0x6E, 0x2E, 0x50, 0x61, 0x63, 0x6B, 0x65,
-    0x74, 0x73, 0x2E, 0x53, 0x65, 0x72, 0x76, 0x65, 0x72, 0x49, 0x6E, 0x66, 0x6F,
-];
-fn parse_server_info_command(input: &[u8]) -> IResult<&[u8], NrbfDecodedInstruction> {
-    let (input, _) = take_until_and_consume(&SERVER_INFO_MARKER[..], input)?;
-
-    // 0C0200000053446973706C6179426F6172642E436F6D6D756E69636174696F6E2C2056657273696F6E3D312E302E302E3132382C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05010000002D446973706C6179426F6172642E436F6D6D756E69636174696F6E2E5061636B6574732E536572766572496E666F02000000233C41706C6C69636174696F6E56657273696F6E3E6B5F5F4261636B696E674669656C64193C4368616E6E656C733E6B5F5F4261636B696E674669656C640103BA0253797374656D2E436F6C6C656374696F6E732E47656E657269632E4C69737460315B5B53797374656D2E5475706C6560325B5B53797374656D2E496E7433322C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D2C5B53797374656D2E537472696E672C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D5D2C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D5D02000000060300000009312E302E302E31323809040000000404000000BA0253797374656D2E436F6C6C656374696F6E732E47656E657269632E4C69737460315B5B53797374656D2E5475706C6560325B5B53797374656D2E496E7433322C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D2C5B53797374656D2E537472696E672C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D5D2C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D5D03000000065F6974656D73055F73697A65085F76657273696F6E030000CA0153797374656D2E5475706C6560325B5B53797374656D2E496E7433322C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D2C5B53797374656D2E537472696E672C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D5D5B5D080809050000000100000001000000070500000000010000000400000003C80153797374656D2E5475706C6560325B5B53797374656D2E496E7433322C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D2C5B53797374656D2E537472696E672C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D5D09060000000D030406000000C80153797374656D2E5475706C6560325B5B53797374656D2E496E7433322C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D2C5B53797374656D2E537472696E672C206D73636F726C69622C2056657273696F6E3D342E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D623737613563353631393334653038395D5D02000000076D5F4974656D31076D5F4974656D32000108000000000607000000064F7574707574
-
-    Ok((
-        input,
-        NrbfDecodedInstruction::FromExternalDisplayProgram(
-            InstructionFromExternalDisplayProgram::ServerInfo,
+fn property_value_from_record(value: &NrbfValue) -> TimingProgramPropertyValue {
+    match value {
+        NrbfValue::Null => TimingProgramPropertyValue::Null,
+        NrbfValue::Boolean(b) => TimingProgramPropertyValue::Bool(*b),
+        NrbfValue::Double(v) => TimingProgramPropertyValue::Number(*v),
+        NrbfValue::Single(v) => TimingProgramPropertyValue::Number(*v as f64),
+        NrbfValue::TimeSpan(ticks) => TimingProgramPropertyValue::Duration(
+            Duration::from_nanos_u128((*ticks).max(0) as u128 * 100),
         ),
-    ))
+        NrbfValue::Object(object) => {
+            if let Some(table) = object.member("Table").and_then(|t| t.as_object()) {
+                match table_from_record(table) {
+                    Ok(table) => return TimingProgramPropertyValue::Table(table),
+                    Err(e) => warn!("Could not read table of the timing program: {}", e),
+                }
+            } else if let Some(number) = object.member("value__").and_then(|v| v.as_i64()) {
+                // enums are objects with their number in "value__"
+                return TimingProgramPropertyValue::Number(number as f64);
+            }
+            TimingProgramPropertyValue::Unknown(object.class_name.clone())
+        }
+        other => match (other.as_str(), other.as_i64()) {
+            (Some(text), _) => TimingProgramPropertyValue::Text(text.to_string()),
+            (_, Some(number)) => TimingProgramPropertyValue::Number(number as f64),
+            _ => TimingProgramPropertyValue::Unknown(format!("{:?}", other)),
+        },
+    }
 }
 
-const SEND_FRAME_MARKER: [u8; 46] = [
-    0x44, 0x69, 0x73, 0x70, 0x6C, 0x61, 0x79, 0x42, 0x6F, 0x61, 0x72, 0x64, 0x2E, 0x43, 0x6F, 0x6D,
-    0x6D, 0x75, 0x6E, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6F, 0x6E, 0x2E, 0x50, 0x61, 0x63, 0x6B, 0x65,
-    0x74, 0x73, 0x2E, 0x43, 0x75, 0x72, 0x72, 0x65, 0x6E, 0x74, 0x56, 0x69, 0x65, 0x77,
-];
-fn parse_send_frame_command(input: &[u8]) -> IResult<&[u8], NrbfDecodedInstruction> {
-    let (input, _) = take_until_and_consume(&SEND_FRAME_MARKER[..], input)?;
-    let (input, _) = take_until_and_consume(&DATA_BEFORE_IMAGEA[..], input)?;
-    let (input, _) = take_until(&b"\x42\x4D"[..])(input)?;
-    let (input, image_data) = take_until(&END_OF_MESSAGE_MARKER_TEMPLATE[..])(input)?;
-
-    Ok((
-        input,
-        NrbfDecodedInstruction::FromExternalDisplayProgram(
-            InstructionFromExternalDisplayProgram::Frame(image_data.into()),
-        ),
-    ))
+fn cell_from_record(value: &NrbfValue) -> Option<String> {
+    match value {
+        NrbfValue::Null => None,
+        NrbfValue::Boolean(b) => Some(b.to_string()),
+        NrbfValue::Double(v) => Some(v.to_string()),
+        NrbfValue::Single(v) => Some(v.to_string()),
+        other => other
+            .as_str()
+            .map(|s| s.to_string())
+            .or(other.as_i64().map(|v| v.to_string())),
+    }
 }
 
-const JUMP_TO_TIME_MARKER: [u8; 27] = [
-    0x49, 0x44, 0x43, 0x61, 0x6D, 0x2E, 0x50, 0x61, 0x63, 0x6B, 0x65, 0x74, 0x73, 0x2E, 0x53, 0x65,
-    0x65, 0x6B, 0x54, 0x69, 0x6D, 0x65, 0x73, 0x74, 0x61, 0x6D, 0x70,
-];
-const TIME_BACKING_FIELD_MARKER: [u8; 51] = [
-    0x3C, 0x54, 0x69, 0x6D, 0x65, 0x73, 0x74, 0x61, 0x6D, 0x70, 0x3E, 0x6B, 0x5F, 0x5F, 0x42, 0x61,
-    0x63, 0x6B, 0x69, 0x6E, 0x67, 0x46, 0x69, 0x65, 0x6C, 0x64, 0x18, 0x3C, 0x43, 0x68, 0x61, 0x6E,
-    0x6E, 0x65, 0x6C, 0x3E, 0x6B, 0x5F, 0x5F, 0x42, 0x61, 0x63, 0x6B, 0x69, 0x6E, 0x67, 0x46, 0x69,
-    0x65, 0x6C, 0x64,
-];
-fn parse_jump_to_time_command(input: &[u8]) -> IResult<&[u8], NrbfDecodedInstruction> {
-    let (input, _) = take_until_and_consume(&JUMP_TO_TIME_MARKER[..], input)?;
-    let (input, _) = take_until_and_consume(&TIME_BACKING_FIELD_MARKER[..], input)?;
-    let (input, _) = take_until_and_consume(&b"\x00\x00\x00"[..], input)?;
-    let (input, timestamp_data) = take_until(&b"\x0A"[..])(input)?;
-    let (input, _) = take_until(&END_OF_MESSAGE_MARKER_TEMPLATE[..])(input)?;
-
-    // 0001000000FFFFFFFF01000000000000000C0200000045494443616D2E45786368616E67652C2056657273696F6E3D312E302E302E302C2043756C747572653D6E65757472616C2C205075626C69634B6579546F6B656E3D6E756C6C05010000001B494443616D2E5061636B6574732E5365656B54696D657374616D70020000001A3C54696D657374616D703E6B5F5F4261636B696E674669656C64183C4368616E6E656C3E6B5F5F4261636B696E674669656C6400010C02000000E80BEC997B0000000A0B0101020203030000
-
-    if timestamp_data.len() != 8 {
-        return Err(NomFailure(NomError::new(
-            input,
-            nom::error::ErrorKind::Count,
-        )));
-    }
+/// System.BitArray keeps its bits in Int32 words, lowest bit first
+fn bit_is_set(bit_array: Option<&NrbfValue>, index: usize) -> bool {
+    bit_array
+        .and_then(|b| b.as_object())
+        .and_then(|b| b.member("m_array"))
+        .and_then(|a| a.as_array())
+        .and_then(|words| words.get(index / 32))
+        .and_then(|word| word.as_i64())
+        .is_some_and(|word| (word >> (index % 32)) & 1 == 1)
+}
 
-    let timestamp_data: [u8; 8] = match timestamp_data.try_into() {
-        Ok(a) => a,
-        Err(_) => {
-            return Err(NomFailure(NomError::new(
-                input,
-                nom::error::ErrorKind::Space,
-            )))
+/// A System.Data.DataTable in binary remoting format stores its records per column.
+/// Every row takes one record, modified rows take their original before the current one
+/// and rows with a pending edit one more after it (3 state bits per row)
+fn table_from_record(table: &NrbfObject) -> Result<TimingProgramTable, String> {
+    let column_count = table
+        .member("DataTable.Columns.Count")
+        .and_then(|c| c.as_i64())
+        .ok_or(String::from("DataTable without column count"))?
+        .clamp(0, table.members.len() as i64) as usize;
+    let columns = (0..column_count)
+        .map(|c| {
+            table
+                .member(&format!("DataTable.DataColumn_{}.ColumnName", c))
+                .and_then(|n| n.as_str())
+                .unwrap_or_default()
+                .to_string()
+        })
+        .collect();
+
+    let row_count = table
+        .member("DataTable_0.Rows.Count")
+        .and_then(|c| c.as_i64())
+        .unwrap_or(0)
+        .max(0) as usize;
+    let records = table
+        .member("DataTable_0.Records")
+        .and_then(|r| r.list_items())
+        .unwrap_or_default();
+    let null_bits = table
+        .member("DataTable_0.NullBits")
+        .and_then(|n| n.list_items())
+        .unwrap_or_default();
+    let row_states = table.member("DataTable_0.RowStates");
+
+    // every row takes at least one record, the counts are not trusted any further than that
+    let record_count = records
+        .iter()
+        .map(|r| match r {
+            NrbfValue::Array(values) => values.len(),
+            NrbfValue::Bytes(values) => values.len(),
+            _ => 0,
+        })
+        .max()
+        .unwrap_or(0);
+    let row_count = row_count.min(record_count);
+
+    let mut rows = Vec::new();
+    let mut record = 0;
+    for row in 0..row_count {
+        let added = bit_is_set(row_states, row * 3);
+        let modified = bit_is_set(row_states, row * 3 + 1);
+        let pending_edit = bit_is_set(row_states, row * 3 + 2);
+
+        let current = if modified && !added {
+            record + 1
+        } else {
+            record
+        };
+        record += 1 + usize::from(modified && !added) + usize::from(pending_edit);
+        if added && modified {
+            continue; // deleted
         }
-    };
 
-    let timestamp = u64::from_le_bytes(timestamp_data);
-    let duration = Duration::from_nanos_u128(timestamp as u128 * 100);
+        rows.push(
+            (0..column_count)
+                .map(|c| {
+                    if bit_is_set(null_bits.get(c).copied(), current) {
+                        return None;
+                    }
+                    match records.get(c) {
+                        Some(NrbfValue::Array(values)) => {
+                            values.get(current).and_then(cell_from_record)
+                        }
+                        Some(NrbfValue::Bytes(values)) => {
+                            values.get(current).map(|v| v.to_string())
+                        }
+                        _ => None,
+                    }
+                })
+                .collect(),
+        );
+    }
 
-    Ok((
-        input,
-        NrbfDecodedInstruction::FromIdcaptureServer(IDCaptureMessage::JumpToTime(DayTime::from(
-            duration,
-        ))),
-    ))
+    Ok(TimingProgramTable { columns, rows })
 }
 
-fn parse_any_known_command(input: &[u8]) -> IResult<&[u8], NrbfDecodedInstruction> {
-    alt((
-        |i| parse_failing_command(i),
-        |i| parse_client_info_command(i),
-        |i| parse_text_command(i),
-        |i| parse_clear_command(i),
-        |i| parse_slideshow_command(i),
-        |i| parse_startlist_command(i),
-        |i| parse_timing_command(i),
-        |i| parse_results_command(i),
-        |i| parse_server_info_command(i),
-        |i| parse_send_frame_command(i),
-        |i| parse_jump_to_time_command(i),
-        |i| parse_set_property_command(i),
-    ))
-    .parse(input)
+fn i32_from_bytes(bytes: &[u8]) -> i32 {
+    return i32::from_le_bytes(bytes.try_into().unwrap_or([0x00, 0x00, 0x00, 0x00]));
 }
 
 fn text_log_bytes(buf: &[u8]) {
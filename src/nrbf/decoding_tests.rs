@@ -0,0 +1,420 @@
+//! Decoding of captured packets (`tests/nrbf_packets/<name>.hex`) and of malformed input
+
+use super::{instruction_from_records, table_from_record, NrbfDecodedInstruction};
+use crate::idcapture::format::IDCaptureMessage;
+use crate::instructions::{InstructionFromExternalDisplayProgram, InstructionFromTimingProgram};
+use crate::nrbf_records::{decode_nrbf_records, NrbfObject, NrbfValue};
+use crate::server::camera_program_types::{HeatCompetitor, HeatStartList};
+use crate::server::timing_program_types::{
+    heat_from_properties, heat_result_from_properties, matching_heat, TimingProgramProperty,
+    TimingProgramPropertyValue, TimingProgramTable,
+};
+use crate::times::DayTime;
+use std::time::Duration;
+
+fn packet(name: &str) -> Vec<u8> {
+    let path = format!(
+        "{}/tests/nrbf_packets/{}.hex",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    );
+    let hex = std::fs::read_to_string(&path).expect("missing packet fixture");
+    hex::decode(hex.trim()).expect("invalid hex in packet fixture")
+}
+
+fn decode(name: &str) -> NrbfDecodedInstruction {
+    let root = decode_nrbf_records(&packet(name)).expect("packet does not decode");
+    instruction_from_records(&root).expect("packet is not a known instruction")
+}
+
+fn timing_program_instruction(name: &str) -> InstructionFromTimingProgram {
+    match decode(name).into_timing_program_instruction() {
+        Ok(i) => i,
+        Err(other) => panic!("{} is not from the timing program: {}", name, other),
+    }
+}
+
+fn property<'a>(
+    properties: &'a [TimingProgramProperty],
+    element_name: &str,
+) -> &'a TimingProgramPropertyValue {
+    &properties
+        .iter()
+        .find(|p| p.element_name == element_name)
+        .unwrap_or_else(|| panic!("no property for element {}", element_name))
+        .value
+}
+
+#[test]
+fn decodes_layouts() {
+    assert!(matches!(
+        timing_program_instruction("client_info"),
+        InstructionFromTimingProgram::ClientInfo
+    ));
+    assert!(matches!(
+        timing_program_instruction("clear"),
+        InstructionFromTimingProgram::Clear
+    ));
+    assert!(matches!(
+        timing_program_instruction("start_list"),
+        InstructionFromTimingProgram::StartList
+    ));
+    assert!(matches!(
+        timing_program_instruction("timing"),
+        InstructionFromTimingProgram::Timing
+    ));
+    match timing_program_instruction("freetext") {
+        InstructionFromTimingProgram::Freetext(text) => assert_eq!(text, "testtesttesttesttest"),
+        other => panic!("unexpected {}", other),
+    }
+    assert!(matches!(
+        decode("server_info").into_external_display_program_instruction(),
+        Ok(InstructionFromExternalDisplayProgram::ServerInfo)
+    ));
+}
+
+#[test]
+fn decodes_seek_timestamp() {
+    match decode("seek_timestamp").into_idcapture_instruction() {
+        Ok(IDCaptureMessage::JumpToTime(time)) => assert_eq!(
+            time,
+            DayTime::from(Duration::from_nanos(530_863_361_000 * 100))
+        ),
+        _ => panic!("seek timestamp is no jump to time"),
+    }
+}
+
+#[test]
+fn decodes_property_values() {
+    match timing_program_instruction("slideshow") {
+        InstructionFromTimingProgram::Advertisements(properties) => {
+            let interval = properties
+                .iter()
+                .find(|p| p.property_name == "ChangeInterval")
+                .map(|p| &p.value);
+            assert!(matches!(
+                interval,
+                Some(TimingProgramPropertyValue::Duration(d)) if *d == Duration::from_secs(5)
+            ));
+        }
+        other => panic!("unexpected {}", other),
+    }
+
+    match timing_program_instruction("set_property_time") {
+        InstructionFromTimingProgram::SetProperty(properties) => assert!(matches!(
+            property(&properties, "Time"),
+            TimingProgramPropertyValue::Text(t) if t == "0.0"
+        )),
+        other => panic!("unexpected {}", other),
+    }
+    match timing_program_instruction("set_property_has_wind") {
+        InstructionFromTimingProgram::SetProperty(properties) => assert!(matches!(
+            property(&properties, "HasWind"),
+            TimingProgramPropertyValue::Bool(false)
+        )),
+        other => panic!("unexpected {}", other),
+    }
+    match timing_program_instruction("set_property_info") {
+        InstructionFromTimingProgram::SetProperty(properties) => assert!(matches!(
+            property(&properties, "Info"),
+            TimingProgramPropertyValue::Text(t) if t == "1200m"
+        )),
+        other => panic!("unexpected {}", other),
+    }
+}
+
+#[test]
+fn decodes_result_tables() {
+    for name in ["result_list", "results_update"] {
+        let properties = match timing_program_instruction(name) {
+            InstructionFromTimingProgram::Results(properties)
+            | InstructionFromTimingProgram::ResultsUpdate(properties) => properties,
+            other => panic!("unexpected {}", other),
+        };
+        match property(&properties, "List") {
+            TimingProgramPropertyValue::Table(table) => {
+                assert_eq!(table.columns.len(), 20);
+                assert_eq!(table.columns[2], "Bib");
+                assert_eq!(table.columns[16], "Rank");
+                assert_eq!(table.columns[17], "Result");
+                assert!(table.rows.is_empty()); // the list was empty, when it was captured
+            }
+            other => panic!("no table in {}: {:?}", name, other),
+        }
+    }
+}
+
+fn start_list(name: &str, seed: u128, bibs: &[u32]) -> HeatStartList {
+    HeatStartList {
+        name: String::from(name),
+        id: uuid::Uuid::from_u128(seed),
+        nr: 1,
+        session_nr: 1,
+        distance_meters: 100,
+        scheduled_start_time: DayTime::from_hms_opt(10, 0, 0).unwrap(),
+        competitors: bibs
+            .iter()
+            .enumerate()
+            .map(|(lane, bib)| HeatCompetitor {
+                id: bib.to_string(),
+                lane: lane as u32 + 1,
+                bib: *bib,
+                class: String::new(),
+                last_name: format!("Last {}", bib),
+                first_name: String::new(),
+                nation: String::new(),
+                club: String::new(),
+                gender: String::new(),
+                disqualified: None,
+            })
+            .collect(),
+    }
+}
+
+fn result_list_properties() -> Vec<TimingProgramProperty> {
+    match timing_program_instruction("result_list") {
+        InstructionFromTimingProgram::Results(properties) => properties,
+        other => panic!("unexpected {}", other),
+    }
+}
+
+#[test]
+fn result_list_belongs_to_the_heat_with_its_title() {
+    let properties = result_list_properties();
+    let title = match properties
+        .iter()
+        .find(|p| p.element_name == "EventTitle" && p.property_name == "Text")
+        .map(|p| &p.value)
+    {
+        Some(TimingProgramPropertyValue::Text(title)) => title.trim().to_string(),
+        other => panic!("result list without event title: {:?}", other),
+    };
+
+    let heats = vec![start_list("Other", 1, &[]), start_list(&title, 2, &[])];
+    let heat = matching_heat(&properties, &heats).expect("no heat matched");
+    assert_eq!(heat.id, heats[1].id);
+
+    let start = DayTime::from_hms_opt(10, 2, 3).unwrap();
+    let result = heat_result_from_properties(&properties, heat, start.clone()).unwrap();
+    assert_eq!(result.id, heats[1].id);
+    assert_eq!(result.start_time, start);
+    assert!(result.competitors_evaluated.is_empty());
+    assert!(result.competitors_left_to_evaluate.is_empty());
+}
+
+#[test]
+fn result_rows_are_matched_to_the_start_list() {
+    let mut properties = result_list_properties();
+    let cell = |c: &str| Some(String::from(c));
+    properties.push(TimingProgramProperty {
+        element_name: String::from("EventTitle"),
+        property_name: String::from("Text"),
+        value: TimingProgramPropertyValue::Text(String::from("Not in the schedule")),
+    });
+    properties.push(TimingProgramProperty {
+        element_name: String::from("List"),
+        property_name: String::from("Data"),
+        value: TimingProgramPropertyValue::Table(TimingProgramTable {
+            columns: vec![
+                String::from("Bib"),
+                String::from("Rank"),
+                String::from("Result"),
+            ],
+            rows: vec![
+                vec![cell("12"), cell("1."), cell("10,51")],
+                vec![cell("7"), None, cell("DNF")],
+            ],
+        }),
+    });
+
+    // without a matching title, the heat with all the bibs is taken
+    let heats = vec![start_list("A", 1, &[12, 3]), start_list("B", 2, &[7, 12])];
+    let heat = matching_heat(&properties, &heats).expect("no heat matched");
+    assert_eq!(heat.id, heats[1].id);
+
+    let start = DayTime::from_hms_opt(10, 0, 0).unwrap();
+    let result = heat_result_from_properties(&properties, heat, start.clone()).unwrap();
+    assert_eq!(result.competitors_evaluated.len(), 1);
+    let winner = &result.competitors_evaluated[0];
+    assert_eq!(winner.competitor.lane, 2);
+    assert_eq!(winner.competitor.last_name, "Last 12");
+    assert_eq!(winner.rank, 1);
+    assert_eq!(
+        winner.finish_time,
+        start.add_duration(Duration::from_millis(10_510))
+    );
+    assert_eq!(result.competitors_left_to_evaluate.len(), 1);
+    assert!(result.competitors_left_to_evaluate[0]
+        .disqualified
+        .is_some());
+
+    assert!(matching_heat(&properties, &heats[..1]).is_none());
+}
+
+#[test]
+fn result_list_without_heat_is_shown_as_its_own_heat() {
+    let properties = result_list_properties();
+    assert!(matching_heat(&properties, &[]).is_none());
+
+    let heat = heat_from_properties(&properties);
+    assert_eq!(heat.name, "100m Hürden Frauen");
+    assert_eq!(heat.distance_meters, 100);
+    // the same list always ends up as the same heat on the display
+    assert_eq!(heat.id, heat_from_properties(&properties).id);
+
+    let result =
+        heat_result_from_properties(&properties, &heat, heat.scheduled_start_time.clone()).unwrap();
+    assert_eq!(result.id, heat.id);
+    assert_eq!(result.name, heat.name);
+}
+
+fn object(class_name: &str, members: Vec<(&str, NrbfValue)>) -> NrbfValue {
+    NrbfValue::Object(NrbfObject {
+        class_name: String::from(class_name),
+        library: None,
+        members: members
+            .into_iter()
+            .map(|(n, v)| (String::from(n), v))
+            .collect(),
+    })
+}
+
+fn list(items: Vec<NrbfValue>) -> NrbfValue {
+    let size = items.len() as i32;
+    object(
+        "System.Collections.ArrayList",
+        vec![
+            ("_items", NrbfValue::Array(items)),
+            ("_size", NrbfValue::Int32(size)),
+        ],
+    )
+}
+
+fn bit_array(words: Vec<i32>) -> NrbfValue {
+    object(
+        "System.Collections.BitArray",
+        vec![(
+            "m_array",
+            NrbfValue::Array(words.into_iter().map(NrbfValue::Int32).collect()),
+        )],
+    )
+}
+
+#[test]
+fn table_records_follow_row_states() {
+    // row 0 added, row 1 modified (original, then current record), row 2 deleted
+    let row_states = 0b11_010_001;
+    let table = object(
+        "System.Data.DataTable",
+        vec![
+            ("DataTable.Columns.Count", NrbfValue::Int32(2)),
+            (
+                "DataTable.DataColumn_0.ColumnName",
+                NrbfValue::String(String::from("Rank")),
+            ),
+            (
+                "DataTable.DataColumn_1.ColumnName",
+                NrbfValue::String(String::from("Result")),
+            ),
+            ("DataTable_0.Rows.Count", NrbfValue::Int32(3)),
+            ("DataTable_0.RowStates", bit_array(vec![row_states])),
+            (
+                "DataTable_0.Records",
+                list(vec![
+                    NrbfValue::Array(vec![
+                        NrbfValue::Int32(1),
+                        NrbfValue::Int32(3),
+                        NrbfValue::Int32(2),
+                        NrbfValue::Int32(4),
+                    ]),
+                    NrbfValue::Array(vec![
+                        NrbfValue::String(String::from("10,51")),
+                        NrbfValue::String(String::from("11,02")),
+                        NrbfValue::Null,
+                        NrbfValue::String(String::from("12,00")),
+                    ]),
+                ]),
+            ),
+            (
+                "DataTable_0.NullBits",
+                list(vec![bit_array(vec![0]), bit_array(vec![0b0100])]),
+            ),
+        ],
+    );
+
+    let table = table_from_record(table.as_object().unwrap()).unwrap();
+    assert_eq!(table.columns, vec!["Rank", "Result"]);
+    assert_eq!(
+        table.rows,
+        vec![
+            vec![Some(String::from("1")), Some(String::from("10,51"))],
+            vec![Some(String::from("2")), None],
+        ]
+    );
+}
+
+const STREAM_HEADER: [u8; 17] = [
+    0x00, 0x01, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00,
+];
+
+#[test]
+fn rejects_huge_multidimensional_arrays() {
+    // rank 3 Int32 array of 1000x1000x1000 elements, in a few hundred bytes
+    let mut input = STREAM_HEADER.to_vec();
+    input.extend([0x07, 0x01, 0x00, 0x00, 0x00, 0x02, 0x03, 0x00, 0x00, 0x00]);
+    for _ in 0..3 {
+        input.extend(1000i32.to_le_bytes());
+    }
+    input.extend([0x00, 0x08]);
+    input.extend([0x00; 200]);
+
+    assert!(decode_nrbf_records(&input).is_err());
+}
+
+#[test]
+fn rejects_deeply_nested_records() {
+    let mut input = STREAM_HEADER.to_vec();
+    for id in 1..200i32 {
+        // ArraySingleObject with one element, that is the next array
+        input.push(0x10);
+        input.extend(id.to_le_bytes());
+        input.extend(1i32.to_le_bytes());
+    }
+    input.push(0x0A);
+    input.push(0x0B);
+
+    assert!(decode_nrbf_records(&input).is_err());
+}
+
+#[test]
+fn keeps_cyclic_references() {
+    let mut input = STREAM_HEADER.to_vec();
+    // BinaryLibrary 2 "L"
+    input.extend([0x0C, 0x02, 0x00, 0x00, 0x00, 0x01, b'L']);
+    // ClassWithMembersAndTypes 1 "A" with the object member "Self"
+    input.extend([
+        0x05, 0x01, 0x00, 0x00, 0x00, 0x01, b'A', 0x01, 0x00, 0x00, 0x00,
+    ]);
+    input.extend([0x04, b'S', b'e', b'l', b'f', 0x02, 0x02, 0x00, 0x00, 0x00]);
+    // Self = MemberReference 1
+    input.extend([0x09, 0x01, 0x00, 0x00, 0x00, 0x0B]);
+
+    let root = decode_nrbf_records(&input).unwrap();
+    let a = root.as_object().unwrap();
+    assert_eq!(a.class_name, "A");
+    assert_eq!(a.member("Self"), Some(&NrbfValue::Reference(1)));
+}
+
+#[test]
+fn copies_shared_references() {
+    let mut input = STREAM_HEADER.to_vec();
+    // ArraySingleObject 1 with the same string twice
+    input.extend([0x10, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00]);
+    input.extend([0x06, 0x02, 0x00, 0x00, 0x00, 0x01, b'x']);
+    input.extend([0x09, 0x02, 0x00, 0x00, 0x00, 0x0B]);
+
+    let root = decode_nrbf_records(&input).unwrap();
+    let x = NrbfValue::String(String::from("x"));
+    assert_eq!(root, NrbfValue::Array(vec![x.clone(), x]));
+}
@@ -0,0 +1,676 @@
+//! Record level decoder for MS-NRBF (the .NET BinaryFormatter stream format)
+//! https://winprotocoldocs-bhdugrdyduf5h2e4.b02.azurefd.net/MS-NRBF/%5bMS-NRBF%5d.pdf -> section 2
+//!
+//! Decodes a stream of records into a generic value tree, object references are resolved.
+//! What the values mean is left to the caller (see nrbf.rs)
+use crate::hex::{NomError, NomErrorKind, NomFailure};
+use nom::bytes::complete::take;
+use nom::number::complete::{
+    le_f32, le_f64, le_i16, le_i32, le_i64, le_i8, le_u16, le_u32, le_u64, le_u8,
+};
+use nom::IResult;
+use std::collections::{HashMap, HashSet};
+
+/// Records nested inline deeper than this are rejected (protects the stack)
+const MAX_RECORD_NESTING: usize = 64;
+/// Object graphs referencing deeper than this are rejected (protects the stack)
+const MAX_RESOLVE_DEPTH: usize = 64;
+/// Shared references are copied into the tree, this limits how big it can get
+const MAX_RESOLVED_VALUES: usize = 200_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NrbfValue {
+    Null,
+    Boolean(bool),
+    Byte(u8),
+    Char(char),
+    Decimal(String), // transmitted as string
+    Double(f64),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    SByte(i8),
+    Single(f32),
+    TimeSpan(i64), // 100ns ticks
+    DateTime(u64), // raw, 62 bits of 100ns ticks since 0001-01-01 and 2 bits of kind
+    UInt16(u16),
+    UInt32(u32),
+    UInt64(u64),
+    String(String),
+    Bytes(Vec<u8>), // byte arrays are kept in one piece (images)
+    Array(Vec<NrbfValue>),
+    Object(NrbfObject),
+    Reference(i32), // only left over, if the object is missing or nested too deep
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NrbfObject {
+    pub class_name: String,
+    pub library: Option<String>,
+    pub members: Vec<(String, NrbfValue)>,
+}
+impl NrbfObject {
+    /// Auto properties are serialized as "<Name>k__BackingField" (or "Base+<Name>k__BackingField" if inherited),
+    /// this finds them by "Name", too
+    pub fn member(&self, name: &str) -> Option<&NrbfValue> {
+        let backing_field = format!("<{}>k__BackingField", name);
+        let inherited_backing_field = format!("+{}", backing_field);
+        self.members
+            .iter()
+            .find(|(n, _)| {
+                n == name || *n == backing_field || n.ends_with(&inherited_backing_field)
+            })
+            .map(|(_, v)| v)
+    }
+
+    /// "DisplayBoard.Communication.Packets.Clear" -> "Clear"
+    pub fn short_class_name(&self) -> &str {
+        self.class_name
+            .rsplit('.')
+            .next()
+            .unwrap_or(&self.class_name)
+    }
+}
+
+impl NrbfValue {
+    pub fn as_object(&self) -> Option<&NrbfObject> {
+        match self {
+            Self::Object(o) => Some(o),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) | Self::Decimal(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&Vec<NrbfValue>> {
+        match self {
+            Self::Array(a) => Some(a),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Byte(v) => Some(*v as i64),
+            Self::SByte(v) => Some(*v as i64),
+            Self::Int16(v) => Some(*v as i64),
+            Self::Int32(v) => Some(*v as i64),
+            Self::Int64(v) | Self::TimeSpan(v) => Some(*v),
+            Self::UInt16(v) => Some(*v as i64),
+            Self::UInt32(v) => Some(*v as i64),
+            Self::UInt64(v) => i64::try_from(*v).ok(),
+            _ => None,
+        }
+    }
+
+    /// The .NET List<T> is serialized with an "_items" array that is longer than "_size"
+    pub fn list_items(&self) -> Option<Vec<&NrbfValue>> {
+        let list = self.as_object()?;
+        let items = list.member("_items")?.as_array()?;
+        let size = list
+            .member("_size")
+            .and_then(|s| s.as_i64())
+            .unwrap_or(items.len() as i64)
+            .max(0) as usize;
+        Some(items.iter().take(size).collect())
+    }
+}
+
+/// 7 bits per byte, lowest first (used for string lengths)
+fn leb128_u32(input: &[u8]) -> IResult<&[u8], u32> {
+    let mut result: u32 = 0;
+    let mut shift = 0;
+    let mut consumed = 0;
+
+    for &byte in input.iter() {
+        let value = (byte & 0x7F) as u32;
+        result |= value << shift;
+        consumed += 1;
+
+        if byte & 0x80 == 0 {
+            // MSB not set → last byte
+            return Ok((&input[consumed..], result));
+        }
+
+        shift += 7;
+
+        // u32 can only be up to 32 bits → max 5 bytes
+        if shift >= 32 {
+            return Err(NomFailure(NomError::new(input, NomErrorKind::TooLarge)));
+        }
+    }
+
+    Err(nom::Err::Incomplete(nom::Needed::new(1)))
+}
+
+fn length_prefixed_string(input: &[u8]) -> IResult<&[u8], String> {
+    let (input, len) = leb128_u32(input)?;
+    let (input, bytes) = take(len)(input)?;
+    let text = std::str::from_utf8(bytes)
+        .map(|s| s.to_string())
+        .map_err(|_| NomFailure(NomError::new(input, NomErrorKind::Alpha)))?;
+    Ok((input, text))
+}
+
+/// Element and member counts, every one of them takes at least one byte of the input
+fn count(input: &[u8]) -> IResult<&[u8], usize> {
+    let (rest, value) = le_i32(input)?;
+    match usize::try_from(value) {
+        Ok(c) if c <= rest.len() => Ok((rest, c)),
+        _ => Err(NomFailure(NomError::new(input, NomErrorKind::Count))),
+    }
+}
+
+/// The number of nulls in ObjectNullMultiple, those take no bytes
+fn null_count(input: &[u8]) -> IResult<&[u8], usize> {
+    let (rest, value) = le_i32(input)?;
+    match usize::try_from(value) {
+        Ok(c) => Ok((rest, c)),
+        _ => Err(NomFailure(NomError::new(input, NomErrorKind::Count))),
+    }
+}
+
+fn fail<T>(input: &[u8], kind: NomErrorKind) -> IResult<&[u8], T> {
+    Err(NomFailure(NomError::new(input, kind)))
+}
+
+// PrimitiveTypeEnumeration
+fn primitive(input: &[u8], primitive_type: u8) -> IResult<&[u8], NrbfValue> {
+    match primitive_type {
+        1 => le_u8(input).map(|(i, v)| (i, NrbfValue::Boolean(v != 0))),
+        2 => le_u8(input).map(|(i, v)| (i, NrbfValue::Byte(v))),
+        3 => {
+            // one UTF-8 encoded character
+            let (_, first) = le_u8(input)?;
+            let len = match first {
+                0x00..=0x7F => 1,
+                0xC0..=0xDF => 2,
+                0xE0..=0xEF => 3,
+                _ => 4,
+            };
+            let (rest, bytes) = take(len as usize)(input)?;
+            match std::str::from_utf8(bytes)
+                .ok()
+                .and_then(|s| s.chars().next())
+            {
+                Some(c) => Ok((rest, NrbfValue::Char(c))),
+                None => fail(input, NomErrorKind::Char),
+            }
+        }
+        5 => length_prefixed_string(input).map(|(i, v)| (i, NrbfValue::Decimal(v))),
+        6 => le_f64(input).map(|(i, v)| (i, NrbfValue::Double(v))),
+        7 => le_i16(input).map(|(i, v)| (i, NrbfValue::Int16(v))),
+        8 => le_i32(input).map(|(i, v)| (i, NrbfValue::Int32(v))),
+        9 => le_i64(input).map(|(i, v)| (i, NrbfValue::Int64(v))),
+        10 => le_i8(input).map(|(i, v)| (i, NrbfValue::SByte(v))),
+        11 => le_f32(input).map(|(i, v)| (i, NrbfValue::Single(v))),
+        12 => le_i64(input).map(|(i, v)| (i, NrbfValue::TimeSpan(v))),
+        13 => le_u64(input).map(|(i, v)| (i, NrbfValue::DateTime(v))),
+        14 => le_u16(input).map(|(i, v)| (i, NrbfValue::UInt16(v))),
+        15 => le_u32(input).map(|(i, v)| (i, NrbfValue::UInt32(v))),
+        16 => le_u64(input).map(|(i, v)| (i, NrbfValue::UInt64(v))),
+        17 => Ok((input, NrbfValue::Null)),
+        18 => length_prefixed_string(input).map(|(i, v)| (i, NrbfValue::String(v))),
+        _ => fail(input, NomErrorKind::Switch),
+    }
+}
+
+// BinaryTypeEnumeration with its AdditionalInfo
+#[derive(Debug, Clone)]
+enum MemberType {
+    Primitive(u8),
+    String,
+    Object,
+    SystemClass,
+    Class,
+    ObjectArray,
+    StringArray,
+    PrimitiveArray,
+}
+
+fn member_type_additional_info(input: &[u8], binary_type: u8) -> IResult<&[u8], MemberType> {
+    match binary_type {
+        0 => le_u8(input).map(|(i, p)| (i, MemberType::Primitive(p))),
+        1 => Ok((input, MemberType::String)),
+        2 => Ok((input, MemberType::Object)),
+        3 => length_prefixed_string(input).map(|(i, _)| (i, MemberType::SystemClass)),
+        4 => {
+            let (input, _) = length_prefixed_string(input)?;
+            let (input, _library_id) = le_i32(input)?;
+            Ok((input, MemberType::Class))
+        }
+        5 => Ok((input, MemberType::ObjectArray)),
+        6 => Ok((input, MemberType::StringArray)),
+        7 => le_u8(input).map(|(i, _)| (i, MemberType::PrimitiveArray)),
+        _ => fail(input, NomErrorKind::Switch),
+    }
+}
+
+struct ClassMetadata {
+    name: String,
+    library_id: Option<i32>,
+    member_names: Vec<String>,
+    member_types: Option<Vec<MemberType>>, // None for the *WithMembers records, without type info
+}
+
+enum Record {
+    Value(NrbfValue), // objects with an id are Reference(id) here and stored by the decoder
+    NullMultiple(usize),
+    Library,
+    End,
+}
+
+#[derive(Default)]
+struct NrbfDecoder {
+    libraries: HashMap<i32, String>,
+    classes: HashMap<i32, ClassMetadata>,
+    objects: HashMap<i32, NrbfValue>,
+    nesting: usize,
+}
+
+impl NrbfDecoder {
+    fn store(&mut self, id: i32, value: NrbfValue) -> Record {
+        self.objects.insert(id, value);
+        Record::Value(NrbfValue::Reference(id))
+    }
+
+    fn class_info<'a>(&self, input: &'a [u8]) -> IResult<&'a [u8], (i32, String, Vec<String>)> {
+        let (mut input, id) = le_i32(input)?;
+        let (rest, name) = length_prefixed_string(input)?;
+        input = rest;
+        let (rest, member_count) = count(input)?;
+        input = rest;
+
+        let mut member_names = Vec::new();
+        for _ in 0..member_count {
+            let (rest, member_name) = length_prefixed_string(input)?;
+            input = rest;
+            member_names.push(member_name);
+        }
+        Ok((input, (id, name, member_names)))
+    }
+
+    fn member_type_info<'a>(
+        &self,
+        input: &'a [u8],
+        member_count: usize,
+    ) -> IResult<&'a [u8], Vec<MemberType>> {
+        let (mut input, binary_types) = take(member_count)(input)?;
+        let mut types = Vec::new();
+        for binary_type in binary_types {
+            let (rest, member_type) = member_type_additional_info(input, *binary_type)?;
+            input = rest;
+            types.push(member_type);
+        }
+        Ok((input, types))
+    }
+
+    /// Values of `count` elements that are records (ObjectNullMultiple counts for several)
+    fn record_values<'a>(
+        &mut self,
+        mut input: &'a [u8],
+        count: usize,
+    ) -> IResult<&'a [u8], Vec<NrbfValue>> {
+        let mut values = Vec::new();
+        while values.len() < count {
+            let (rest, record) = self.record(input)?;
+            input = rest;
+            match record {
+                Record::Value(v) => values.push(v),
+                Record::NullMultiple(n) => values.extend(std::iter::repeat_n(
+                    NrbfValue::Null,
+                    n.min(count - values.len()),
+                )),
+                Record::Library => (), // libraries can be put in between, they do not count
+                Record::End => return fail(input, NomErrorKind::Eof),
+            }
+        }
+        Ok((input, values))
+    }
+
+    fn typed_values<'a>(
+        &mut self,
+        mut input: &'a [u8],
+        element_type: &MemberType,
+        count: usize,
+    ) -> IResult<&'a [u8], NrbfValue> {
+        match element_type {
+            MemberType::Primitive(2) => {
+                let (rest, bytes) = take(count)(input)?;
+                Ok((rest, NrbfValue::Bytes(bytes.to_vec())))
+            }
+            MemberType::Primitive(p) => {
+                let mut values = Vec::new();
+                for _ in 0..count {
+                    let (rest, value) = primitive(input, *p)?;
+                    input = rest;
+                    values.push(value);
+                }
+                Ok((input, NrbfValue::Array(values)))
+            }
+            _ => {
+                let (rest, values) = self.record_values(input, count)?;
+                Ok((rest, NrbfValue::Array(values)))
+            }
+        }
+    }
+
+    fn class_members<'a>(
+        &mut self,
+        mut input: &'a [u8],
+        metadata_id: i32,
+    ) -> IResult<&'a [u8], NrbfObject> {
+        let (class_name, library_id, member_names, member_types) =
+            match self.classes.get(&metadata_id) {
+                Some(m) => (
+                    m.name.clone(),
+                    m.library_id,
+                    m.member_names.clone(),
+                    m.member_types.clone(),
+                ),
+                None => return fail(input, NomErrorKind::Verify),
+            };
+
+        let mut members = Vec::new();
+        let mut pending_nulls = 0;
+        for (i, member_name) in member_names.into_iter().enumerate() {
+            if pending_nulls > 0 {
+                pending_nulls -= 1;
+                members.push((member_name, NrbfValue::Null));
+                continue;
+            }
+
+            let value = match member_types.as_ref().and_then(|t| t.get(i)) {
+                Some(MemberType::Primitive(p)) => {
+                    let (rest, value) = primitive(input, *p)?;
+                    input = rest;
+                    value
+                }
+                _ => loop {
+                    let (rest, record) = self.record(input)?;
+                    input = rest;
+                    match record {
+                        Record::Value(v) => break v,
+                        Record::NullMultiple(n) => {
+                            pending_nulls = n.saturating_sub(1);
+                            break NrbfValue::Null;
+                        }
+                        Record::Library => continue,
+                        Record::End => return fail(input, NomErrorKind::Eof),
+                    }
+                },
+            };
+            members.push((member_name, value));
+        }
+
+        Ok((
+            input,
+            NrbfObject {
+                class_name,
+                library: library_id.and_then(|id| self.libraries.get(&id).cloned()),
+                members,
+            },
+        ))
+    }
+
+    /// Member values can be records themselves, which can have members...
+    fn record<'a>(&mut self, input: &'a [u8]) -> IResult<&'a [u8], Record> {
+        if self.nesting >= MAX_RECORD_NESTING {
+            return fail(input, NomErrorKind::TooLarge);
+        }
+        self.nesting += 1;
+        let res = self.single_record(input);
+        self.nesting -= 1;
+        res
+    }
+
+    // RecordTypeEnumeration
+    fn single_record<'a>(&mut self, input: &'a [u8]) -> IResult<&'a [u8], Record> {
+        let (input, record_type) = le_u8(input)?;
+
+        match record_type {
+            // SerializedStreamHeader
+            0 => {
+                let (input, _) = take(16usize)(input)?;
+                Ok((input, Record::Library))
+            }
+            // ClassWithId
+            1 => {
+                let (input, id) = le_i32(input)?;
+                let (input, metadata_id) = le_i32(input)?;
+                let (input, object) = self.class_members(input, metadata_id)?;
+                Ok((input, self.store(id, NrbfValue::Object(object))))
+            }
+            // SystemClassWithMembers, ClassWithMembers, SystemClassWithMembersAndTypes, ClassWithMembersAndTypes
+            2..=5 => {
+                let (mut input, (id, name, member_names)) = self.class_info(input)?;
+                let mut member_types = None;
+                if record_type == 4 || record_type == 5 {
+                    let (rest, types) = self.member_type_info(input, member_names.len())?;
+                    input = rest;
+                    member_types = Some(types);
+                }
+                let mut library_id = None;
+                if record_type == 3 || record_type == 5 {
+                    let (rest, lib) = le_i32(input)?;
+                    input = rest;
+                    library_id = Some(lib);
+                }
+
+                self.classes.insert(
+                    id,
+                    ClassMetadata {
+                        name,
+                        library_id,
+                        member_names,
+                        member_types,
+                    },
+                );
+                let (input, object) = self.class_members(input, id)?;
+                Ok((input, self.store(id, NrbfValue::Object(object))))
+            }
+            // BinaryObjectString
+            6 => {
+                let (input, id) = le_i32(input)?;
+                let (input, text) = length_prefixed_string(input)?;
+                Ok((input, self.store(id, NrbfValue::String(text))))
+            }
+            // BinaryArray
+            7 => {
+                let (input, id) = le_i32(input)?;
+                let (input, array_type) = le_u8(input)?;
+                let (mut input, rank) = count(input)?;
+                let mut total: usize = 1;
+                for _ in 0..rank {
+                    let (rest, length) = count(input)?;
+                    total = match total.checked_mul(length) {
+                        Some(t) if t <= rest.len() => t,
+                        _ => return fail(input, NomErrorKind::Count),
+                    };
+                    input = rest;
+                }
+                if (3..=5).contains(&array_type) {
+                    // lower bounds
+                    let (rest, _) = take(rank * 4)(input)?;
+                    input = rest;
+                }
+                let (input, binary_type) = le_u8(input)?;
+                let (input, element_type) = member_type_additional_info(input, binary_type)?;
+                let (input, value) = self.typed_values(input, &element_type, total)?;
+                Ok((input, self.store(id, value)))
+            }
+            // MemberPrimitiveTyped
+            8 => {
+                let (input, primitive_type) = le_u8(input)?;
+                let (input, value) = primitive(input, primitive_type)?;
+                Ok((input, Record::Value(value)))
+            }
+            // MemberReference
+            9 => {
+                let (input, id) = le_i32(input)?;
+                Ok((input, Record::Value(NrbfValue::Reference(id))))
+            }
+            // ObjectNull
+            10 => Ok((input, Record::Value(NrbfValue::Null))),
+            // MessageEnd
+            11 => Ok((input, Record::End)),
+            // BinaryLibrary
+            12 => {
+                let (input, id) = le_i32(input)?;
+                let (input, name) = length_prefixed_string(input)?;
+                self.libraries.insert(id, name);
+                Ok((input, Record::Library))
+            }
+            // ObjectNullMultiple256
+            13 => le_u8(input).map(|(i, n)| (i, Record::NullMultiple(n as usize))),
+            // ObjectNullMultiple
+            14 => null_count(input).map(|(i, n)| (i, Record::NullMultiple(n))),
+            // ArraySinglePrimitive
+            15 => {
+                let (input, id) = le_i32(input)?;
+                let (input, length) = count(input)?;
+                let (input, primitive_type) = le_u8(input)?;
+                let (input, value) =
+                    self.typed_values(input, &MemberType::Primitive(primitive_type), length)?;
+                Ok((input, self.store(id, value)))
+            }
+            // ArraySingleObject, ArraySingleString
+            16 | 17 => {
+                let (input, id) = le_i32(input)?;
+                let (input, length) = count(input)?;
+                let (input, values) = self.record_values(input, length)?;
+                Ok((input, self.store(id, NrbfValue::Array(values))))
+            }
+            // MethodCall and MethodReturn (remoting) are not used by the programs we talk to
+            _ => fail(input, NomErrorKind::Switch),
+        }
+    }
+}
+
+/// Replaces the references with the objects they point to.
+/// Every object is resolved only once, references to it get a copy
+struct Resolver<'a> {
+    objects: &'a HashMap<i32, NrbfValue>,
+    resolved: HashMap<i32, (NrbfValue, usize)>, // with the number of values in it
+    in_progress: HashSet<i32>,
+    values_left: usize,
+}
+
+impl Resolver<'_> {
+    fn new(objects: &HashMap<i32, NrbfValue>) -> Resolver<'_> {
+        Resolver {
+            objects,
+            resolved: HashMap::new(),
+            in_progress: HashSet::new(),
+            values_left: MAX_RESOLVED_VALUES,
+        }
+    }
+
+    fn take_budget(&mut self, values: usize) -> Result<(), String> {
+        self.values_left = self.values_left.checked_sub(values).ok_or(format!(
+            "NRBF object graph resolves to more than {} values",
+            MAX_RESOLVED_VALUES
+        ))?;
+        Ok(())
+    }
+
+    /// The resolved value and the number of values in it
+    fn resolve(&mut self, value: &NrbfValue, depth: usize) -> Result<(NrbfValue, usize), String> {
+        if depth > MAX_RESOLVE_DEPTH {
+            return Err(format!(
+                "NRBF object graph is nested deeper than {}",
+                MAX_RESOLVE_DEPTH
+            ));
+        }
+
+        let (resolved, size) = match value {
+            NrbfValue::Reference(id) => {
+                if let Some((resolved, size)) = self.resolved.get(id) {
+                    let copy = (resolved.clone(), *size);
+                    self.take_budget(copy.1)?;
+                    return Ok(copy);
+                }
+                let objects = self.objects;
+                // cyclic references and missing objects stay references
+                let object = match objects.get(id) {
+                    Some(object) if !self.in_progress.contains(id) => object,
+                    _ => return Ok((value.clone(), 1)),
+                };
+
+                self.in_progress.insert(*id);
+                let result = self.resolve(object, depth + 1);
+                self.in_progress.remove(id);
+                let result = result?;
+                self.resolved.insert(*id, result.clone());
+                return Ok(result);
+            }
+            NrbfValue::Array(values) => {
+                let mut resolved = Vec::new();
+                let mut size = 1;
+                for v in values {
+                    let (v, s) = self.resolve(v, depth + 1)?;
+                    resolved.push(v);
+                    size += s;
+                }
+                (NrbfValue::Array(resolved), size)
+            }
+            NrbfValue::Object(o) => {
+                let mut members = Vec::new();
+                let mut size = 1;
+                for (n, v) in &o.members {
+                    let (v, s) = self.resolve(v, depth + 1)?;
+                    members.push((n.clone(), v));
+                    size += s;
+                }
+                (
+                    NrbfValue::Object(NrbfObject {
+                        class_name: o.class_name.clone(),
+                        library: o.library.clone(),
+                        members,
+                    }),
+                    size,
+                )
+            }
+            other => (other.clone(), 1),
+        };
+
+        self.take_budget(1)?;
+        Ok((resolved, size))
+    }
+}
+
+/// Decodes the records of one message (with or without the SerializedStreamHeader) into the root object.
+/// The root is the first object record of the stream
+pub fn decode_nrbf_records(input: &[u8]) -> Result<NrbfValue, String> {
+    let mut decoder = NrbfDecoder::default();
+    let mut root: Option<NrbfValue> = None;
+    let total_len = input.len();
+    let mut input = input;
+
+    while !input.is_empty() {
+        let (rest, record) = decoder.record(input).map_err(|e| {
+            format!(
+                "Invalid NRBF record at byte {}: {}",
+                total_len - input.len(),
+                e
+            )
+        })?;
+        input = rest;
+
+        match record {
+            Record::End => break,
+            Record::Value(v) if root.is_none() => root = Some(v),
+            _ => (),
+        }
+    }
+
+    let root = root.ok_or(String::from("NRBF stream contains no object"))?;
+    Resolver::new(&decoder.objects)
+        .resolve(&root, 0)
+        .map(|(value, _)| value)
+}
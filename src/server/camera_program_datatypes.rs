@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use crate::client::RaceDistance;
 use crate::times::{DayTime, RaceTime, RaceWind};
 use chrono::{NaiveDate, NaiveDateTime};
 use rust_to_ts_types::TypescriptSerializable;
@@ -44,6 +45,43 @@ pub struct HeatIntermediate {
     pub intermediate_time_at: RaceTime,
 }
 
+/// Intermediate mapped to the split distance it (most likely) belongs to
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct HeatSplit {
+    pub distance_meters: Option<u32>,
+    pub race_time: RaceTime,
+    pub time: DayTime,
+}
+impl HeatSplit {
+    /// Intermediates are taken in race time order, the nth one belongs to the nth split distance
+    pub fn from_intermediates(
+        distance_meters: u32,
+        intermediates: &Vec<HeatIntermediate>,
+    ) -> Vec<HeatSplit> {
+        let distance = RaceDistance::new(distance_meters);
+
+        let mut sorted: Vec<&HeatIntermediate> = intermediates.iter().collect();
+        sorted.sort_by_key(|i| i.intermediate_time_at.into_ten_thousands());
+
+        sorted
+            .into_iter()
+            .enumerate()
+            .map(|(i, intermediate)| HeatSplit {
+                distance_meters: distance.get_distance_at_hold(i + 1),
+                race_time: intermediate.intermediate_time_at.clone(),
+                time: intermediate.time.clone(),
+            })
+            .collect()
+    }
+}
+
+/// The splits of a heat, updated whenever one of its intermediates is stored
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HeatSplits {
+    pub id: Uuid,
+    pub splits: Vec<HeatSplit>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct HeatFalseStart {
     pub application: String,
@@ -86,6 +124,14 @@ pub struct HeatWind {
     pub wind: RaceWind,
 }
 
+/// The race wind the wind server measured during a heat, kept next to the one of the camera program
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct HeatWindServerWind {
+    pub id: Uuid,
+    pub wind: RaceWind,
+    pub differs_from_camera_program: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct HeatWindMissing {
     pub application: String,
@@ -150,7 +196,9 @@ pub struct HeatData {
     pub start_list: HeatStartList,
     pub start: Option<HeatStart>,
     pub intermediates: Option<Vec<HeatIntermediate>>,
+    pub splits: Option<Vec<HeatSplit>>,
     pub wind: Option<HeatWind>,
+    pub wind_server_wind: Option<HeatWindServerWind>,
     pub finish: Option<HeatFinish>,
     pub evaluations: Option<Vec<CompetitorEvaluated>>,
     pub result: Option<HeatResult>,
@@ -171,7 +219,7 @@ pub struct Session {
     pub events: Vec<Event>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, TypescriptSerializable)]
 pub enum DistanceType {
     Relay,
     Normal,
@@ -241,4 +289,71 @@ pub struct HeatAssignment {
     pub distance: u32,
     pub heat_descriminator: u8,
     pub athlete_ids: HashMap<u32, Uuid>,
+    /// Lanes of relay events, a lane holds either an athlete or a relay team
+    #[serde(default)]
+    pub relay_teams: HashMap<u32, RelayTeam>,
+}
+
+impl HeatAssignment {
+    pub fn contains_athlete(&self, athlete_id: &Uuid) -> bool {
+        self.athlete_ids.values().any(|id| id == athlete_id)
+            || self
+                .relay_teams
+                .values()
+                .any(|team| team.member_ids.contains(athlete_id))
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for (lane, team) in &self.relay_teams {
+            if self.athlete_ids.contains_key(lane) {
+                return Err(format!("Lane {} has an athlete and a relay team", lane));
+            }
+            if team.name.trim().is_empty() {
+                return Err(format!("The relay team in lane {} has no name", lane));
+            }
+            if team.member_ids.is_empty() || team.member_ids.len() > RELAY_TEAM_MAX_MEMBERS {
+                return Err(format!(
+                    "The relay team {} needs between 1 and {} members",
+                    team.name, RELAY_TEAM_MAX_MEMBERS
+                ));
+            }
+        }
+
+        let mut seen: Vec<&Uuid> = Vec::new();
+        for id in self.athlete_ids.values().chain(
+            self.relay_teams
+                .values()
+                .flat_map(|team| team.member_ids.iter()),
+        ) {
+            if seen.contains(&id) {
+                return Err(format!("Athlete {} is assigned to more than one lane", id));
+            }
+            seen.push(id);
+        }
+
+        Ok(())
+    }
+}
+
+pub const RELAY_TEAM_MAX_MEMBERS: usize = 4;
+
+/// A relay team in one lane, the members in running order
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct RelayTeam {
+    pub name: String,
+    pub member_ids: Vec<Uuid>,
+}
+
+/// Scheduling of one event in a track competition.
+/// Heat assignments belong to the event with the same distance and heat_descriminator
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct CompetitionEvent {
+    pub id: Uuid,
+    pub name: String,
+    pub distance: u32,
+    pub distance_type: DistanceType,
+    pub heat_descriminator: u8,
+    pub session_date: NaiveDate,
+    pub scheduled_start_time: DayTime,
+    pub minutes_between_heats: u32,
 }
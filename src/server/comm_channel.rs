@@ -7,7 +7,10 @@ use crate::{
     },
     interface::{MessageFromClientToServer, MessageFromServerToClient},
     server::bib_detection::{MessageFromBibServer, MessageToBibServer},
-    webserver::{MessageFromWebControl, MessageToWebControl},
+    server::display_clients::MessageToDisplayClients,
+    server::live_feed::{LiveFeedEvent, LiveFeedSnapshot},
+    server::session_recording::{RecordedStream, SessionRecorder},
+    webserver::{MessageFromWebControl, MessageToWebControl, WebControlRole},
     wind::format::{MessageToWindServer, WindMessageBroadcast},
 };
 use async_broadcast::{
@@ -15,7 +18,11 @@ use async_broadcast::{
     Sender as BroadcastSender, TrySendError as BroadcastTrySendError,
 };
 use async_channel::{Receiver, RecvError, Sender, TrySendError};
-use std::time::Duration;
+use serde::Serialize;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 use tokio::time::{self, error::Elapsed};
 
 pub enum ConnectionCheck {
@@ -32,8 +39,8 @@ pub struct InstructionCommunicationChannel {
     inbound_receiver: Receiver<IncomingInstruction>,
     outbound_sender_timing_program: BroadcastSender<InstructionToTimingProgram>,
     outbound_receiver_timing_program: BroadcastReceiverStorage<InstructionToTimingProgram>,
-    outbound_sender_client: BroadcastSender<MessageFromServerToClient>,
-    outbound_receiver_client: BroadcastReceiverStorage<MessageFromServerToClient>,
+    outbound_sender_client: BroadcastSender<MessageToDisplayClients>,
+    outbound_receiver_client: BroadcastReceiverStorage<MessageToDisplayClients>,
     outbound_sender_web_control: BroadcastSender<MessageToWebControl>,
     outbound_receiver_web_control: BroadcastReceiverStorage<MessageToWebControl>,
     outbound_sender_wind_server: BroadcastSender<MessageToWindServer>,
@@ -42,6 +49,9 @@ pub struct InstructionCommunicationChannel {
     outbound_receiver_bib_server: BroadcastReceiverStorage<MessageToBibServer>,
     outbound_sender_idcapture_server: BroadcastSender<MessageToIdcaptureServer>,
     outbound_receiver_idcapture_server: BroadcastReceiverStorage<MessageToIdcaptureServer>,
+    outbound_sender_live_feed: BroadcastSender<LiveFeedEvent>,
+    outbound_receiver_live_feed: BroadcastReceiverStorage<LiveFeedEvent>,
+    live_feed_snapshot: Arc<Mutex<LiveFeedSnapshot>>,
     connection_check_sender_camera_program_timing_port: BroadcastSender<bool>,
     connection_check_receiver_camera_program_timing_port: BroadcastReceiverStorage<bool>,
     connection_check_sender_camera_program_data_port: BroadcastSender<bool>,
@@ -50,6 +60,7 @@ pub struct InstructionCommunicationChannel {
     connection_check_receiver_camera_program_xml_port: BroadcastReceiverStorage<bool>,
     connection_check_sender_external_display_passthrough: BroadcastSender<bool>,
     connection_check_receiver_external_display_passthrough: BroadcastReceiverStorage<bool>,
+    session_recorder: SessionRecorder,
 }
 impl InstructionCommunicationChannel {
     pub fn new(args: &Args) -> Self {
@@ -62,7 +73,7 @@ impl InstructionCommunicationChannel {
             MAX_NUMBER_OF_MESSAGES_IN_INTERNAL_BUFFERS,
         );
         os.set_overflow(true);
-        let (mut sc, rc) = async_broadcast::broadcast::<MessageFromServerToClient>(
+        let (mut sc, rc) = async_broadcast::broadcast::<MessageToDisplayClients>(
             MAX_NUMBER_OF_MESSAGES_IN_INTERNAL_BUFFERS,
         );
         sc.set_overflow(true);
@@ -82,6 +93,9 @@ impl InstructionCommunicationChannel {
             MAX_NUMBER_OF_MESSAGES_IN_INTERNAL_BUFFERS,
         );
         sid.set_overflow(true);
+        let (mut slf, rlf) =
+            async_broadcast::broadcast::<LiveFeedEvent>(MAX_NUMBER_OF_MESSAGES_IN_INTERNAL_BUFFERS);
+        slf.set_overflow(true);
         // channels that only check for connection
         let (mut scptp, rcptp) =
             async_broadcast::broadcast::<bool>(MAX_NUMBER_OF_MESSAGES_IN_INTERNAL_BUFFERS);
@@ -112,6 +126,9 @@ impl InstructionCommunicationChannel {
             outbound_receiver_bib_server: BroadcastReceiverStorage::new(rbi, args),
             outbound_sender_idcapture_server: sid,
             outbound_receiver_idcapture_server: BroadcastReceiverStorage::new(rid, args),
+            outbound_sender_live_feed: slf,
+            outbound_receiver_live_feed: BroadcastReceiverStorage::new(rlf, args),
+            live_feed_snapshot: Arc::new(Mutex::new(LiveFeedSnapshot::default())),
             connection_check_sender_camera_program_timing_port: scptp,
             connection_check_receiver_camera_program_timing_port: BroadcastReceiverStorage::new(
                 rcptp, args,
@@ -128,6 +145,19 @@ impl InstructionCommunicationChannel {
             connection_check_receiver_external_display_passthrough: BroadcastReceiverStorage::new(
                 rdpt, args,
             ),
+            session_recorder: SessionRecorder::new(args),
+        }
+    }
+
+    pub fn record_inbound_bytes(&self, stream: RecordedStream, bytes: &[u8]) -> () {
+        self.session_recorder.record(stream, bytes);
+    }
+
+    /// For the json framed exchanges, the frame content is re-serialized from the decoded message
+    pub fn record_inbound_message<T: Serialize>(&self, stream: RecordedStream, mes: &T) -> () {
+        match serde_json::to_vec(mes) {
+            Ok(bytes) => self.session_recorder.record(stream, &bytes),
+            Err(e) => error!("Could not serialize inbound message for recording: {}", e),
         }
     }
 
@@ -188,13 +218,26 @@ impl InstructionCommunicationChannel {
         }
     }
 
+    /// Waits for room in the internal buffer instead of dropping the instruction when it is full.
+    /// For sources that deliver a lot at once and can not send it again (like a whole lynx schedule)
+    pub async fn send_command_from_camera_program(
+        &self,
+        inst: InstructionFromCameraProgram,
+    ) -> Result<(), String> {
+        self.inbound_sender
+            .send(IncomingInstruction::FromCameraProgram(inst))
+            .await
+            .map_err(|_| String::from("Internal communication channel closed..."))
+    }
+
     pub fn take_in_command_from_web_control(
         &self,
+        role: WebControlRole,
         inst: MessageFromWebControl,
     ) -> Result<(), String> {
         match self
             .inbound_sender
-            .try_send(IncomingInstruction::FromWebControl(inst))
+            .try_send(IncomingInstruction::FromWebControl(role, inst))
         {
             Ok(_) => Ok(()),
             Err(TrySendError::Closed(_)) => {
@@ -209,12 +252,15 @@ impl InstructionCommunicationChannel {
 
     pub fn take_in_command_from_client(
         &self,
+        client_name: &str,
         inst: MessageFromClientToServer,
     ) -> Result<(), String> {
         match self
             .inbound_sender
-            .try_send(IncomingInstruction::FromClient(inst))
-        {
+            .try_send(IncomingInstruction::FromClient(
+                String::from(client_name),
+                inst,
+            )) {
             Ok(_) => Ok(()),
             Err(TrySendError::Closed(_)) => {
                 Err(format!("Internal communication channel closed..."))
@@ -327,11 +373,17 @@ impl InstructionCommunicationChannel {
         self.outbound_receiver_timing_program.get_active_receiver()
     }
 
-    pub fn send_out_command_to_client(
+    pub fn send_out_command_to_clients(
         &self,
+        targets: Vec<String>,
         inst: MessageFromServerToClient,
     ) -> Result<(), String> {
-        match self.outbound_sender_client.try_broadcast(inst) {
+        match self
+            .outbound_sender_client
+            .try_broadcast(MessageToDisplayClients {
+                targets,
+                message: inst,
+            }) {
             Ok(Some(_)) => {
                 trace!("Thrown away old message in internal comm channel (to client)");
                 Ok(())
@@ -351,7 +403,7 @@ impl InstructionCommunicationChannel {
         }
     }
 
-    pub fn client_receiver(&self) -> BroadcastReceiver<MessageFromServerToClient> {
+    pub fn client_receiver(&self) -> BroadcastReceiver<MessageToDisplayClients> {
         self.outbound_receiver_client.get_active_receiver()
     }
 
@@ -376,6 +428,41 @@ impl InstructionCommunicationChannel {
         }
     }
 
+    /// The live feed is public and often has no listeners, so no receivers is not worth a warning
+    pub fn send_out_live_feed_event(&self, event: LiveFeedEvent) -> Result<(), String> {
+        self.live_feed_snapshot
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .apply(&event);
+
+        match self.outbound_sender_live_feed.try_broadcast(event) {
+            Ok(Some(_)) => {
+                trace!("Thrown away old message in internal comm channel (to live feed)");
+                Ok(())
+            }
+            Ok(None) => Ok(()),
+            Err(BroadcastTrySendError::Inactive(_)) => Ok(()),
+            Err(BroadcastTrySendError::Full(_)) => {
+                error!("Live feed receivers are there, but outbound internal channel full. This should not happen!");
+                Ok(())
+            }
+            Err(BroadcastTrySendError::Closed(_)) => Err(format!(
+                "Live feed communication channel went away unexpectedly"
+            )),
+        }
+    }
+
+    pub fn live_feed_receiver(&self) -> BroadcastReceiver<LiveFeedEvent> {
+        self.outbound_receiver_live_feed.get_active_receiver()
+    }
+
+    pub fn live_feed_snapshot(&self) -> LiveFeedSnapshot {
+        self.live_feed_snapshot
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
     pub fn web_control_there_to_receive(&self) -> bool {
         self.outbound_sender_web_control.receiver_count() > 0
     }
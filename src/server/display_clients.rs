@@ -0,0 +1,279 @@
+#[cfg(test)]
+mod address_tests;
+
+use crate::{
+    args::Args,
+    interface::{MessageFromServerToClient, ServerImposedSettings},
+};
+use rust_to_ts_types::TypescriptSerializable;
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, SocketAddr};
+
+/// The client configured via `address_display_client` is registered under this name
+pub const MAIN_DISPLAY_CLIENT_NAME: &str = "main";
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, TypescriptSerializable)]
+pub enum DisplayClientRole {
+    FinishLine,
+    StartArea,
+    Speaker,
+    CallRoom,
+}
+impl DisplayClientRole {
+    fn parse(role: &str) -> Result<Self, String> {
+        match role.trim().to_lowercase().as_str() {
+            "finish" | "finishline" => Ok(DisplayClientRole::FinishLine),
+            "start" | "startarea" => Ok(DisplayClientRole::StartArea),
+            "speaker" => Ok(DisplayClientRole::Speaker),
+            "callroom" | "call_room" => Ok(DisplayClientRole::CallRoom),
+            _ => Err(format!(
+                "Unknown display client role '{}' (allowed: finish, start, speaker, callroom)",
+                role
+            )),
+        }
+    }
+}
+
+/// Which kinds of messages a display client gets. Messages to set up the client (version, settings, images) are always sent
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct DisplayClientRouting {
+    pub free_text: bool,
+    pub advertisements: bool,
+    pub timing: bool,
+    pub clock: bool,
+    pub external_frames: bool,
+    pub display_entries: bool,
+    pub call_room: bool, // the client is dedicated to the call room, nothing else switches it away
+}
+impl DisplayClientRouting {
+    pub fn for_role(role: &DisplayClientRole) -> Self {
+        match role {
+            DisplayClientRole::FinishLine => Self {
+                free_text: true,
+                advertisements: true,
+                timing: true,
+                clock: true,
+                external_frames: true,
+                display_entries: true,
+                call_room: false,
+            },
+            // start lists and the clock are relevant, but the runners there should not be distracted by bib popups
+            DisplayClientRole::StartArea => Self {
+                free_text: true,
+                advertisements: true,
+                timing: true,
+                clock: true,
+                external_frames: false,
+                display_entries: false,
+                call_room: false,
+            },
+            // the speaker wants all the race information, but no show elements
+            DisplayClientRole::Speaker => Self {
+                free_text: true,
+                advertisements: false,
+                timing: true,
+                clock: false,
+                external_frames: false,
+                display_entries: true,
+                call_room: false,
+            },
+            // runs unattended next to the call room
+            DisplayClientRole::CallRoom => Self {
+                free_text: false,
+                advertisements: false,
+                timing: false,
+                clock: false,
+                external_frames: false,
+                display_entries: false,
+                call_room: true,
+            },
+        }
+    }
+
+    pub fn allows(&self, msg: &MessageFromServerToClient) -> bool {
+        if self.call_room
+            && DisplayClientMode::after_message(msg)
+                .is_some_and(|m| m != DisplayClientMode::CallRoom)
+        {
+            return false;
+        }
+
+        match msg {
+            MessageFromServerToClient::DisplayText(_)
+            | MessageFromServerToClient::TickerQueue(_) => self.free_text,
+            // QR codes are a show element, like the advertisements
+            MessageFromServerToClient::Advertisements
+            | MessageFromServerToClient::DisplayQrCode(_) => self.advertisements,
+            MessageFromServerToClient::Timing
+            | MessageFromServerToClient::TimingStateUpdate(_)
+            | MessageFromServerToClient::FieldEvent(_) => self.timing,
+            MessageFromServerToClient::CallRoom(_) => self.call_room,
+            MessageFromServerToClient::Clock(_) => self.clock,
+            MessageFromServerToClient::DisplayExternalFrame(_) => self.external_frames,
+            MessageFromServerToClient::PushDisplayEntry(_) => self.display_entries,
+            MessageFromServerToClient::RequestVersion
+            | MessageFromServerToClient::ProductKey(_)
+            | MessageFromServerToClient::ServerImposedSettings(_)
+            | MessageFromServerToClient::Clear
+            | MessageFromServerToClient::AdvertisementImages(_)
+            | MessageFromServerToClient::TimingSettingsUpdate(_)
+            | MessageFromServerToClient::RequestTimingSettings
+            | MessageFromServerToClient::ClientInternal(_) => true,
+        }
+    }
+}
+
+/// What the server last told the client to show (mirrors the client side `ClientState`)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, TypescriptSerializable)]
+pub enum DisplayClientMode {
+    Created,
+    Idle,
+    DisplayText,
+    DisplayExternalFrame,
+    QrCode,
+    CallRoom,
+    FieldEvent,
+    Advertisements,
+    Timing,
+    Clock,
+}
+impl DisplayClientMode {
+    /// None if the message does not change the mode of the client
+    pub fn after_message(msg: &MessageFromServerToClient) -> Option<Self> {
+        match msg {
+            MessageFromServerToClient::RequestVersion => Some(DisplayClientMode::Idle),
+            MessageFromServerToClient::Clear => Some(DisplayClientMode::Idle),
+            MessageFromServerToClient::DisplayText(_) => Some(DisplayClientMode::DisplayText),
+            MessageFromServerToClient::DisplayExternalFrame(_) => {
+                Some(DisplayClientMode::DisplayExternalFrame)
+            }
+            MessageFromServerToClient::DisplayQrCode(_) => Some(DisplayClientMode::QrCode),
+            MessageFromServerToClient::CallRoom(_) => Some(DisplayClientMode::CallRoom),
+            MessageFromServerToClient::FieldEvent(_) => Some(DisplayClientMode::FieldEvent),
+            MessageFromServerToClient::Advertisements => Some(DisplayClientMode::Advertisements),
+            MessageFromServerToClient::Timing => Some(DisplayClientMode::Timing),
+            MessageFromServerToClient::Clock(_) => Some(DisplayClientMode::Clock),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DisplayClientConfig {
+    pub name: String,
+    pub role: DisplayClientRole,
+    pub address: SocketAddr,
+}
+
+/// Main client from `address_display_client` first, then all `additional_display_client` entries ("name,role,address[:port]")
+pub fn display_clients_from_args(args: &Args) -> Result<Vec<DisplayClientConfig>, String> {
+    // IPv6 addresses contain colons themselves, so only a complete socket address brings its own port
+    let parse_address = |address: &str| -> Result<SocketAddr, String> {
+        if let Ok(socket_address) = address.parse::<SocketAddr>() {
+            return Ok(socket_address);
+        }
+
+        let ip = address
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<IpAddr>()
+            .map_err(|e| format!("Invalid display client address '{}': {}", address, e))?;
+        let port = args
+            .display_client_communication_port
+            .parse::<u16>()
+            .map_err(|e| {
+                format!(
+                    "Invalid display client communication port '{}': {}",
+                    args.display_client_communication_port, e
+                )
+            })?;
+
+        Ok(SocketAddr::new(ip, port))
+    };
+
+    let mut res = Vec::new();
+    if let Some(display_client_ip) = &args.address_display_client {
+        res.push(DisplayClientConfig {
+            name: String::from(MAIN_DISPLAY_CLIENT_NAME),
+            role: DisplayClientRole::FinishLine,
+            address: parse_address(display_client_ip)?,
+        });
+    }
+
+    for entry in &args.additional_display_client {
+        let parts: Vec<&str> = entry.split(',').map(|p| p.trim()).collect();
+        if parts.len() != 3 || parts[0].is_empty() {
+            return Err(format!(
+                "Display client '{}' must have the format 'name,role,address[:port]'",
+                entry
+            ));
+        }
+        if res.iter().any(|c: &DisplayClientConfig| c.name == parts[0]) {
+            return Err(format!("Display client name '{}' is used twice", parts[0]));
+        }
+
+        res.push(DisplayClientConfig {
+            name: String::from(parts[0]),
+            role: DisplayClientRole::parse(parts[1])?,
+            address: parse_address(parts[2])?,
+        });
+    }
+
+    Ok(res)
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MessageToDisplayClients {
+    pub targets: Vec<String>,
+    pub message: MessageFromServerToClient,
+}
+
+/// State the server keeps per display client
+pub struct DisplayClient {
+    pub config: DisplayClientConfig,
+    pub connected: bool,
+    pub mode: DisplayClientMode,
+    pub routing: DisplayClientRouting,
+    pub server_imposed_settings: ServerImposedSettings,
+}
+impl DisplayClient {
+    pub fn new(config: DisplayClientConfig, args: &Args) -> Self {
+        Self {
+            routing: DisplayClientRouting::for_role(&config.role),
+            config,
+            connected: false,
+            mode: DisplayClientMode::Created,
+            server_imposed_settings: ServerImposedSettings::new(args),
+        }
+    }
+
+    pub fn connection(&self) -> DisplayClientConnection {
+        DisplayClientConnection {
+            name: self.config.name.clone(),
+            role: self.config.role.clone(),
+            address_with_port: self.config.address.to_string(),
+            connected: self.connected,
+            mode: self.mode.clone(),
+            routing: self.routing.clone(),
+            server_imposed_settings: self.server_imposed_settings.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct DisplayClientConnection {
+    pub name: String,
+    pub role: DisplayClientRole,
+    pub address_with_port: String,
+    pub connected: bool,
+    pub mode: DisplayClientMode,
+    pub routing: DisplayClientRouting,
+    pub server_imposed_settings: ServerImposedSettings,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct DisplayClientUpdate {
+    pub name: String,
+    pub routing: DisplayClientRouting,
+    pub server_imposed_settings: ServerImposedSettings,
+}
@@ -0,0 +1,69 @@
+//! Display client addresses from the command line, with and without port
+
+use super::{display_clients_from_args, DisplayClientRole};
+use crate::args::Args;
+use clap::Parser;
+use std::net::SocketAddr;
+
+fn addresses(additional: &[&str]) -> Result<Vec<SocketAddr>, String> {
+    let mut command = vec!["jta-display-wall-adapter", "server"];
+    for entry in additional {
+        command.push("--additional-display-client");
+        command.push(entry);
+    }
+    let args = Args::parse_from(command);
+
+    display_clients_from_args(&args).map(|clients| clients.into_iter().map(|c| c.address).collect())
+}
+
+fn address(input: &str) -> SocketAddr {
+    input.parse().unwrap()
+}
+
+#[test]
+fn default_port_is_added() {
+    assert_eq!(
+        addresses(&["start,start,192.168.1.20", "speaker,speaker,::1"]),
+        Ok(vec![address("192.168.1.20:5678"), address("[::1]:5678")])
+    );
+    assert_eq!(
+        addresses(&["start,start,[fe80::2]"]),
+        Ok(vec![address("[fe80::2]:5678")])
+    );
+}
+
+#[test]
+fn own_port_is_kept() {
+    assert_eq!(
+        addresses(&[
+            "start,start,192.168.1.20:7000",
+            "speaker,speaker,[::1]:7001"
+        ]),
+        Ok(vec![address("192.168.1.20:7000"), address("[::1]:7001")])
+    );
+}
+
+#[test]
+fn invalid_addresses_are_rejected() {
+    assert!(addresses(&["start,start,192.168.1"]).is_err());
+    assert!(addresses(&["start,start,192.168.1.20:port"]).is_err());
+    assert!(addresses(&["start,unknown,192.168.1.20"]).is_err());
+}
+
+#[test]
+fn role_and_name_are_read() {
+    let args = Args::parse_from([
+        "jta-display-wall-adapter",
+        "server",
+        "--address-display-client",
+        "10.0.0.5",
+        "--additional-display-client",
+        "call room, callroom, 10.0.0.6",
+    ]);
+    let clients = display_clients_from_args(&args).unwrap();
+
+    assert_eq!(clients.len(), 2);
+    assert!(matches!(clients[0].role, DisplayClientRole::FinishLine));
+    assert_eq!(clients[1].name, "call room");
+    assert!(matches!(clients[1].role, DisplayClientRole::CallRoom));
+}
@@ -0,0 +1,224 @@
+#[cfg(test)]
+mod ranking_tests;
+
+use crate::times::{DayTime, RaceWind};
+use rust_to_ts_types::TypescriptSerializable;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, TypescriptSerializable)]
+pub enum FieldEventKind {
+    Jump, // horizontal jumps, every attempt gets a wind reading
+    Throw,
+}
+
+/// A jump or throw competition, entered manually in the web control
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct FieldEvent {
+    pub id: Uuid,
+    pub name: String,
+    pub kind: FieldEventKind,
+    pub nr_attempts: u8,
+    pub scheduled_start_time: DayTime,
+    pub competitors: Vec<FieldEventCompetitor>, // in competition order
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct FieldEventCompetitor {
+    pub bib: u32,
+    pub first_name: String,
+    pub last_name: String,
+    pub club: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, TypescriptSerializable)]
+#[serde(tag = "type", content = "data")]
+pub enum FieldAttemptResult {
+    Mark(u32), // centimeters
+    Foul,
+    Pass,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct FieldEventAttempt {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub bib: u32,
+    pub attempt_nr: u8, // starts at 1
+    pub result: FieldAttemptResult,
+    pub wind: Option<RaceWind>, // None for throws, or if no jump measurement arrived
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct FieldEventStanding {
+    pub rank: Option<u32>, // None as long as there is no valid mark
+    pub competitor: FieldEventCompetitor,
+    pub best_mark: Option<u32>,
+    pub best_mark_wind: Option<RaceWind>,
+    pub attempts: Vec<Option<FieldEventAttempt>>, // one entry per attempt of the event
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct FieldEventData {
+    pub event: FieldEvent,
+    pub attempts: Vec<FieldEventAttempt>,
+    pub standings: Vec<FieldEventStanding>,
+    pub up_next: Option<FieldEventUpNext>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct FieldEventUpNext {
+    pub competitor: FieldEventCompetitor,
+    pub attempt_nr: u8,
+}
+
+impl FieldEventAttempt {
+    /// The bib has to be one of the event and the attempt one the event has
+    pub fn validate(&self, event: &FieldEvent) -> Result<(), String> {
+        if !event.competitors.iter().any(|c| c.bib == self.bib) {
+            return Err(format!(
+                "Bib {} does not compete in field event {}",
+                self.bib, event.name
+            ));
+        }
+        if self.attempt_nr == 0 || self.attempt_nr > event.nr_attempts {
+            return Err(format!(
+                "Attempt {} is not between 1 and {} of field event {}",
+                self.attempt_nr, event.nr_attempts, event.name
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 745 -> "7.45"
+pub fn format_field_mark(mark_cm: u32) -> String {
+    format!("{}.{:02}", mark_cm / 100, mark_cm % 100)
+}
+
+impl FieldEventData {
+    pub fn build(event: FieldEvent, attempts: Vec<FieldEventAttempt>) -> Self {
+        let standings = rank_field_event(&event, &attempts);
+        let up_next = up_next(&event, &attempts);
+
+        Self {
+            event,
+            attempts,
+            standings,
+            up_next,
+        }
+    }
+}
+
+/// Valid marks of one competitor, best first
+fn marks_descending(attempts: &[Option<FieldEventAttempt>]) -> Vec<u32> {
+    let mut marks: Vec<u32> = attempts
+        .iter()
+        .filter_map(|a| match a.as_ref().map(|a| &a.result) {
+            Some(FieldAttemptResult::Mark(cm)) => Some(*cm),
+            _ => None,
+        })
+        .collect();
+    marks.sort_by(|a, b| b.cmp(a));
+    marks
+}
+
+/// Best mark wins, ties are broken by the second best mark, then the third best...
+/// Competitors that are still equal after that share the rank
+fn compare_marks(a: &[u32], b: &[u32]) -> Ordering {
+    for i in 0..a.len().max(b.len()) {
+        match (a.get(i), b.get(i)) {
+            (Some(x), Some(y)) if x != y => return y.cmp(x),
+            (Some(_), None) => return Ordering::Less,
+            (None, Some(_)) => return Ordering::Greater,
+            _ => (),
+        }
+    }
+    Ordering::Equal
+}
+
+pub fn rank_field_event(
+    event: &FieldEvent,
+    attempts: &[FieldEventAttempt],
+) -> Vec<FieldEventStanding> {
+    let mut standings: Vec<(Vec<u32>, FieldEventStanding)> = event
+        .competitors
+        .iter()
+        .map(|competitor| {
+            let competitor_attempts: Vec<Option<FieldEventAttempt>> = (1..=event.nr_attempts)
+                .map(|nr| {
+                    attempts
+                        .iter()
+                        .find(|a| a.bib == competitor.bib && a.attempt_nr == nr)
+                        .cloned()
+                })
+                .collect();
+            let marks = marks_descending(&competitor_attempts);
+            let best_mark = marks.first().copied();
+            // on equal best marks the wind of the first of them counts
+            let best_mark_wind = competitor_attempts
+                .iter()
+                .flatten()
+                .find(|a| {
+                    best_mark.is_some()
+                        && a.result == FieldAttemptResult::Mark(best_mark.unwrap_or(0))
+                })
+                .and_then(|a| a.wind.clone());
+
+            (
+                marks,
+                FieldEventStanding {
+                    rank: None,
+                    competitor: competitor.clone(),
+                    best_mark,
+                    best_mark_wind,
+                    attempts: competitor_attempts,
+                },
+            )
+        })
+        .collect();
+
+    // stable, so competitors without marks stay in competition order
+    standings.sort_by(|(a, _), (b, _)| compare_marks(a, b));
+
+    let mut previous: Option<(Vec<u32>, u32)> = None;
+    for (i, (marks, standing)) in standings.iter_mut().enumerate() {
+        if marks.is_empty() {
+            continue;
+        }
+        let rank = match &previous {
+            Some((previous_marks, previous_rank))
+                if compare_marks(previous_marks, marks) == Ordering::Equal =>
+            {
+                *previous_rank
+            }
+            _ => i as u32 + 1,
+        };
+        standing.rank = Some(rank);
+        previous = Some((marks.clone(), rank));
+    }
+
+    standings.into_iter().map(|(_, s)| s).collect()
+}
+
+/// Round by round in competition order: the first competitor with the fewest attempts
+pub fn up_next(event: &FieldEvent, attempts: &[FieldEventAttempt]) -> Option<FieldEventUpNext> {
+    let attempts_done = |bib: u32| {
+        attempts
+            .iter()
+            .filter(|a| a.bib == bib && a.attempt_nr <= event.nr_attempts)
+            .count() as u8
+    };
+
+    event
+        .competitors
+        .iter()
+        .map(|c| (c, attempts_done(c.bib)))
+        .filter(|(_, done)| *done < event.nr_attempts)
+        .min_by_key(|(_, done)| *done)
+        .map(|(competitor, done)| FieldEventUpNext {
+            competitor: competitor.clone(),
+            attempt_nr: done + 1,
+        })
+}
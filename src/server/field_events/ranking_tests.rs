@@ -0,0 +1,153 @@
+//! Standings of field events: best mark first, ties broken by the following marks
+
+use super::{
+    compare_marks, rank_field_event, FieldAttemptResult, FieldEvent, FieldEventAttempt,
+    FieldEventCompetitor, FieldEventKind, FieldEventStanding,
+};
+use crate::times::{DayTime, RaceWind};
+use std::cmp::Ordering;
+use uuid::Uuid;
+
+fn event(bibs: &[u32]) -> FieldEvent {
+    FieldEvent {
+        id: Uuid::new_v4(),
+        name: String::from("Long Jump Women"),
+        kind: FieldEventKind::Jump,
+        nr_attempts: 3,
+        scheduled_start_time: DayTime::from_hms_opt(14, 0, 0).unwrap(),
+        competitors: bibs
+            .iter()
+            .map(|bib| FieldEventCompetitor {
+                bib: *bib,
+                first_name: String::from("Eva"),
+                last_name: format!("Competitor {}", bib),
+                club: String::new(),
+            })
+            .collect(),
+    }
+}
+
+fn attempt(bib: u32, attempt_nr: u8, result: FieldAttemptResult) -> FieldEventAttempt {
+    FieldEventAttempt {
+        id: Uuid::new_v4(),
+        event_id: Uuid::nil(),
+        bib,
+        attempt_nr,
+        result,
+        wind: None,
+    }
+}
+
+fn marks(bib: u32, marks: &[u32]) -> Vec<FieldEventAttempt> {
+    marks
+        .iter()
+        .enumerate()
+        .map(|(i, cm)| attempt(bib, i as u8 + 1, FieldAttemptResult::Mark(*cm)))
+        .collect()
+}
+
+fn ranks(standings: &[FieldEventStanding]) -> Vec<(u32, Option<u32>)> {
+    standings
+        .iter()
+        .map(|s| (s.competitor.bib, s.rank))
+        .collect()
+}
+
+#[test]
+fn better_mark_sorts_first() {
+    assert_eq!(compare_marks(&[612], &[598]), Ordering::Less);
+    assert_eq!(compare_marks(&[598], &[612]), Ordering::Greater);
+    // the second best mark only matters on equal best marks
+    assert_eq!(compare_marks(&[612], &[598, 597]), Ordering::Less);
+    assert_eq!(compare_marks(&[612, 590], &[612, 580]), Ordering::Less);
+    assert_eq!(
+        compare_marks(&[612, 590, 500], &[612, 590, 510]),
+        Ordering::Greater
+    );
+    // an additional valid mark wins
+    assert_eq!(compare_marks(&[612, 590], &[612]), Ordering::Less);
+    assert_eq!(compare_marks(&[612, 590], &[612, 590]), Ordering::Equal);
+    assert_eq!(compare_marks(&[], &[]), Ordering::Equal);
+}
+
+#[test]
+fn ranked_by_best_mark() {
+    let mut attempts = marks(1, &[540, 585]);
+    attempts.extend(marks(2, &[612]));
+    attempts.extend(marks(3, &[598, 560, 570]));
+
+    let standings = rank_field_event(&event(&[1, 2, 3]), &attempts);
+
+    assert_eq!(
+        ranks(&standings),
+        vec![(2, Some(1)), (3, Some(2)), (1, Some(3))]
+    );
+    assert_eq!(standings[0].best_mark, Some(612));
+    // one entry per attempt of the event, even if not jumped yet
+    assert_eq!(standings[0].attempts.len(), 3);
+    assert!(standings[0].attempts[1].is_none());
+}
+
+#[test]
+fn ties_are_broken_by_the_following_marks() {
+    let mut attempts = marks(1, &[600, 550, 580]);
+    attempts.extend(marks(2, &[600, 590]));
+    attempts.extend(marks(3, &[580, 600, 550, 500]));
+
+    let standings = rank_field_event(&event(&[1, 2, 3]), &attempts);
+
+    // 2 wins on the second best mark, 1 and 3 only differ in a fourth attempt the event does not have
+    assert_eq!(
+        ranks(&standings),
+        vec![(2, Some(1)), (1, Some(2)), (3, Some(2))]
+    );
+}
+
+#[test]
+fn equal_series_share_the_rank() {
+    let mut attempts = marks(1, &[500]);
+    attempts.extend(marks(2, &[610, 590]));
+    attempts.extend(marks(3, &[590, 610]));
+    attempts.extend(marks(4, &[480]));
+
+    let standings = rank_field_event(&event(&[1, 2, 3, 4]), &attempts);
+
+    // the next rank after a shared one is skipped
+    assert_eq!(
+        ranks(&standings),
+        vec![(2, Some(1)), (3, Some(1)), (1, Some(3)), (4, Some(4))]
+    );
+}
+
+#[test]
+fn competitors_without_marks_are_unranked() {
+    let mut attempts = vec![
+        attempt(1, 1, FieldAttemptResult::Foul),
+        attempt(1, 2, FieldAttemptResult::Pass),
+    ];
+    attempts.extend(marks(3, &[455]));
+
+    let standings = rank_field_event(&event(&[1, 2, 3, 4]), &attempts);
+
+    // in competition order behind the ranked ones
+    assert_eq!(
+        ranks(&standings),
+        vec![(3, Some(1)), (1, None), (2, None), (4, None)]
+    );
+    assert_eq!(standings[1].best_mark, None);
+    assert!(standings[1].attempts[0].is_some());
+}
+
+#[test]
+fn wind_of_the_first_best_mark() {
+    let mut attempts = marks(1, &[600, 600]);
+    attempts[0].wind = Some(RaceWind::parse_from_f32(1.5));
+    attempts[1].wind = Some(RaceWind::parse_from_f32(2.5));
+
+    let standings = rank_field_event(&event(&[1]), &attempts);
+
+    assert_eq!(
+        standings[0].best_mark_wind.as_ref().map(|w| w.to_tenths()),
+        Some(15)
+    );
+}
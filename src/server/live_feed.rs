@@ -0,0 +1,76 @@
+use crate::{
+    server::camera_program_types::{
+        CompetitorEvaluated, HeatResult, HeatStart, HeatStartList, HeatWind,
+    },
+    times::RaceTime,
+};
+use serde::{Deserialize, Serialize};
+
+/// Public, read-only events for livestream overlays (no authentication on this feed, so only competition data)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum LiveFeedEvent {
+    /// Sent once when connecting to the event stream
+    Snapshot(LiveFeedSnapshot),
+    HeatStartList(HeatStartList),
+    HeatStart(HeatStart),
+    TimeRunning(RaceTime),
+    TimeHeld(RaceTime),
+    TimeEnd(RaceTime),
+    TimeReset,
+    Wind(HeatWind),
+    CompetitorEvaluated(CompetitorEvaluated),
+    HeatResult(HeatResult),
+}
+
+/// State of the current heat, as far as the live feed knows it
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LiveFeedSnapshot {
+    pub start_list: Option<HeatStartList>,
+    pub start: Option<HeatStart>,
+    pub race_time: Option<RaceTime>,
+    pub wind: Option<HeatWind>,
+    pub evaluations: Vec<CompetitorEvaluated>,
+    pub result: Option<HeatResult>,
+}
+impl LiveFeedSnapshot {
+    pub fn apply(&mut self, event: &LiveFeedEvent) {
+        match event {
+            LiveFeedEvent::Snapshot(_) => (),
+            LiveFeedEvent::HeatStartList(list) => {
+                if self.start_list.as_ref().map(|l| l.id) != Some(list.id) {
+                    *self = Self::default();
+                }
+                self.start_list = Some(list.clone());
+            }
+            LiveFeedEvent::HeatStart(start) => {
+                if self.start_list.as_ref().map(|l| l.id) != Some(start.id) {
+                    // start of a heat we have no start list of
+                    *self = Self::default();
+                }
+                self.start = Some(start.clone());
+            }
+            LiveFeedEvent::TimeRunning(rt)
+            | LiveFeedEvent::TimeHeld(rt)
+            | LiveFeedEvent::TimeEnd(rt) => {
+                self.race_time = Some(rt.clone());
+            }
+            LiveFeedEvent::TimeReset => {
+                self.race_time = None;
+            }
+            LiveFeedEvent::Wind(wind) => {
+                self.wind = Some(wind.clone());
+            }
+            LiveFeedEvent::CompetitorEvaluated(evaluated) => {
+                // re-evaluations replace the previous one of the same competitor
+                self.evaluations.retain(|e| {
+                    e.competitor_result.competitor.bib != evaluated.competitor_result.competitor.bib
+                });
+                self.evaluations.push(evaluated.clone());
+            }
+            LiveFeedEvent::HeatResult(result) => {
+                self.result = Some(result.clone());
+            }
+        }
+    }
+}
@@ -0,0 +1,397 @@
+//! FinishLynx exchanges its schedule and results as comma separated text files:
+//! - `lynx.ppl`: one line per athlete `ID,Last Name,First Name,Affiliation`
+//! - `lynx.evt`: a heat line `Event,Round,Heat,Event Name` followed by its entries `,ID,Lane,Last Name,First Name,Affiliation`
+//! - `<event>-<round>-<heat>.lif`: a header `Event,Round,Heat,Event Name,Wind,Wind Unit,...,Start Time,...`
+//!   followed by one line per competitor `Place,ID,Lane,Last Name,First Name,Affiliation,Time,...`
+//!
+//! Lines starting with `;` are comments. The files we export start with [`LYNX_EXPORT_MARKER`],
+//! and the events file lists the heat ids behind the lynx numbers as `;heat,Event,Round,Heat,Id`
+
+#[cfg(test)]
+mod parsing_tests;
+
+use crate::helpers::uuids_from_seed;
+use crate::server::camera_program_types::{
+    CompetitorEvaluated, DisqualificationReason, HeatCompetitor, HeatCompetitorResult, HeatResult,
+    HeatStartList, Meet,
+};
+use crate::times::{DayTime, RaceTime, RaceWind};
+use chrono::Local;
+use std::collections::HashMap;
+use std::time::Duration;
+use uuid::Uuid;
+
+const LYNX_APPLICATION: &str = "FinishLynx";
+
+pub const LYNX_PEOPLE_FILE: &str = "lynx.ppl";
+pub const LYNX_EVENTS_FILE: &str = "lynx.evt";
+pub const LYNX_RESULT_EXTENSION: &str = "lif";
+pub const LYNX_EXPORT_MARKER: &str = "; exported by jta-dwa";
+
+/// Lynx event, round and heat number -> id of the heat in our database
+pub type LynxHeatIds = HashMap<(u32, u32, u32), Uuid>;
+
+/// Athlete data from a `lynx.ppl`, used to complete sparse `lynx.evt` entries
+#[derive(Debug, Clone)]
+pub struct LynxPerson {
+    pub last_name: String,
+    pub first_name: String,
+    pub club: String,
+}
+
+/// Lynx identifies a heat by event, round and heat number, we need a stable uuid for it.
+/// Heats we exported keep their own id
+fn lynx_heat_id(event: u32, round: u32, heat: u32, known: &LynxHeatIds) -> Uuid {
+    match known.get(&(event, round, heat)) {
+        Some(id) => *id,
+        None => uuids_from_seed(&format!("lynx_heat_{}_{}_{}", event, round, heat), 1)[0],
+    }
+}
+
+pub fn is_own_lynx_export(content: &str) -> bool {
+    content.starts_with(LYNX_EXPORT_MARKER)
+}
+
+/// The heat ids listed in an events file we exported
+pub fn parse_lynx_heat_ids(content: &str) -> LynxHeatIds {
+    content
+        .lines()
+        .filter_map(|line| line.strip_prefix(";heat,"))
+        .map(split_line)
+        .filter_map(|fields| {
+            let event = field(&fields, 0).parse().ok()?;
+            let round = field(&fields, 1).parse().ok()?;
+            let heat = field(&fields, 2).parse().ok()?;
+            let id = Uuid::parse_str(field(&fields, 3)).ok()?;
+            Some(((event, round, heat), id))
+        })
+        .collect()
+}
+
+fn lynx_evaluation_id(heat_id: Uuid, bib: u32) -> Uuid {
+    uuids_from_seed(&format!("lynx_evaluation_{}_{}", heat_id, bib), 1)[0]
+}
+
+/// "100m Hurdles" -> 100
+pub(super) fn distance_from_event_name(name: &str) -> u32 {
+    let bytes = name.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i].is_ascii_digit() {
+            let start = i;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            if name[i..].trim_start().starts_with('m') {
+                return name[start..i].parse().unwrap_or(0);
+            }
+        } else {
+            i += 1;
+        }
+    }
+    0
+}
+
+/// Lynx quotes fields that contain commas
+fn split_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+
+    for c in line.trim_end_matches(['\r', '\n']).chars() {
+        match c {
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut current)),
+            other => current.push(other),
+        }
+    }
+    fields.push(current);
+
+    fields.into_iter().map(|f| f.trim().to_string()).collect()
+}
+
+fn field(fields: &[String], index: usize) -> &str {
+    fields.get(index).map(|f| f.as_str()).unwrap_or("")
+}
+
+fn parse_number(fields: &[String], index: usize, what: &str) -> Result<u32, String> {
+    field(fields, index)
+        .parse()
+        .map_err(|_| format!("Lynx line has no valid {}: '{}'", what, fields.join(",")))
+}
+
+/// Lynx writes the wind with one decimal, rounded to tenths so "+1.3" does not become +1.2 in f32
+fn parse_wind(input: &str) -> Option<RaceWind> {
+    let value = input.trim_start_matches('+').parse::<f32>().ok()?;
+    let tenths = (value.abs() * 10.0).round() as u32;
+
+    Some(RaceWind {
+        back_wind: value >= 0.0,
+        whole_number_part: (tenths / 10) as u16,
+        fraction_part: (tenths % 10) as u8,
+    })
+}
+
+fn parse_competitor(
+    fields: &[String],
+    people: &HashMap<u32, LynxPerson>,
+) -> Option<HeatCompetitor> {
+    let bib: u32 = field(fields, 1).parse().ok()?;
+    let person = people.get(&bib);
+    let or_from_people = |index: usize, from_person: fn(&LynxPerson) -> &String| {
+        let value = field(fields, index);
+        if value.is_empty() {
+            person.map(|p| from_person(p).clone()).unwrap_or_default()
+        } else {
+            value.to_string()
+        }
+    };
+
+    Some(HeatCompetitor {
+        id: bib.to_string(),
+        lane: field(fields, 2).parse().unwrap_or(0),
+        bib,
+        class: String::new(),
+        last_name: or_from_people(3, |p| &p.last_name),
+        first_name: or_from_people(4, |p| &p.first_name),
+        nation: String::new(),
+        club: or_from_people(5, |p| &p.club),
+        gender: String::new(),
+        disqualified: None,
+    })
+}
+
+pub fn parse_lynx_people(content: &str) -> HashMap<u32, LynxPerson> {
+    content
+        .lines()
+        .map(split_line)
+        .filter_map(|fields| {
+            let bib: u32 = field(&fields, 0).parse().ok()?;
+            Some((
+                bib,
+                LynxPerson {
+                    last_name: field(&fields, 1).to_string(),
+                    first_name: field(&fields, 2).to_string(),
+                    club: field(&fields, 3).to_string(),
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Lynx does not know about scheduled start times, so all heats are scheduled for midnight
+pub fn parse_lynx_events(
+    content: &str,
+    people: &HashMap<u32, LynxPerson>,
+    heat_ids: &LynxHeatIds,
+) -> Result<Vec<HeatStartList>, String> {
+    let mut heats: Vec<HeatStartList> = Vec::new();
+
+    for line in content.lines() {
+        if line.trim().is_empty() || line.starts_with(';') {
+            continue;
+        }
+        let fields = split_line(line);
+
+        if field(&fields, 0).is_empty() {
+            // entry of the previous heat
+            let heat = heats.last_mut().ok_or(String::from(
+                "Lynx event file has an entry before the first heat",
+            ))?;
+            match parse_competitor(&fields, people) {
+                Some(competitor) => heat.competitors.push(competitor),
+                None => warn!("Skipping lynx entry without a valid ID: '{}'", line),
+            }
+            continue;
+        }
+
+        let event = parse_number(&fields, 0, "event number")?;
+        let round = parse_number(&fields, 1, "round number")?;
+        let heat = parse_number(&fields, 2, "heat number")?;
+        let name = field(&fields, 3).to_string();
+
+        heats.push(HeatStartList {
+            id: lynx_heat_id(event, round, heat, heat_ids),
+            nr: heat,
+            session_nr: round,
+            distance_meters: distance_from_event_name(&name),
+            scheduled_start_time: DayTime::from_hms_opt(0, 0, 0).unwrap(),
+            name,
+            competitors: Vec::new(),
+        });
+    }
+
+    Ok(heats)
+}
+
+/// The place column holds the rank or a status like "DNF"
+fn place_to_rank(place: &str) -> Result<u32, DisqualificationReason> {
+    match place.parse::<u32>() {
+        Ok(rank) if rank > 0 => Ok(rank),
+        _ => Err(DisqualificationReason::parse_from_string(place)),
+    }
+}
+
+/// Returns the heat result and one evaluation for every competitor with a time
+pub fn parse_lynx_result(
+    content: &str,
+    heat_ids: &LynxHeatIds,
+) -> Result<(HeatResult, Vec<CompetitorEvaluated>), String> {
+    let mut lines = content
+        .lines()
+        .filter(|l| !l.trim().is_empty() && !l.starts_with(';'));
+    let header = split_line(
+        lines
+            .next()
+            .ok_or(String::from("Lynx result file is empty"))?,
+    );
+
+    let event = parse_number(&header, 0, "event number")?;
+    let round = parse_number(&header, 1, "round number")?;
+    let heat = parse_number(&header, 2, "heat number")?;
+    let name = field(&header, 3).to_string();
+    let wind = if field(&header, 5).is_empty() || field(&header, 5) == "m/s" {
+        parse_wind(field(&header, 4))
+    } else {
+        warn!(
+            "Can only parse lynx wind in m/s, not '{}'",
+            field(&header, 5)
+        );
+        None
+    };
+    // the position of the start time moved between lynx versions, it is the first time of day after the wind
+    let start_time = header
+        .iter()
+        .skip(6)
+        .filter(|f| f.matches(':').count() == 2)
+        .find_map(|f| DayTime::parse_from_string(f).ok())
+        .ok_or(format!("Lynx result of '{}' has no start time", name))?;
+
+    let id = lynx_heat_id(event, round, heat, heat_ids);
+    let distance_meters = distance_from_event_name(&name);
+    let generated = Local::now().naive_local();
+
+    let mut competitors_evaluated = Vec::new();
+    let mut competitors_left_to_evaluate = Vec::new();
+    let mut evaluations = Vec::new();
+
+    for line in lines {
+        let fields = split_line(line);
+        let Some(mut competitor) = parse_competitor(&fields, &HashMap::new()) else {
+            warn!("Skipping lynx result without a valid ID: '{}'", line);
+            continue;
+        };
+
+        let rank = place_to_rank(field(&fields, 0));
+        let runtime = RaceTime::parse_from_string(field(&fields, 6));
+
+        match (rank, runtime) {
+            (Ok(rank), Ok(runtime)) => {
+                let finish_time = start_time
+                    .add_duration(Duration::from_micros(runtime.into_ten_thousands() * 100));
+                let result = HeatCompetitorResult {
+                    competitor,
+                    distance: distance_meters,
+                    rank,
+                    runtime: runtime.clone(),
+                    runtime_full_precision: runtime,
+                    finish_time,
+                };
+                evaluations.push(CompetitorEvaluated {
+                    application: String::from(LYNX_APPLICATION),
+                    version: String::new(),
+                    generated,
+                    id: lynx_evaluation_id(id, result.competitor.bib),
+                    competitor_result: result.clone(),
+                });
+                competitors_evaluated.push(result);
+            }
+            (Err(reason), _) => {
+                competitor.disqualified = Some(reason);
+                competitors_left_to_evaluate.push(competitor);
+            }
+            // placed, but not timed yet
+            (Ok(_), Err(_)) => competitors_left_to_evaluate.push(competitor),
+        }
+    }
+
+    Ok((
+        HeatResult {
+            id,
+            name,
+            distance_meters,
+            start_time,
+            wind,
+            competitors_evaluated,
+            competitors_left_to_evaluate,
+        },
+        evaluations,
+    ))
+}
+
+fn lynx_field(input: &str) -> String {
+    if input.contains(',') {
+        format!("\"{}\"", input.replace('"', ""))
+    } else {
+        input.to_string()
+    }
+}
+
+/// Numbers events in meet order, every heat of ours is round 1
+pub fn generate_lynx_events(meet: &Meet) -> String {
+    let mut out = format!("{}\r\n", LYNX_EXPORT_MARKER);
+
+    let events = meet.sessions.iter().flat_map(|s| s.events.iter());
+    for (event_index, event) in events.enumerate() {
+        for (heat_index, heat) in event.heats.iter().enumerate() {
+            out.push_str(&format!(
+                ";heat,{},1,{},{}\r\n",
+                event_index + 1,
+                heat_index + 1,
+                heat.id
+            ));
+            out.push_str(&format!(
+                "{},1,{},{}\r\n",
+                event_index + 1,
+                heat_index + 1,
+                lynx_field(&heat.name)
+            ));
+            for competitor in &heat.competitors {
+                out.push_str(&format!(
+                    ",{},{},{},{},{}\r\n",
+                    competitor.bib,
+                    competitor.lane,
+                    lynx_field(&competitor.last_name),
+                    lynx_field(&competitor.first_name),
+                    lynx_field(&competitor.club)
+                ));
+            }
+        }
+    }
+
+    out
+}
+
+pub fn generate_lynx_people(meet: &Meet) -> String {
+    let mut competitors: Vec<&HeatCompetitor> = meet
+        .sessions
+        .iter()
+        .flat_map(|s| s.events.iter())
+        .flat_map(|e| e.heats.iter())
+        .flat_map(|h| h.competitors.iter())
+        .collect();
+    competitors.sort_by_key(|c| c.bib);
+    competitors.dedup_by_key(|c| c.bib);
+
+    let mut out = format!("{}\r\n", LYNX_EXPORT_MARKER);
+    for c in competitors {
+        out.push_str(&format!(
+            "{},{},{},{}\r\n",
+            c.bib,
+            lynx_field(&c.last_name),
+            lynx_field(&c.first_name),
+            lynx_field(&c.club)
+        ));
+    }
+    out
+}
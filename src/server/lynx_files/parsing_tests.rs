@@ -0,0 +1,230 @@
+//! Parsing of the lynx exchange files (`tests/lynx_files/`) and the round trip of our own exports
+
+use super::{
+    generate_lynx_events, generate_lynx_people, is_own_lynx_export, lynx_heat_id,
+    parse_lynx_events, parse_lynx_heat_ids, parse_lynx_people, parse_lynx_result, place_to_rank,
+    split_line, LynxHeatIds,
+};
+use crate::server::camera_program_types::{
+    DisqualificationReason, DistanceType, Event, Heat, HeatCompetitor, Meet, Session,
+};
+use crate::times::DayTime;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+fn fixture(name: &str) -> String {
+    let path = format!("{}/tests/lynx_files/{}", env!("CARGO_MANIFEST_DIR"), name);
+    std::fs::read_to_string(&path).expect("missing lynx fixture")
+}
+
+fn competitor(bib: u32, lane: u32, last_name: &str, club: &str) -> HeatCompetitor {
+    HeatCompetitor {
+        id: bib.to_string(),
+        lane,
+        bib,
+        class: String::from("W"),
+        last_name: String::from(last_name),
+        first_name: String::from("Eva"),
+        nation: String::new(),
+        club: String::from(club),
+        gender: String::from("F"),
+        disqualified: None,
+    }
+}
+
+fn heat(name: &str, id: Uuid, competitors: Vec<HeatCompetitor>) -> Heat {
+    Heat {
+        name: String::from(name),
+        id,
+        distance: 100,
+        scheduled_start_time: DayTime::from_hms_opt(10, 0, 0).unwrap(),
+        distance_type: DistanceType::Normal,
+        competitors,
+    }
+}
+
+#[test]
+fn split_line_keeps_quoted_commas() {
+    assert_eq!(
+        split_line("1, 12 ,\"LG Nord, Team A\",,x\r\n"),
+        vec!["1", "12", "LG Nord, Team A", "", "x"]
+    );
+    assert_eq!(split_line(""), vec![""]);
+}
+
+#[test]
+fn place_is_rank_or_status() {
+    assert_eq!(place_to_rank("3").ok(), Some(3));
+    assert!(matches!(
+        place_to_rank("DNF"),
+        Err(DisqualificationReason::DidNotFinish)
+    ));
+    assert!(matches!(
+        place_to_rank("DQ"),
+        Err(DisqualificationReason::Disqualified)
+    ));
+    assert!(matches!(
+        place_to_rank("DNS"),
+        Err(DisqualificationReason::DidNotStart)
+    ));
+    // lynx writes 0 for unplaced competitors
+    assert!(matches!(
+        place_to_rank("0"),
+        Err(DisqualificationReason::Other(_))
+    ));
+}
+
+#[test]
+fn people() {
+    let people = parse_lynx_people(&fixture("lynx.ppl"));
+
+    assert_eq!(people.len(), 3);
+    assert_eq!(people[&12].last_name, "Müller");
+    assert_eq!(people[&12].club, "LG Nord, Team A");
+    assert_eq!(people[&21].club, "");
+}
+
+#[test]
+fn events() {
+    let people = parse_lynx_people(&fixture("lynx.ppl"));
+    let heats = parse_lynx_events(&fixture("lynx.evt"), &people, &LynxHeatIds::new())
+        .expect("events do not parse");
+
+    assert_eq!(heats.len(), 3);
+    assert_eq!(heats[0].name, "100m Women");
+    assert_eq!(heats[0].distance_meters, 100);
+    assert_eq!(heats[0].nr, 1);
+    assert_eq!(heats[1].nr, 2);
+    assert_eq!(heats[2].name, "400m Hurdles, Women");
+    assert_eq!(heats[2].distance_meters, 400);
+    assert_ne!(heats[0].id, heats[1].id);
+
+    // sparse entries are completed from the people file
+    let first = &heats[0].competitors[0];
+    assert_eq!((first.bib, first.lane), (12, 3));
+    assert_eq!(first.last_name, "Müller");
+    assert_eq!(first.club, "LG Nord, Team A");
+    // entries of the events file win over the people file
+    assert_eq!(heats[0].competitors[1].club, "TV Süd, Abt. Leichtathletik");
+    // not in the people file
+    assert_eq!(heats[2].competitors[0].last_name, "Fischer");
+}
+
+#[test]
+fn entry_before_first_heat_is_an_error() {
+    assert!(parse_lynx_events(",12,3,A,B,C", &Default::default(), &LynxHeatIds::new()).is_err());
+}
+
+#[test]
+fn result() {
+    let (result, evaluations) = parse_lynx_result(&fixture("001-1-01.lif"), &LynxHeatIds::new())
+        .expect("lif does not parse");
+
+    assert_eq!(result.id, lynx_heat_id(1, 1, 1, &LynxHeatIds::new()));
+    assert_eq!(result.distance_meters, 100);
+    assert_eq!(result.wind.as_ref().map(|w| w.to_tenths()), Some(13));
+    assert_eq!(
+        result.start_time,
+        DayTime::parse_from_string("14:05:30.1250").unwrap()
+    );
+
+    assert_eq!(result.competitors_evaluated.len(), 2);
+    assert_eq!(evaluations.len(), 2);
+    let winner = &result.competitors_evaluated[0];
+    assert_eq!(winner.rank, 1);
+    assert_eq!(winner.competitor.bib, 12);
+    assert_eq!(winner.competitor.club, "LG Nord, Team A");
+    assert_eq!(winner.runtime.into_ten_thousands(), 123400);
+    assert_eq!(
+        winner.finish_time,
+        DayTime::parse_from_string("14:05:42.4650").unwrap()
+    );
+
+    // the comment line is no competitor, a status in the place column disqualifies even with a time
+    assert_eq!(result.competitors_left_to_evaluate.len(), 2);
+    let dnf = &result.competitors_left_to_evaluate[0];
+    assert_eq!(dnf.bib, 21);
+    assert!(matches!(
+        dnf.disqualified,
+        Some(DisqualificationReason::DidNotFinish)
+    ));
+    assert!(matches!(
+        result.competitors_left_to_evaluate[1].disqualified,
+        Some(DisqualificationReason::Disqualified)
+    ));
+}
+
+#[test]
+fn result_start_time_after_the_wind() {
+    // older lynx versions have fewer columns before the start time
+    let content = "3,2,1,200m Men,-0.4,m/s,09:30:00.00\r\n1,7,4,Meyer,Tom,,22.10\r\n";
+    let (result, _) = parse_lynx_result(content, &LynxHeatIds::new()).expect("lif does not parse");
+
+    assert_eq!(
+        result.start_time,
+        DayTime::parse_from_string("09:30:00.00").unwrap()
+    );
+    assert_eq!(result.wind.as_ref().map(|w| w.to_tenths()), Some(-4));
+}
+
+#[test]
+fn result_without_start_time_is_an_error() {
+    let content = "1,1,1,100m Women,+1.3,m/s\r\n1,12,3,Müller,Anna,,12.34\r\n";
+    assert!(parse_lynx_result(content, &LynxHeatIds::new()).is_err());
+}
+
+#[test]
+fn exported_heat_ids_round_trip() {
+    let first_id = Uuid::new_v4();
+    let second_id = Uuid::new_v4();
+    let meet = Meet {
+        name: String::from("Club meet"),
+        id: Uuid::new_v4(),
+        city: String::from("Berlin"),
+        sessions: vec![Session {
+            location: String::from("Stadium"),
+            date: NaiveDate::from_ymd_opt(2026, 6, 1).unwrap(),
+            events: vec![Event {
+                name: String::from("100m"),
+                id: Uuid::new_v4(),
+                distance: 100,
+                distance_type: DistanceType::Normal,
+                scheduled_start_time: DayTime::from_hms_opt(10, 0, 0).unwrap(),
+                heats: vec![
+                    heat(
+                        "100m Women, Heat 1",
+                        first_id,
+                        vec![competitor(12, 3, "Müller", "LG Nord, Team A")],
+                    ),
+                    heat(
+                        "100m Women, Heat 2",
+                        second_id,
+                        vec![competitor(15, 4, "Schmidt", "TV Süd")],
+                    ),
+                ],
+            }],
+        }],
+    };
+
+    let events = generate_lynx_events(&meet);
+    let people = generate_lynx_people(&meet);
+    assert!(is_own_lynx_export(&events));
+    assert!(is_own_lynx_export(&people));
+
+    let heat_ids = parse_lynx_heat_ids(&events);
+    assert_eq!(heat_ids.get(&(1, 1, 1)), Some(&first_id));
+    assert_eq!(heat_ids.get(&(1, 1, 2)), Some(&second_id));
+
+    let heats = parse_lynx_events(&events, &parse_lynx_people(&people), &heat_ids)
+        .expect("own export does not parse");
+    assert_eq!(heats.len(), 2);
+    assert_eq!(heats[0].id, first_id);
+    assert_eq!(heats[0].name, "100m Women, Heat 1");
+    assert_eq!(heats[0].competitors[0].club, "LG Nord, Team A");
+    assert_eq!(heats[1].id, second_id);
+
+    // a result of the heat lands on the same heat
+    let result = "1,1,2,100m Women,,,12:00:00.00\r\n1,15,4,Schmidt,Eva,TV Süd,12.50\r\n";
+    let (result, _) = parse_lynx_result(result, &heat_ids).expect("lif does not parse");
+    assert_eq!(result.id, second_id);
+}
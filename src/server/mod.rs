@@ -1,7 +1,13 @@
 pub mod bib_detection;
 mod camera_program_datatypes;
 pub mod comm_channel;
+pub mod display_clients;
+pub mod field_events;
+pub mod live_feed;
+mod lynx_files;
 mod parts;
+pub mod session_recording;
+pub mod timing_program_types;
 mod xml_serial;
 
 pub use parts::server::run_server;
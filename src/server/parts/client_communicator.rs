@@ -1,14 +1,14 @@
 use crate::args::Args;
 use crate::interface::ServerInternalMessageFromClientToServer::{
-    MakeVersionRequestToAllClients, SetMainDisplayState,
+    MakeVersionRequest, SetDisplayState,
 };
 use crate::interface::{
     MessageFromClientToServer, MessageFromServerToClient, ServerStateMachineServerStateReader,
 };
 use crate::server::comm_channel::InstructionCommunicationChannel;
+use crate::server::display_clients::DisplayClientConfig;
 use futures::prelude::*;
 use std::io;
-use std::net::SocketAddr;
 use std::sync::{
     atomic::{AtomicBool, Ordering},
     Arc,
@@ -25,14 +25,10 @@ pub async fn client_communicator(
     state_reader: ServerStateMachineServerStateReader,
     comm_channel: InstructionCommunicationChannel,
     shutdown_marker: Arc<AtomicBool>,
-    client_addr: Option<SocketAddr>,
+    client: DisplayClientConfig,
 ) -> io::Result<()> {
-    let client_addr = if let Some(client_addr) = client_addr {
-        client_addr
-    } else {
-        // never listen to the display client -> we can just die
-        return Ok(());
-    };
+    let client_addr = client.address;
+    let client_name = client.name;
 
     loop {
         if shutdown_marker.load(Ordering::SeqCst) {
@@ -44,9 +40,10 @@ pub async fn client_communicator(
             time::sleep(Duration::from_millis(1000)).await;
             continue;
         }
-        match comm_channel.take_in_command_from_client(MessageFromClientToServer::ServerInternal(
-            SetMainDisplayState(false),
-        )) {
+        match comm_channel.take_in_command_from_client(
+            &client_name,
+            MessageFromClientToServer::ServerInternal(SetDisplayState(false)),
+        ) {
             Ok(()) => (),
             Err(e) => {
                 error!(
@@ -64,11 +61,12 @@ pub async fn client_communicator(
         .await
         {
             Ok(Ok(client_stream)) => {
-                info!("Connected to client at {}", client_addr);
+                info!("Connected to client '{}' at {}", client_name, client_addr);
 
                 // on connection first request version to initiate communication
                 match comm_channel.take_in_command_from_client(
-                    MessageFromClientToServer::ServerInternal(SetMainDisplayState(true)),
+                    &client_name,
+                    MessageFromClientToServer::ServerInternal(SetDisplayState(true)),
                 ) {
                     Ok(()) => (),
                     Err(e) => {
@@ -77,12 +75,13 @@ pub async fn client_communicator(
                     }
                 };
                 match comm_channel.take_in_command_from_client(
-                    MessageFromClientToServer::ServerInternal(MakeVersionRequestToAllClients),
+                    &client_name,
+                    MessageFromClientToServer::ServerInternal(MakeVersionRequest),
                 ) {
                     Ok(()) => {
                         debug!(
-                            "Scheduled server to request version from client(s) {}",
-                            client_addr
+                            "Scheduled server to request version from client '{}' at {}",
+                            client_name, client_addr
                         );
                     }
                     Err(e) => {
@@ -114,6 +113,7 @@ pub async fn client_communicator(
 
                 let shutdown_marker_read = shutdown_marker.clone();
                 let comm_channel_client_inbound_read = comm_channel.clone();
+                let client_name_read = client_name.clone();
 
                 let read_handler = async move {
                     loop {
@@ -137,7 +137,7 @@ pub async fn client_communicator(
                             Ok(Some(Ok(mes))) => {
                                 // message from server
                                 match comm_channel_client_inbound_read
-                                    .take_in_command_from_client(mes)
+                                    .take_in_command_from_client(&client_name_read, mes)
                                 {
                                     Ok(()) => (),
                                     Err(e) => {
@@ -153,6 +153,7 @@ pub async fn client_communicator(
 
                 let shutdown_marker_write = shutdown_marker.clone();
                 let mut comm_channel_client_outbound_write = comm_channel.client_receiver();
+                let client_name_write = client_name.clone();
 
                 let write_handler = async move {
                     loop {
@@ -170,13 +171,20 @@ pub async fn client_communicator(
                                 continue;
                             }
                             Ok(Err(e)) => return Err(e.to_string()),
-                            Ok(Ok(msg)) => match serializer.send(msg).await {
-                                Ok(()) => {
-                                    // trace!("Communication to client was sent out"); // during timing this is even too much for tracing
+                            Ok(Ok(msg)) => {
+                                if !msg.targets.contains(&client_name_write) {
+                                    // addressed to other display clients
                                     continue;
                                 }
-                                Err(e) => return Err(e.to_string()),
-                            },
+
+                                match serializer.send(msg.message).await {
+                                    Ok(()) => {
+                                        // trace!("Communication to client was sent out"); // during timing this is even too much for tracing
+                                        continue;
+                                    }
+                                    Err(e) => return Err(e.to_string()),
+                                }
+                            }
                         }
                     }
 
@@ -186,9 +194,10 @@ pub async fn client_communicator(
                 match tokio::try_join!(read_handler, write_handler) {
                     Ok(_) => (),
                     Err(e) => {
-                        error!("Client connection gone away: {}", e);
+                        error!("Client '{}' connection gone away: {}", client_name, e);
                         match comm_channel.take_in_command_from_client(
-                            MessageFromClientToServer::ServerInternal(SetMainDisplayState(false)),
+                            &client_name,
+                            MessageFromClientToServer::ServerInternal(SetDisplayState(false)),
                         ) {
                             Ok(()) => (),
                             Err(e) => {
@@ -201,9 +210,10 @@ pub async fn client_communicator(
                 }
             }
             Ok(Err(e)) => {
-                error!("Client exchange error: {}", e);
+                error!("Client '{}' exchange error: {}", client_name, e);
                 match comm_channel.take_in_command_from_client(
-                    MessageFromClientToServer::ServerInternal(SetMainDisplayState(false)),
+                    &client_name,
+                    MessageFromClientToServer::ServerInternal(SetDisplayState(false)),
                 ) {
                     Ok(()) => (),
                     Err(e) => {
@@ -219,7 +229,8 @@ pub async fn client_communicator(
                 // expected on timeout, just loop
                 trace!("No TCP connection to client could be established within timeout interval");
                 match comm_channel.take_in_command_from_client(
-                    MessageFromClientToServer::ServerInternal(SetMainDisplayState(false)),
+                    &client_name,
+                    MessageFromClientToServer::ServerInternal(SetDisplayState(false)),
                 ) {
                     Ok(()) => (),
                     Err(e) => {
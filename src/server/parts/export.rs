@@ -1,3 +1,6 @@
+#[cfg(test)]
+mod relay_tests;
+
 use crate::{
     args::Args,
     database::{
@@ -7,16 +10,33 @@ use crate::{
     file::{create_file_if_not_there_and_write, make_sure_folder_exists},
     helpers::uuids_from_seed,
     server::camera_program_types::{
-        Athlete, AthleteWithMetadata, DistanceType, Event, Gender, Heat, HeatAssignment,
-        HeatCompetitor, HeatStartList, Meet, Session,
+        Athlete, AthleteWithMetadata, CompetitionEvent, DistanceType, Event, Gender, Heat,
+        HeatAssignment, HeatCompetitor, HeatStartList, Meet, RelayTeam, Session,
+    },
+    server::lynx_files::{
+        generate_lynx_events, generate_lynx_people, LYNX_EVENTS_FILE, LYNX_PEOPLE_FILE,
     },
     times::DayTime,
 };
 use chrono::Datelike;
 use chrono::NaiveDate;
 use std::{path::Path, time::Duration};
+use uuid::Uuid;
 
 pub fn write_to_xml_output_file(args: &Args, file_name: &str, data: Meet) {
+    let data = match data.as_xml_serealized_string() {
+        Ok(d) => d,
+        Err(e) => {
+            error!("Could not convert to XML string: {}", e);
+            return;
+        }
+    };
+
+    write_to_output_file(args, file_name, &data);
+}
+
+/// Writes into the configured export folder
+pub fn write_to_output_file<T: AsRef<[u8]>>(args: &Args, file_name: &str, data: T) {
     let path_string = match &args.export_folder_path {
         Some(a) => a,
         None => {
@@ -36,18 +56,41 @@ pub fn write_to_xml_output_file(args: &Args, file_name: &str, data: Meet) {
     };
 
     let file_path = folder.join(file_name);
-    let data = match data.as_xml_serealized_string() {
-        Ok(d) => d,
-        Err(e) => {
-            error!("Could not convert to XML string: {}", e);
+
+    match create_file_if_not_there_and_write(&file_path, data) {
+        Ok(()) => debug!("Export output written to file"),
+        Err(e) => error!("Could not write the output to the file: {}", e),
+    };
+}
+
+/// FinishLynx reads its start lists from the same folder it writes the results to.
+/// The lynx folder watcher recognizes these files as ours and does not import them again
+pub fn write_lynx_schedule_files(args: &Args, meet: &Meet) {
+    let folder = match &args.lynx_folder_path {
+        Some(folder) => Path::new(folder),
+        None => {
+            debug!("No lynx folder configured, not writing lynx start lists");
             return;
         }
     };
 
-    match create_file_if_not_there_and_write(&file_path, &data) {
-        Ok(()) => debug!("Export output written to file"),
-        Err(e) => error!("Could not write the output to the file: {}", e),
+    match make_sure_folder_exists(folder) {
+        Ok(()) => (),
+        Err(e) => {
+            error!("Could not create or access lynx folder: {}", e);
+            return;
+        }
     };
+
+    for (file_name, data) in [
+        (LYNX_PEOPLE_FILE, generate_lynx_people(meet)),
+        (LYNX_EVENTS_FILE, generate_lynx_events(meet)),
+    ] {
+        match create_file_if_not_there_and_write(&folder.join(file_name), data) {
+            Ok(()) => debug!("Lynx file {} written", file_name),
+            Err(e) => error!("Could not write the lynx file {}: {}", file_name, e),
+        };
+    }
 }
 
 fn rounded_year(date: NaiveDate) -> i32 {
@@ -57,57 +100,161 @@ fn rounded_year(date: NaiveDate) -> i32 {
     }
 }
 
-fn generate_heats_spk(
-    event_key: String,
+fn athlete_competitor(lane: u32, athlete: &Athlete) -> HeatCompetitor {
+    HeatCompetitor {
+        lane,
+        bib: athlete.bib,
+        class: athlete.gender.to_string(),
+        gender: athlete.gender.to_string(),
+        club: athlete.club.clone(),
+        first_name: athlete.first_name.clone(),
+        last_name: athlete.last_name.clone(),
+        id: athlete.id.to_string(),
+        nation: athlete.nation.clone(),
+        disqualified: None,
+    }
+}
+
+/// A relay team runs under the bib of its first member, the member names go into the first name
+fn relay_team_competitor(
+    heat_id: &Uuid,
+    lane: u32,
+    team: &RelayTeam,
+    members: &[&Athlete],
+) -> Option<HeatCompetitor> {
+    let first = members.first()?;
+
+    let shared = |value: fn(&Athlete) -> String, fallback: &str| {
+        let first_value = value(first);
+        match members.iter().all(|m| value(m) == first_value) {
+            true => first_value,
+            false => String::from(fallback),
+        }
+    };
+    let gender = shared(|a| a.gender.to_string(), &Gender::Mixed.to_string());
+
+    Some(HeatCompetitor {
+        lane,
+        bib: first.bib,
+        class: gender.clone(),
+        gender,
+        club: shared(|a| a.club.clone(), &team.name),
+        first_name: members
+            .iter()
+            .map(|m| m.last_name.clone())
+            .collect::<Vec<String>>()
+            .join(" / "),
+        last_name: team.name.clone(),
+        id: uuids_from_seed(&format!("{}_relay_lane_{}", heat_id, lane), 1)[0].to_string(),
+        nation: shared(|a| a.nation.clone(), ""),
+        disqualified: None,
+    })
+}
+
+/// Heat assignments with their athletes, and everything the export had to leave out
+struct HeatSources {
+    athletes_meta: Vec<AthleteWithMetadata>,
+    all_heats: Vec<HeatAssignment>,
+    problems: Vec<String>,
+}
+impl HeatSources {
+    fn read(manager: &DatabaseManager) -> Self {
+        let athletes_meta = match get_all_athletes_meta_data(manager) {
+            Ok(e) => e,
+            Err(e) => {
+                error!(
+                    "Error while generating export - could not read from database: {}",
+                    e
+                );
+                Vec::new()
+            }
+        };
+        let all_heats = match get_all_heat_assignments(manager) {
+            Ok(e) => e,
+            Err(e) => {
+                error!(
+                    "Error while generating export - could not read from database: {}",
+                    e
+                );
+                Vec::new()
+            }
+        };
+
+        Self {
+            athletes_meta,
+            all_heats,
+            problems: Vec::new(),
+        }
+    }
+}
+
+/// Heats are all heat assignments with the given distance and heat_descriminator (index), ordered by creation
+fn generate_heats(
     distance: u32,
     index: u8,
+    distance_type: DistanceType,
+    name_prefix: &str,
     start: &mut CountingOrderedStartTime,
-    athletes_meta: &Vec<AthleteWithMetadata>,
-    all_heats: &Vec<HeatAssignment>,
+    sources: &mut HeatSources,
 ) -> Vec<Heat> {
-    let _ = event_key;
-
-    let mut relevant_heats: Vec<&HeatAssignment> = all_heats
+    let mut relevant_heats: Vec<&HeatAssignment> = sources
+        .all_heats
         .iter()
         .filter(|h| h.distance == distance && h.heat_descriminator == index)
         .collect();
     relevant_heats.sort_by(|h1, h2| h1.id.cmp(&h2.id));
 
+    let problems = &mut sources.problems;
+    let find_athlete = |id: &Uuid| {
+        sources
+            .athletes_meta
+            .iter()
+            .find(|a| a.athlete.id == *id)
+            .map(|a| &a.athlete)
+    };
+
     let mut heats = Vec::new();
 
     for (i, heat) in relevant_heats.into_iter().enumerate() {
-        let mut athletes_with_lanes: Vec<(&u32, &Athlete)> = heat
+        let name = format!("{}, Run {}", name_prefix, i + 1);
+
+        let mut competitors: Vec<HeatCompetitor> = heat
             .athlete_ids
             .iter()
-            .filter_map(
-                |(lane, id)| match athletes_meta.iter().find(|a| a.athlete.id == *id) {
-                    Some(a) => Some((lane, &a.athlete)),
-                    None => return None,
-                },
-            )
-            .collect();
-        athletes_with_lanes.sort_by(|a, b| a.0.cmp(b.0));
-        let competitors: Vec<HeatCompetitor> = athletes_with_lanes
-            .into_iter()
-            .map(|(lane, athlete)| HeatCompetitor {
-                lane: lane.clone(),
-                bib: athlete.bib.clone(),
-                class: athlete.gender.to_string(),
-                gender: athlete.gender.to_string(),
-                club: athlete.club.clone(),
-                first_name: athlete.first_name.clone(),
-                last_name: athlete.last_name.clone(),
-                id: athlete.id.to_string(),
-                nation: athlete.nation.clone(),
-                disqualified: None,
-            })
+            .filter_map(|(lane, id)| find_athlete(id).map(|a| athlete_competitor(*lane, a)))
             .collect();
 
+        if !heat.relay_teams.is_empty() && !matches!(distance_type, DistanceType::Relay) {
+            problems.push(format!(
+                "{} is no relay event, its relay teams are not exported",
+                name
+            ));
+        } else {
+            for (lane, team) in &heat.relay_teams {
+                let members: Vec<&Athlete> =
+                    team.member_ids.iter().filter_map(find_athlete).collect();
+                if members.len() != team.member_ids.len() {
+                    problems.push(format!(
+                        "Relay team {} in {} has members that are no longer athletes",
+                        team.name, name
+                    ));
+                }
+                match relay_team_competitor(&heat.heat_id, *lane, team, &members) {
+                    Some(competitor) => competitors.push(competitor),
+                    None => problems.push(format!(
+                        "Relay team {} in {} has no members and is not exported",
+                        team.name, name
+                    )),
+                }
+            }
+        }
+        competitors.sort_by_key(|c| c.lane);
+
         heats.push(Heat {
             id: heat.heat_id.clone(),
             distance,
-            distance_type: DistanceType::Normal,
-            name: format!("SPK {}m-{}, Run {}", distance, index, i + 1),
+            distance_type: distance_type.clone(),
+            name,
             scheduled_start_time: start.get_next(),
             competitors,
         });
@@ -125,20 +272,9 @@ fn generate_heat_street_race(event_key: String, distance: u32, mut athletes: Vec
     athletes.sort_by(|a, b| a.bib.cmp(&b.bib));
 
     let competitors: Vec<HeatCompetitor> = athletes
-        .into_iter()
+        .iter()
         .enumerate()
-        .map(|(lane, athlete)| HeatCompetitor {
-            lane: (lane + 1) as u32,
-            bib: athlete.bib.clone(),
-            class: athlete.gender.to_string(),
-            gender: athlete.gender.to_string(),
-            club: athlete.club.clone(),
-            first_name: athlete.first_name.clone(),
-            last_name: athlete.last_name.clone(),
-            id: athlete.id.to_string(),
-            nation: athlete.nation.clone(),
-            disqualified: None,
-        })
+        .map(|(lane, athlete)| athlete_competitor((lane + 1) as u32, athlete))
         .collect();
 
     Heat {
@@ -153,30 +289,48 @@ fn generate_heat_street_race(event_key: String, distance: u32, mut athletes: Vec
 
 struct CountingOrderedStartTime {
     start: DayTime,
+    step: Duration,
     current_index: u32,
 }
 impl CountingOrderedStartTime {
-    pub fn new(init: DayTime) -> Self {
+    pub fn new(init: DayTime, step: Duration) -> Self {
         Self {
             current_index: 0,
             start: init,
+            step,
         }
     }
 
     pub fn get_next(&mut self) -> DayTime {
-        let res = self
-            .start
-            .add_duration(Duration::from_secs(self.current_index as u64 * 60));
+        let res = self.start.add_duration(self.step * self.current_index);
         self.current_index += 1;
         res
     }
 }
 
+pub fn meet_name(dbss: &DatabaseStaticState) -> String {
+    match dbss.mode {
+        ApplicationMode::SprinterKing => {
+            format!("Sprinter König {}", rounded_year(dbss.date.clone()))
+        }
+        ApplicationMode::StreetLongRun => {
+            format!("Lauf {}", dbss.date.to_string())
+        }
+        ApplicationMode::TrackCompetition => {
+            format!("Bahnveranstaltung {}", dbss.date.to_string())
+        }
+    }
+}
+
 fn generate_event_key(dbss: &DatabaseStaticState) -> String {
     format!("{}-{}", dbss.mode.to_string(), dbss.date.to_string())
 }
 
-pub fn generate_meet_data(dbss: &DatabaseStaticState, manager: &DatabaseManager) -> Meet {
+/// The meet together with everything that could not be exported, so the operator can fix it
+pub fn generate_meet_data(
+    dbss: &DatabaseStaticState,
+    manager: &DatabaseManager,
+) -> (Meet, Vec<String>) {
     let _ = Gender::Female;
     let _ = Gender::Mixed;
     let _ = Gender::Male;
@@ -184,30 +338,19 @@ pub fn generate_meet_data(dbss: &DatabaseStaticState, manager: &DatabaseManager)
     let event_key = generate_event_key(dbss);
 
     let mut events: Vec<Event> = Vec::new();
+    let mut own_sessions: Vec<Session> = Vec::new();
+    let mut sources = HeatSources::read(manager);
     match dbss.mode {
-        ApplicationMode::TrackCompetition => (), // the program does not generate meetxml for this case (currently)
+        ApplicationMode::TrackCompetition => {
+            // the track competition can span multiple days, so it brings its own sessions
+            own_sessions =
+                generate_track_competition_sessions(dbss, &event_key, manager, &mut sources);
+        }
         ApplicationMode::SprinterKing => {
-            let mut start = CountingOrderedStartTime::new(DayTime::from_hms_opt(10, 0, 0).unwrap());
-            let athletes_meta = match get_all_athletes_meta_data(manager) {
-                Ok(e) => e,
-                Err(e) => {
-                    error!(
-                        "Error while generating export - could not read from database: {}",
-                        e
-                    );
-                    Vec::new()
-                }
-            };
-            let all_heats = match get_all_heat_assignments(manager) {
-                Ok(e) => e,
-                Err(e) => {
-                    error!(
-                        "Error while generating export - could not read from database: {}",
-                        e
-                    );
-                    Vec::new()
-                }
-            };
+            let mut start = CountingOrderedStartTime::new(
+                DayTime::from_hms_opt(10, 0, 0).unwrap(),
+                Duration::from_secs(60),
+            );
 
             for distance in [15u32, 20, 30] {
                 let ids = uuids_from_seed(&format!("{}_event_id_{}", event_key, distance), 2);
@@ -220,13 +363,13 @@ pub fn generate_meet_data(dbss: &DatabaseStaticState, manager: &DatabaseManager)
                     id: id_a,
                     name: format!("SPK {}m Run 1", distance),
                     scheduled_start_time: start.get_next(),
-                    heats: generate_heats_spk(
-                        event_key.clone(),
+                    heats: generate_heats(
                         distance,
                         1,
+                        DistanceType::Normal,
+                        &format!("SPK {}m-1", distance),
                         &mut start,
-                        &athletes_meta,
-                        &all_heats,
+                        &mut sources,
                     ),
                 });
                 events.push(Event {
@@ -235,13 +378,13 @@ pub fn generate_meet_data(dbss: &DatabaseStaticState, manager: &DatabaseManager)
                     id: id_b,
                     name: format!("SPK {}m Run 2", distance),
                     scheduled_start_time: start.get_next(),
-                    heats: generate_heats_spk(
-                        event_key.clone(),
+                    heats: generate_heats(
                         distance,
                         2,
+                        DistanceType::Normal,
+                        &format!("SPK {}m-2", distance),
                         &mut start,
-                        &athletes_meta,
-                        &all_heats,
+                        &mut sources,
                     ),
                 });
             }
@@ -251,27 +394,145 @@ pub fn generate_meet_data(dbss: &DatabaseStaticState, manager: &DatabaseManager)
         }
     }
 
-    Meet {
-        name: match dbss.mode {
-            ApplicationMode::SprinterKing => {
-                format!("Sprinter König {}", rounded_year(dbss.date.clone()))
-            }
-            ApplicationMode::StreetLongRun => {
-                format!("Lauf {}", dbss.date.to_string())
-            }
-            ApplicationMode::TrackCompetition => {
-                format!("Bahnveranstaltung {}", dbss.date.to_string())
-            }
-        },
+    let meet = Meet {
+        name: meet_name(dbss),
         id: dbss.meet_id.clone(),
         city: dbss.meet_city.clone(),
-        sessions: [Session {
-            date: dbss.date.clone(),
-            location: dbss.meet_location.clone(),
-            events,
-        }]
-        .into(),
+        sessions: if own_sessions.is_empty() {
+            [Session {
+                date: dbss.date.clone(),
+                location: dbss.meet_location.clone(),
+                events,
+            }]
+            .into()
+        } else {
+            own_sessions
+        },
+    };
+
+    (meet, sources.problems)
+}
+
+const TRACK_COMPETITION_DEFAULT_MINUTES_BETWEEN_HEATS: u32 = 5;
+
+/// Every heat assignment that has no scheduled competition event gets a generated one on the meet date, so nothing gets lost in the export
+fn unscheduled_competition_events(
+    dbss: &DatabaseStaticState,
+    event_key: &str,
+    scheduled: &Vec<CompetitionEvent>,
+    all_heats: &Vec<HeatAssignment>,
+) -> Vec<CompetitionEvent> {
+    let mut unscheduled: Vec<CompetitionEvent> = Vec::new();
+
+    for heat in all_heats {
+        let is_known = scheduled.iter().chain(unscheduled.iter()).any(|e| {
+            e.distance == heat.distance && e.heat_descriminator == heat.heat_descriminator
+        });
+        if is_known {
+            continue;
+        }
+
+        let ids = uuids_from_seed(
+            &format!(
+                "{}_unscheduled_event_{}_{}",
+                event_key, heat.distance, heat.heat_descriminator
+            ),
+            1,
+        );
+        unscheduled.push(CompetitionEvent {
+            id: ids[0],
+            name: format!("{}m-{}", heat.distance, heat.heat_descriminator),
+            distance: heat.distance,
+            distance_type: DistanceType::Normal,
+            heat_descriminator: heat.heat_descriminator,
+            session_date: dbss.date.clone(),
+            scheduled_start_time: DayTime::from_hms_opt(10, 0, 0).unwrap(),
+            minutes_between_heats: TRACK_COMPETITION_DEFAULT_MINUTES_BETWEEN_HEATS,
+        });
+    }
+
+    unscheduled
+}
+
+fn generate_track_competition_sessions(
+    dbss: &DatabaseStaticState,
+    event_key: &str,
+    manager: &DatabaseManager,
+    sources: &mut HeatSources,
+) -> Vec<Session> {
+    let mut competition_events = match CompetitionEvent::get_all_from_database(manager) {
+        Ok(e) => e,
+        Err(e) => {
+            error!(
+                "Error while generating export - could not read from database: {}",
+                e
+            );
+            Vec::new()
+        }
+    };
+    let mut unscheduled =
+        unscheduled_competition_events(dbss, event_key, &competition_events, &sources.all_heats);
+    competition_events.append(&mut unscheduled);
+
+    competition_events.sort_by(|a, b| {
+        a.session_date.cmp(&b.session_date).then(
+            Duration::from(a.scheduled_start_time.clone())
+                .cmp(&Duration::from(b.scheduled_start_time.clone())),
+        )
+    });
+
+    // heats are assigned by distance and descriminator, a second event with both would export the same heats again
+    let mut exported_keys: Vec<(u32, u8)> = Vec::new();
+    competition_events.retain(|e| {
+        let key = (e.distance, e.heat_descriminator);
+        if exported_keys.contains(&key) {
+            sources.problems.push(format!(
+                "Competition event {} has the same distance and descriminator as an earlier one and is not exported",
+                e.name
+            ));
+            return false;
+        }
+        exported_keys.push(key);
+        true
+    });
+
+    let mut sessions: Vec<Session> = Vec::new();
+    for competition_event in competition_events {
+        let mut start = CountingOrderedStartTime::new(
+            competition_event.scheduled_start_time.clone(),
+            Duration::from_secs(competition_event.minutes_between_heats as u64 * 60),
+        );
+
+        let event = Event {
+            name: competition_event.name.clone(),
+            id: competition_event.id,
+            distance: competition_event.distance,
+            distance_type: competition_event.distance_type.clone(),
+            scheduled_start_time: competition_event.scheduled_start_time.clone(),
+            heats: generate_heats(
+                competition_event.distance,
+                competition_event.heat_descriminator,
+                competition_event.distance_type.clone(),
+                &competition_event.name,
+                &mut start,
+                sources,
+            ),
+        };
+
+        // events are sorted by date, so the matching session can only be the last one
+        match sessions.last_mut() {
+            Some(session) if session.date == competition_event.session_date => {
+                session.events.push(event)
+            }
+            _ => sessions.push(Session {
+                date: competition_event.session_date,
+                location: dbss.meet_location.clone(),
+                events: vec![event],
+            }),
+        }
     }
+
+    sessions
 }
 
 fn generate_street_long_run_event(event_key: String, manager: &DatabaseManager) -> Event {
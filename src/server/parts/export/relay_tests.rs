@@ -0,0 +1,121 @@
+//! Relay teams in heat assignments and their export as one competitor per lane
+
+use super::relay_team_competitor;
+use crate::server::camera_program_types::{Athlete, Gender, HeatAssignment, RelayTeam};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+fn athlete(bib: u32, last_name: &str, club: &str, gender: Gender) -> Athlete {
+    Athlete {
+        id: Uuid::new_v4(),
+        gender,
+        bib,
+        club: String::from(club),
+        first_name: String::from("Eva"),
+        last_name: String::from(last_name),
+        nation: String::from("GER"),
+        spk_guess: None,
+        street_run_rounds: None,
+        birth_date: None,
+    }
+}
+
+fn team(name: &str, members: &[&Athlete]) -> RelayTeam {
+    RelayTeam {
+        name: String::from(name),
+        member_ids: members.iter().map(|m| m.id).collect(),
+    }
+}
+
+fn assignment(
+    athlete_ids: HashMap<u32, Uuid>,
+    relay_teams: HashMap<u32, RelayTeam>,
+) -> HeatAssignment {
+    HeatAssignment {
+        id: 1,
+        heat_id: Uuid::new_v4(),
+        distance: 400,
+        heat_descriminator: 1,
+        athlete_ids,
+        relay_teams,
+    }
+}
+
+#[test]
+fn team_runs_under_the_first_bib() {
+    let first = athlete(12, "Müller", "LG Nord", Gender::Female);
+    let second = athlete(15, "Schmidt", "LG Nord", Gender::Female);
+    let heat_id = Uuid::new_v4();
+
+    let competitor = relay_team_competitor(
+        &heat_id,
+        3,
+        &team("LG Nord I", &[&first, &second]),
+        &[&first, &second],
+    )
+    .expect("team has members");
+
+    assert_eq!((competitor.lane, competitor.bib), (3, 12));
+    assert_eq!(competitor.last_name, "LG Nord I");
+    assert_eq!(competitor.first_name, "Müller / Schmidt");
+    assert_eq!(competitor.club, "LG Nord");
+    assert_eq!(competitor.gender, "F");
+    assert_eq!(competitor.nation, "GER");
+    // stable between exports, so lynx results find the team again
+    let again = relay_team_competitor(&heat_id, 3, &team("LG Nord I", &[]), &[&first]).unwrap();
+    assert_eq!(competitor.id, again.id);
+}
+
+#[test]
+fn mixed_team_falls_back_to_the_team() {
+    let first = athlete(12, "Müller", "LG Nord", Gender::Female);
+    let second = athlete(7, "Meyer", "TV Süd", Gender::Male);
+
+    let competitor = relay_team_competitor(
+        &Uuid::new_v4(),
+        1,
+        &team("Startgemeinschaft", &[&first, &second]),
+        &[&first, &second],
+    )
+    .unwrap();
+
+    assert_eq!(competitor.club, "Startgemeinschaft");
+    assert_eq!(competitor.gender, "X");
+}
+
+#[test]
+fn team_without_members_is_not_exported() {
+    assert!(relay_team_competitor(&Uuid::new_v4(), 1, &team("Empty", &[]), &[]).is_none());
+}
+
+#[test]
+fn assignment_validation() {
+    let a = athlete(12, "Müller", "LG Nord", Gender::Female);
+    let b = athlete(15, "Schmidt", "LG Nord", Gender::Female);
+
+    let valid = assignment(
+        HashMap::from([(1, a.id)]),
+        HashMap::from([(2, team("LG Nord I", &[&b]))]),
+    );
+    assert!(valid.validate().is_ok());
+    assert!(valid.contains_athlete(&a.id));
+    assert!(valid.contains_athlete(&b.id));
+
+    let same_lane = assignment(
+        HashMap::from([(1, a.id)]),
+        HashMap::from([(1, team("LG Nord I", &[&b]))]),
+    );
+    assert!(same_lane.validate().is_err());
+
+    let twice = assignment(
+        HashMap::from([(1, a.id)]),
+        HashMap::from([(2, team("LG Nord I", &[&a, &b]))]),
+    );
+    assert!(twice.validate().is_err());
+
+    let unnamed = assignment(HashMap::new(), HashMap::from([(2, team(" ", &[&b]))]));
+    assert!(unnamed.validate().is_err());
+
+    let empty = assignment(HashMap::new(), HashMap::from([(2, team("LG Nord I", &[]))]));
+    assert!(empty.validate().is_err());
+}
@@ -0,0 +1,193 @@
+use crate::args::Args;
+use crate::instructions::InstructionFromCameraProgram;
+use crate::interface::ServerStateMachineServerStateReader;
+use crate::server::comm_channel::InstructionCommunicationChannel;
+use crate::server::lynx_files::{
+    is_own_lynx_export, parse_lynx_events, parse_lynx_heat_ids, parse_lynx_people,
+    parse_lynx_result, LynxHeatIds, LynxPerson, LYNX_EVENTS_FILE, LYNX_PEOPLE_FILE,
+    LYNX_RESULT_EXTENSION,
+};
+use std::collections::HashMap;
+use std::io::{self, Error, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use std::time::{Duration, SystemTime};
+use tokio::time;
+
+/// Files that appeared or were modified since the last scan, sorted by name
+fn changed_files(
+    folder: &Path,
+    last_modified: &mut HashMap<PathBuf, SystemTime>,
+) -> Result<Vec<PathBuf>, String> {
+    let mut changed = Vec::new();
+
+    for entry in std::fs::read_dir(folder).map_err(|e| e.to_string())? {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+        let modified = entry
+            .metadata()
+            .and_then(|m| m.modified())
+            .map_err(|e| e.to_string())?;
+
+        if last_modified.get(&path) != Some(&modified) {
+            last_modified.insert(path.clone(), modified);
+            changed.push(path);
+        }
+    }
+
+    changed.sort();
+    Ok(changed)
+}
+
+fn is_file_named(path: &Path, name: &str) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.eq_ignore_ascii_case(name))
+}
+
+fn is_result_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case(LYNX_RESULT_EXTENSION))
+}
+
+/// Turns changed lynx files into the instructions the camera program would have sent.
+/// The schedule files we exported ourselves are not read back in, they only tell which heats the lynx numbers are
+fn instructions_from_changed_files(
+    folder: &Path,
+    changed: Vec<PathBuf>,
+    people: &mut HashMap<u32, LynxPerson>,
+    heat_ids: &mut LynxHeatIds,
+) -> Vec<InstructionFromCameraProgram> {
+    let mut instructions = Vec::new();
+
+    let people_changed = changed.iter().any(|p| is_file_named(p, LYNX_PEOPLE_FILE));
+    if people_changed {
+        match std::fs::read_to_string(folder.join(LYNX_PEOPLE_FILE)) {
+            Ok(content) if is_own_lynx_export(&content) => people.clear(),
+            Ok(content) => *people = parse_lynx_people(&content),
+            Err(e) => error!("Could not read {}: {}", LYNX_PEOPLE_FILE, e),
+        }
+    }
+
+    // names in the start lists can come from the people file, so they are re-read with it
+    if people_changed || changed.iter().any(|p| is_file_named(p, LYNX_EVENTS_FILE)) {
+        let events_path = folder.join(LYNX_EVENTS_FILE);
+        if events_path.is_file() {
+            match std::fs::read_to_string(&events_path) {
+                Ok(content) if is_own_lynx_export(&content) => {
+                    *heat_ids = parse_lynx_heat_ids(&content);
+                    debug!(
+                        "{} is our own export, mapping {} lynx heats to ours",
+                        LYNX_EVENTS_FILE,
+                        heat_ids.len()
+                    );
+                }
+                Ok(content) => {
+                    heat_ids.clear();
+                    match parse_lynx_events(&content, people, heat_ids) {
+                        Ok(heats) => instructions.extend(
+                            heats
+                                .into_iter()
+                                .map(InstructionFromCameraProgram::HeatStartList),
+                        ),
+                        Err(e) => error!("Could not parse {}: {}", LYNX_EVENTS_FILE, e),
+                    }
+                }
+                Err(e) => error!("Could not read {}: {}", LYNX_EVENTS_FILE, e),
+            }
+        }
+    }
+
+    for path in changed.iter().filter(|p| is_result_file(p)) {
+        match std::fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|content| parse_lynx_result(&content, heat_ids))
+        {
+            Ok((result, evaluations)) => {
+                instructions.extend(
+                    evaluations
+                        .into_iter()
+                        .map(InstructionFromCameraProgram::CompetitorEvaluated),
+                );
+                instructions.push(InstructionFromCameraProgram::HeatResult(result));
+            }
+            Err(e) => error!("Could not parse lynx result {}: {}", path.display(), e),
+        }
+    }
+
+    instructions
+}
+
+/// FinishLynx has no network protocol for us, it exchanges files in a folder
+pub async fn lynx_folder_watcher(
+    args: Args,
+    state_reader: ServerStateMachineServerStateReader,
+    comm_channel: InstructionCommunicationChannel,
+    shutdown_marker: Arc<AtomicBool>,
+    folder: PathBuf,
+) -> io::Result<()> {
+    let mut last_modified: HashMap<PathBuf, SystemTime> = HashMap::new();
+    let mut people: HashMap<u32, LynxPerson> = HashMap::new();
+    let mut heat_ids: LynxHeatIds = HashMap::new();
+    // the first scan finds what was there before we started, that is stored but not shown again
+    let mut backfilled = false;
+
+    loop {
+        if shutdown_marker.load(Ordering::SeqCst) {
+            info!(
+                "Shutdown requested, stopping to watch lynx folder {}",
+                folder.display()
+            );
+            break;
+        }
+        if !state_reader.external_connection_is_allowed().await {
+            warn!("Stopped external connection from forming for now");
+            time::sleep(Duration::from_millis(1000)).await;
+            continue;
+        }
+
+        match changed_files(&folder, &mut last_modified) {
+            Ok(changed) if !changed.is_empty() => {
+                let instructions =
+                    instructions_from_changed_files(&folder, changed, &mut people, &mut heat_ids);
+                for inst in instructions {
+                    let inst = if backfilled {
+                        inst
+                    } else {
+                        InstructionFromCameraProgram::Backfill(Box::new(inst))
+                    };
+                    // a whole schedule can be more than the internal buffer takes at once
+                    match comm_channel.send_command_from_camera_program(inst).await {
+                        Ok(()) => (),
+                        Err(e) => {
+                            return Err(Error::new(ErrorKind::Other, e)); // bad error, internal, can not recover by reading again
+                        }
+                    }
+                }
+                backfilled = true;
+            }
+            Ok(_) => {
+                trace!("No changes in the lynx folder");
+                backfilled = true;
+            }
+            Err(e) => {
+                error!("Could not read lynx folder {}: {}", folder.display(), e);
+                time::sleep(Duration::from_millis(1000)).await; // folder might be a network share that is not mounted yet
+            }
+        }
+
+        time::sleep(Duration::from_millis(
+            args.wait_ms_before_testing_for_shutdown,
+        ))
+        .await;
+    }
+
+    Ok(())
+}
@@ -3,17 +3,28 @@ mod client_communicator;
 mod database;
 mod export;
 mod intake_commands;
+mod lynx_folder_watcher;
+mod pdf;
+mod pdf_export;
+mod results_export;
+mod serial_scoreboard;
 pub mod server;
+mod session_replay;
 mod tcp_client_camera_program;
 mod tcp_forwarder_display_program;
 mod tcp_listener_bib_detection;
 mod tcp_listener_idcapture_server;
 mod tcp_listener_timing_program;
 mod tcp_listener_wind_server;
+mod timing_source;
+mod wind_report;
 
 pub mod audio_types {
     pub use super::audio::{AudioPlayer, Sound};
 }
 pub mod export_functions {
     pub use super::export::*;
+    pub use super::pdf_export::*;
+    pub use super::results_export::*;
+    pub use super::wind_report::*;
 }
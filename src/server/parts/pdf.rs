@@ -0,0 +1,454 @@
+use fontdue::{Font, FontSettings};
+use image::codecs::jpeg::JpegEncoder;
+use image::{ImageFormat, Rgb, RgbImage};
+use std::io::Write;
+
+const MM_TO_PT: f64 = 72.0 / 25.4;
+const FIRST_CHAR: u8 = 32;
+const BACKGROUND_JPEG_QUALITY: u8 = 92;
+
+// cp1252 codes, that are no latin-1 characters
+const WIN_ANSI_SPECIAL: [(u8, char); 27] = [
+    (0x80, '€'),
+    (0x82, '‚'),
+    (0x83, 'ƒ'),
+    (0x84, '„'),
+    (0x85, '…'),
+    (0x86, '†'),
+    (0x87, '‡'),
+    (0x88, 'ˆ'),
+    (0x89, '‰'),
+    (0x8A, 'Š'),
+    (0x8B, '‹'),
+    (0x8C, 'Œ'),
+    (0x8E, 'Ž'),
+    (0x91, '‘'),
+    (0x92, '’'),
+    (0x93, '“'),
+    (0x94, '”'),
+    (0x95, '•'),
+    (0x96, '–'),
+    (0x97, '—'),
+    (0x98, '˜'),
+    (0x99, '™'),
+    (0x9A, 'š'),
+    (0x9B, '›'),
+    (0x9C, 'œ'),
+    (0x9E, 'ž'),
+    (0x9F, 'Ÿ'),
+];
+
+fn win_ansi_char(code: u8) -> Option<char> {
+    match code {
+        0x20..=0x7E | 0xA0..=0xFF => Some(code as char),
+        _ => WIN_ANSI_SPECIAL
+            .iter()
+            .find(|(c, _)| *c == code)
+            .map(|(_, ch)| *ch),
+    }
+}
+
+/// Characters outside of the WinAnsiEncoding are replaced by '?'
+fn win_ansi_byte(character: char) -> u8 {
+    match character as u32 {
+        0x20..=0x7E | 0xA0..=0xFF => character as u8,
+        _ => WIN_ANSI_SPECIAL
+            .iter()
+            .find(|(_, ch)| *ch == character)
+            .map(|(c, _)| *c)
+            .unwrap_or(b'?'),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PdfFontStyle {
+    Regular,
+    Bold,
+    Italic,
+}
+impl PdfFontStyle {
+    const ALL: [PdfFontStyle; 3] = [
+        PdfFontStyle::Regular,
+        PdfFontStyle::Bold,
+        PdfFontStyle::Italic,
+    ];
+
+    /// Same as the browser print: it sets bold first and then italic, so italic wins
+    pub fn from_flags(bold: bool, italic: bool) -> Self {
+        match (bold, italic) {
+            (_, true) => PdfFontStyle::Italic,
+            (true, false) => PdfFontStyle::Bold,
+            (false, false) => PdfFontStyle::Regular,
+        }
+    }
+
+    fn base_font(&self) -> &'static str {
+        match self {
+            PdfFontStyle::Regular => "DejaVuSerif",
+            PdfFontStyle::Bold => "DejaVuSerif-Bold",
+            PdfFontStyle::Italic => "DejaVuSerif-Italic",
+        }
+    }
+
+    fn font_data(&self) -> &'static [u8] {
+        match self {
+            PdfFontStyle::Regular => {
+                include_bytes!("/usr/share/fonts/truetype/dejavu/DejaVuSerif.ttf")
+            }
+            PdfFontStyle::Bold => {
+                include_bytes!("/usr/share/fonts/truetype/dejavu/DejaVuSerif-Bold.ttf")
+            }
+            PdfFontStyle::Italic => {
+                include_bytes!("/usr/share/fonts/truetype/dejavu/DejaVuSerif-Italic.ttf")
+            }
+        }
+    }
+
+    fn is_italic(&self) -> bool {
+        *self == PdfFontStyle::Italic
+    }
+}
+
+/// Stretched over the whole page, like the browser does with the rendered background
+struct PdfImage {
+    width: u32,
+    height: u32,
+    jpeg: Vec<u8>,
+}
+impl PdfImage {
+    fn from_png(png: &[u8]) -> Result<Self, String> {
+        let rgba = image::load_from_memory_with_format(png, ImageFormat::Png)
+            .map_err(|e| format!("Could not read the background image: {}", e))?
+            .to_rgba8();
+
+        // transparent parts are blank paper
+        let rgb = RgbImage::from_fn(rgba.width(), rgba.height(), |x, y| {
+            let [r, g, b, a] = rgba.get_pixel(x, y).0;
+            let on_white = |c: u8| ((c as u32 * a as u32 + 255 * (255 - a as u32)) / 255) as u8;
+            Rgb([on_white(r), on_white(g), on_white(b)])
+        });
+
+        let mut jpeg = Vec::new();
+        JpegEncoder::new_with_quality(&mut jpeg, BACKGROUND_JPEG_QUALITY)
+            .encode_image(&rgb)
+            .map_err(|e| format!("Could not encode the background image: {}", e))?;
+
+        Ok(Self {
+            width: rgb.width(),
+            height: rgb.height(),
+            jpeg,
+        })
+    }
+}
+
+struct PdfText {
+    pos_x: f64, // mm
+    pos_y: f64, // mm, baseline
+    size: f64,  // pt
+    style: PdfFontStyle,
+    centered: bool,
+    text: Vec<u8>,
+}
+
+struct PdfFont {
+    style: PdfFontStyle,
+    widths: Vec<i64>, // 1/1000 of the font size, starting at FIRST_CHAR
+    bounding_box: [i64; 4],
+    ascent: i64,
+    descent: i64,
+    cap_height: i64,
+}
+impl PdfFont {
+    fn load(style: PdfFontStyle) -> Result<Self, String> {
+        let font = Font::from_bytes(style.font_data(), FontSettings::default())
+            .map_err(|e| format!("Could not load the pdf font: {}", e))?;
+
+        let mut widths = Vec::new();
+        let mut bounding_box = [0.0f32; 4];
+        for code in FIRST_CHAR..=255 {
+            match win_ansi_char(code) {
+                Some(character) => {
+                    let metrics = font.metrics(character, 1000.0);
+                    widths.push(metrics.advance_width.round() as i64);
+
+                    let bounds = metrics.bounds;
+                    bounding_box[0] = bounding_box[0].min(bounds.xmin);
+                    bounding_box[1] = bounding_box[1].min(bounds.ymin);
+                    bounding_box[2] = bounding_box[2].max(bounds.xmin + bounds.width);
+                    bounding_box[3] = bounding_box[3].max(bounds.ymin + bounds.height);
+                }
+                None => widths.push(0),
+            }
+        }
+        let (ascent, descent) = match font.horizontal_line_metrics(1000.0) {
+            Some(lm) => (lm.ascent, lm.descent),
+            None => (bounding_box[3], bounding_box[1]),
+        };
+
+        Ok(Self {
+            style,
+            widths,
+            bounding_box: bounding_box.map(|v| v.round() as i64),
+            ascent: ascent.round() as i64,
+            descent: descent.round() as i64,
+            cap_height: font.metrics('H', 1000.0).bounds.height.round() as i64,
+        })
+    }
+
+    fn text_width(&self, text: &[u8], size: f64) -> f64 {
+        let units: i64 = text
+            .iter()
+            .map(|b| {
+                if *b >= FIRST_CHAR {
+                    self.widths[(*b - FIRST_CHAR) as usize]
+                } else {
+                    0
+                }
+            })
+            .sum();
+
+        units as f64 * size / 1000.0
+    }
+}
+
+/// Minimal pdf writer for text pages on an optional background. Fonts are embedded, so umlauts and text widths are exact
+pub struct PdfDocument {
+    width: f64,  // mm
+    height: f64, // mm
+    background: Option<PdfImage>,
+    pages: Vec<Vec<PdfText>>,
+}
+impl PdfDocument {
+    pub fn new(width: f64, height: f64) -> Self {
+        Self {
+            width,
+            height,
+            background: None,
+            pages: Vec::new(),
+        }
+    }
+
+    /// Shown on every page, stored only once in the file
+    pub fn set_background(&mut self, png: &[u8]) -> Result<(), String> {
+        self.background = Some(PdfImage::from_png(png)?);
+        Ok(())
+    }
+
+    pub fn add_page(&mut self) {
+        self.pages.push(Vec::new());
+    }
+
+    /// Same semantics as jsPDF: position in mm from the top left, y is the baseline, size in pt
+    pub fn text(
+        &mut self,
+        text: &str,
+        pos_x: f64,
+        pos_y: f64,
+        size: f64,
+        style: PdfFontStyle,
+        centered: bool,
+    ) {
+        if self.pages.is_empty() {
+            self.add_page();
+        }
+        if let Some(page) = self.pages.last_mut() {
+            page.push(PdfText {
+                pos_x,
+                pos_y,
+                size,
+                style,
+                centered,
+                text: text.chars().map(win_ansi_byte).collect(),
+            });
+        }
+    }
+
+    pub fn render(&self) -> Result<Vec<u8>, String> {
+        let mut pages: Vec<&Vec<PdfText>> = self.pages.iter().collect();
+        let empty_page = Vec::new();
+        if pages.is_empty() {
+            pages.push(&empty_page);
+        }
+
+        let fonts = PdfFontStyle::ALL
+            .iter()
+            .filter(|style| pages.iter().any(|p| p.iter().any(|t| t.style == **style)))
+            .map(|style| PdfFont::load(*style))
+            .collect::<Result<Vec<PdfFont>, String>>()?;
+
+        // object ids: 1 catalog, 2 page tree, 3 per font, the background, 2 per page
+        let font_id = |index: usize| 3 + 3 * index;
+        let background_id = 3 + 3 * fonts.len();
+        let page_id =
+            |index: usize| background_id + usize::from(self.background.is_some()) + 2 * index;
+
+        let mut objects: Vec<Vec<u8>> = Vec::new();
+        objects.push(b"<< /Type /Catalog /Pages 2 0 R >>".to_vec());
+        objects.push(
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                (0..pages.len())
+                    .map(|i| format!("{} 0 R", page_id(i)))
+                    .collect::<Vec<String>>()
+                    .join(" "),
+                pages.len()
+            )
+            .into_bytes(),
+        );
+
+        for (index, font) in fonts.iter().enumerate() {
+            let id = font_id(index);
+            objects.push(
+                format!(
+                    "<< /Type /Font /Subtype /TrueType /BaseFont /{} /FirstChar {} /LastChar 255 /Widths [{}] /FontDescriptor {} 0 R /Encoding /WinAnsiEncoding >>",
+                    font.style.base_font(),
+                    FIRST_CHAR,
+                    font.widths
+                        .iter()
+                        .map(|w| w.to_string())
+                        .collect::<Vec<String>>()
+                        .join(" "),
+                    id + 1
+                )
+                .into_bytes(),
+            );
+            // serif + nonsymbolic (+ italic)
+            let flags = 2 + 32 + if font.style.is_italic() { 64 } else { 0 };
+            objects.push(
+                format!(
+                    "<< /Type /FontDescriptor /FontName /{} /Flags {} /FontBBox [{} {} {} {}] /ItalicAngle {} /Ascent {} /Descent {} /CapHeight {} /StemV 80 /FontFile2 {} 0 R >>",
+                    font.style.base_font(),
+                    flags,
+                    font.bounding_box[0],
+                    font.bounding_box[1],
+                    font.bounding_box[2],
+                    font.bounding_box[3],
+                    if font.style.is_italic() { -11 } else { 0 },
+                    font.ascent,
+                    font.descent,
+                    font.cap_height,
+                    id + 2
+                )
+                .into_bytes(),
+            );
+            let data = font.style.font_data();
+            let mut stream = format!(
+                "<< /Length {} /Length1 {} >>\nstream\n",
+                data.len(),
+                data.len()
+            )
+            .into_bytes();
+            stream.extend_from_slice(data);
+            stream.extend_from_slice(b"\nendstream");
+            objects.push(stream);
+        }
+
+        let mut resources = format!(
+            "/Font << {} >>",
+            fonts
+                .iter()
+                .enumerate()
+                .map(|(i, _)| format!("/F{} {} 0 R", i + 1, font_id(i)))
+                .collect::<Vec<String>>()
+                .join(" ")
+        );
+        if let Some(background) = &self.background {
+            resources.push_str(&format!(" /XObject << /Bg {} 0 R >>", background_id));
+
+            let mut stream = format!(
+                "<< /Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceRGB /BitsPerComponent 8 /Filter /DCTDecode /Length {} >>\nstream\n",
+                background.width,
+                background.height,
+                background.jpeg.len()
+            )
+            .into_bytes();
+            stream.extend_from_slice(&background.jpeg);
+            stream.extend_from_slice(b"\nendstream");
+            objects.push(stream);
+        }
+
+        for (index, page) in pages.iter().enumerate() {
+            objects.push(
+                format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {:.2} {:.2}] /Resources << {} >> /Contents {} 0 R >>",
+                    self.width * MM_TO_PT,
+                    self.height * MM_TO_PT,
+                    resources,
+                    page_id(index) + 1
+                )
+                .into_bytes(),
+            );
+
+            let mut content: Vec<u8> = Vec::new();
+            if self.background.is_some() {
+                writeln!(
+                    content,
+                    "q {:.2} 0 0 {:.2} 0 0 cm /Bg Do Q",
+                    self.width * MM_TO_PT,
+                    self.height * MM_TO_PT
+                )
+                .map_err(|e| e.to_string())?;
+            }
+            for text in page.iter() {
+                let font_index = match fonts.iter().position(|f| f.style == text.style) {
+                    Some(i) => i,
+                    None => return Err("Font of a text was not loaded".into()),
+                };
+                let mut x = text.pos_x * MM_TO_PT;
+                if text.centered {
+                    x -= fonts[font_index].text_width(&text.text, text.size) / 2.0;
+                }
+                let y = (self.height - text.pos_y) * MM_TO_PT;
+
+                write!(
+                    content,
+                    "BT /F{} {:.2} Tf {:.2} {:.2} Td (",
+                    font_index + 1,
+                    text.size,
+                    x,
+                    y
+                )
+                .map_err(|e| e.to_string())?;
+                for byte in text.text.iter() {
+                    match byte {
+                        b'(' | b')' | b'\\' => content.extend_from_slice(&[b'\\', *byte]),
+                        0x80..=0xFF => {
+                            write!(content, "\\{:03o}", byte).map_err(|e| e.to_string())?
+                        }
+                        _ => content.push(*byte),
+                    }
+                }
+                content.extend_from_slice(b") Tj ET\n");
+            }
+            let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+            stream.extend_from_slice(&content);
+            stream.extend_from_slice(b"\nendstream");
+            objects.push(stream);
+        }
+
+        let mut out: Vec<u8> = Vec::new();
+        out.extend_from_slice(b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n");
+        let mut offsets: Vec<usize> = Vec::new();
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(out.len());
+            write!(out, "{} 0 obj\n", index + 1).map_err(|e| e.to_string())?;
+            out.extend_from_slice(object);
+            out.extend_from_slice(b"\nendobj\n");
+        }
+        let xref_offset = out.len();
+        write!(out, "xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1)
+            .map_err(|e| e.to_string())?;
+        for offset in offsets {
+            write!(out, "{:010} 00000 n \n", offset).map_err(|e| e.to_string())?;
+        }
+        write!(
+            out,
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref_offset
+        )
+        .map_err(|e| e.to_string())?;
+
+        Ok(out)
+    }
+}
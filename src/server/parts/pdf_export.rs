@@ -0,0 +1,289 @@
+use super::export::write_to_output_file;
+use super::pdf::{PdfDocument, PdfFontStyle};
+use crate::{
+    args::Args,
+    database::{
+        get_all_athletes_meta_data, get_main_heat, get_pdf_background, ApplicationMode,
+        DatabaseManager, DatabaseSerializable, DatabaseStaticState,
+    },
+    server::camera_program_types::{AthleteWithMetadata, HeatData},
+    times::RaceTime,
+    webserver::{PDFBackground, PDFConfigurationContent, PDFConfigurationSetting, PDFSettingFor},
+};
+
+// distance and heat_descriminator of the runs, in the order they count as rounds
+const SPK_RUNS: [(u32, u8); 6] = [(15, 1), (15, 2), (20, 1), (20, 2), (30, 1), (30, 2)];
+
+/// The same values the web client fills into the PDFConfigurationContentReference fields
+#[derive(Debug, Clone)]
+pub struct PdfAthleteData {
+    pub bib: u32,
+    pub first_name: String,
+    pub last_name: String,
+    pub round_times: Vec<u64>, // accumulated, in ten thousands of a second
+    pub spk_time: Option<u64>, // only if all runs are finished
+    pub spk_guess: Option<f32>,
+}
+
+fn spk_athlete_data(athlete: &AthleteWithMetadata) -> PdfAthleteData {
+    let run_times: Vec<Option<u64>> = SPK_RUNS
+        .iter()
+        .map(|(distance, descriminator)| {
+            athlete
+                .heats_from_assignments
+                .iter()
+                .find(|(_, ha, _)| {
+                    ha.distance == *distance && ha.heat_descriminator == *descriminator
+                })
+                .and_then(|(res, _, _)| res.as_ref())
+                .map(|res| res.runtime_full_precision.into_ten_thousands())
+        })
+        .collect();
+
+    // rounds only count, as long as there is no gap
+    let mut round_times = Vec::new();
+    let mut total = 0;
+    for run_time in run_times.iter() {
+        match run_time {
+            Some(t) => {
+                total += t;
+                round_times.push(total);
+            }
+            None => break,
+        }
+    }
+
+    PdfAthleteData {
+        bib: athlete.athlete.bib,
+        first_name: athlete.athlete.first_name.clone(),
+        last_name: athlete.athlete.last_name.clone(),
+        spk_time: if round_times.len() == SPK_RUNS.len() {
+            Some(total)
+        } else {
+            None
+        },
+        round_times,
+        spk_guess: athlete.athlete.spk_guess,
+    }
+}
+
+fn street_run_athlete_data(
+    athlete: &AthleteWithMetadata,
+    main_heat: Option<&HeatData>,
+) -> PdfAthleteData {
+    let mut round_times: Vec<u64> = Vec::new();
+    if let Some(evaluations) = main_heat.and_then(|h| h.evaluations.as_ref()) {
+        for evaluation in evaluations {
+            let result = &evaluation.competitor_result;
+            if result.competitor.bib == athlete.athlete.bib {
+                round_times.push(result.runtime_full_precision.into_ten_thousands());
+            }
+        }
+    }
+    round_times.sort();
+
+    PdfAthleteData {
+        bib: athlete.athlete.bib,
+        first_name: athlete.athlete.first_name.clone(),
+        last_name: athlete.athlete.last_name.clone(),
+        round_times,
+        spk_time: None,
+        spk_guess: athlete.athlete.spk_guess,
+    }
+}
+
+/// Sorted by bib
+pub fn pdf_athlete_data(
+    dbss: &DatabaseStaticState,
+    manager: &DatabaseManager,
+) -> Result<Vec<PdfAthleteData>, String> {
+    let athletes = get_all_athletes_meta_data(manager).map_err(|e| e.to_string())?;
+
+    let mut res: Vec<PdfAthleteData> = match dbss.mode {
+        ApplicationMode::SprinterKing => athletes.iter().map(spk_athlete_data).collect(),
+        ApplicationMode::StreetLongRun => {
+            let main_heat = get_main_heat(manager).map_err(|e| e.to_string())?;
+
+            athletes
+                .iter()
+                .map(|a| street_run_athlete_data(a, main_heat.as_ref()))
+                .collect()
+        }
+        // no round based results, only the athlete references are available
+        ApplicationMode::TrackCompetition => athletes
+            .iter()
+            .map(|a| PdfAthleteData {
+                bib: a.athlete.bib,
+                first_name: a.athlete.first_name.clone(),
+                last_name: a.athlete.last_name.clone(),
+                round_times: Vec::new(),
+                spk_time: None,
+                spk_guess: None,
+            })
+            .collect(),
+    };
+    res.sort_by_key(|a| a.bib);
+
+    Ok(res)
+}
+
+/// m:ss.hh like the web client
+fn time_text(ten_thousands: u64) -> String {
+    let rt = RaceTime::from_ten_thousands(ten_thousands, 2);
+    let fraction = format!("{}{}", rt.tenths.unwrap_or(0), rt.hundrets.unwrap_or(0));
+
+    match rt.hours {
+        Some(hours) => format!(
+            "{}:{:02}:{:02}.{}",
+            hours,
+            rt.minutes.unwrap_or(0),
+            rt.seconds,
+            fraction
+        ),
+        None => format!("{}:{:02}.{}", rt.minutes.unwrap_or(0), rt.seconds, fraction),
+    }
+}
+
+fn reference_text(reference: &str, alt: &Option<String>, athlete: &PdfAthleteData) -> String {
+    let rounds = athlete.round_times.len();
+    let round_reference = |prefix: &str| -> Option<usize> {
+        reference
+            .strip_prefix(prefix)
+            .and_then(|i| i.parse::<usize>().ok())
+            .filter(|i| *i >= 1)
+    };
+
+    match reference {
+        "Bib" => athlete.bib.to_string(),
+        "Name" => format!("{} {}", athlete.first_name, athlete.last_name),
+        "FirstName" => athlete.first_name.clone(),
+        "LastName" => athlete.last_name.clone(),
+        "FinalTime" => athlete
+            .round_times
+            .last()
+            .map(|t| time_text(*t))
+            .unwrap_or_default(),
+        "SpkTime" => athlete
+            .spk_time
+            .map(|t| format!("{:.2}", t as f64 / 10000.0))
+            .unwrap_or_default(),
+        "SpkGuess" => athlete
+            .spk_guess
+            .map(|g| format!("{:.2}", g))
+            .unwrap_or_default(),
+        _ => {
+            if let Some(i) = round_reference("HasRound") {
+                if i <= rounds {
+                    return alt.clone().unwrap_or_default();
+                }
+            } else if let Some(i) = round_reference("TotalTimeRound") {
+                if i <= rounds {
+                    return time_text(athlete.round_times[i - 1]);
+                }
+            } else if let Some(i) = round_reference("TimeRound") {
+                if i <= rounds {
+                    let previous = if i >= 2 {
+                        athlete.round_times[i - 2]
+                    } else {
+                        0
+                    };
+                    return time_text(athlete.round_times[i - 1] - previous);
+                }
+            }
+
+            String::new()
+        }
+    }
+}
+
+/// One page per athlete. Bibs are A5 landscape, certificates A4 portrait (same as in the web client)
+pub fn generate_pdf(
+    settings: &Vec<PDFConfigurationSetting>,
+    setting_for: PDFSettingFor,
+    background: Option<&PDFBackground>,
+    athletes: &Vec<PdfAthleteData>,
+) -> Result<Vec<u8>, String> {
+    let mut doc = match setting_for {
+        PDFSettingFor::Bib => PdfDocument::new(210.0, 148.0),
+        PDFSettingFor::Certificate => PdfDocument::new(210.0, 297.0),
+    };
+    if let Some(background) = background {
+        doc.set_background(&background.png()?)?;
+    }
+    let settings: Vec<&PDFConfigurationSetting> = settings
+        .iter()
+        .filter(|s| s.setting_for == setting_for)
+        .collect();
+
+    for athlete in athletes {
+        doc.add_page();
+
+        for set in settings.iter() {
+            let text = match &set.content {
+                PDFConfigurationContent::PDFConfigurationContentText { text } => text.clone(),
+                PDFConfigurationContent::PDFConfigurationContentReference {
+                    reference,
+                    reference_content,
+                } => reference_text(reference, reference_content, athlete),
+            };
+
+            doc.text(
+                &text,
+                set.pos_x,
+                set.pos_y,
+                set.size,
+                PdfFontStyle::from_flags(set.bold, set.italic),
+                set.centered,
+            );
+        }
+    }
+
+    doc.render()
+}
+
+/// Writes the bib sheets of all athletes and the certificates of all athletes with results into the export folder.
+/// The backgrounds are the ones the web control uploaded, without one they are meant to be printed onto the pre-printed paper
+pub fn write_pdf_exports(args: &Args, dbss: &DatabaseStaticState, manager: &DatabaseManager) {
+    let settings = match PDFConfigurationSetting::get_all_from_database(manager) {
+        Ok(s) => s,
+        Err(e) => {
+            error!("Could not load pdf settings: {}", e);
+            return;
+        }
+    };
+    let athletes = match pdf_athlete_data(dbss, manager) {
+        Ok(a) => a,
+        Err(e) => {
+            error!("Could not load athletes for the pdf export: {}", e);
+            return;
+        }
+    };
+    let finishers: Vec<PdfAthleteData> = match dbss.mode {
+        ApplicationMode::TrackCompetition => athletes.clone(),
+        _ => athletes
+            .iter()
+            .filter(|a| !a.round_times.is_empty())
+            .cloned()
+            .collect(),
+    };
+
+    for (setting_for, name, data) in [
+        (PDFSettingFor::Bib, "bibs", &athletes),
+        (PDFSettingFor::Certificate, "certificates", &finishers),
+    ] {
+        let background = match get_pdf_background(&setting_for, manager) {
+            Ok(b) => b,
+            Err(e) => {
+                error!("Could not load the {} pdf background: {}", name, e);
+                None
+            }
+        };
+
+        match generate_pdf(&settings, setting_for, background.as_ref(), data) {
+            Ok(pdf) => {
+                write_to_output_file(args, &format!("jta-dwa-{}-{}.pdf", dbss.date, name), pdf)
+            }
+            Err(e) => error!("Could not generate the {} pdf: {}", name, e),
+        }
+    }
+}
@@ -0,0 +1,488 @@
+use super::export::{meet_name, write_to_output_file};
+use super::wind_report::{generate_wind_report, render_wind_report, wind_report_file_name};
+use crate::{
+    args::Args,
+    database::{get_heat_data, DatabaseManager, DatabaseSerializable, DatabaseStaticState},
+    server::camera_program_types::{
+        DisqualificationReason, HeatCompetitor, HeatCompetitorResult, HeatData, HeatStartList,
+    },
+    times::{wind_relevant_for_distance, WindLegality},
+};
+use chrono::{Local, NaiveDate};
+use serde::Serialize;
+use std::time::Duration;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResultExportFormat {
+    Csv,
+    Json,
+    Html,
+}
+impl ResultExportFormat {
+    pub const ALL: [ResultExportFormat; 3] = [
+        ResultExportFormat::Csv,
+        ResultExportFormat::Json,
+        ResultExportFormat::Html,
+    ];
+
+    pub fn parse(input: &str) -> Option<Self> {
+        match input.to_lowercase().as_str() {
+            "csv" => Some(ResultExportFormat::Csv),
+            "json" => Some(ResultExportFormat::Json),
+            "html" => Some(ResultExportFormat::Html),
+            _ => None,
+        }
+    }
+
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            ResultExportFormat::Csv => "csv",
+            ResultExportFormat::Json => "json",
+            ResultExportFormat::Html => "html",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ResultExportFormat::Csv => "text/csv; charset=utf-8",
+            ResultExportFormat::Json => "application/json",
+            ResultExportFormat::Html => "text/html; charset=utf-8",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ResultExportRow {
+    pub rank: Option<u32>,
+    pub lane: u32,
+    pub bib: u32,
+    pub last_name: String,
+    pub first_name: String,
+    pub club: String,
+    pub nation: String,
+    pub runtime: Option<String>,
+    pub disqualification: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ResultExportSplit {
+    pub distance_meters: Option<u32>,
+    pub runtime: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ResultExportHeat {
+    pub id: Uuid,
+    pub name: String,
+    pub distance_meters: u32,
+    pub scheduled_start_time: String,
+    pub wind: Option<String>,
+    pub wind_legality: WindLegality,
+    /// split times of the leader
+    pub splits: Vec<ResultExportSplit>,
+    pub results: Vec<ResultExportRow>,
+}
+impl ResultExportHeat {
+    /// "+2.4 m/s w" for wind aided heats
+    pub fn wind_text(&self) -> Option<String> {
+        self.wind.as_ref().map(|w| match self.wind_legality {
+            WindLegality::WindAided => format!("{} m/s {}", w, self.wind_legality.marker()),
+            _ => format!("{} m/s", w),
+        })
+    }
+
+    fn splits_text(&self) -> String {
+        self.splits
+            .iter()
+            .map(|s| match s.distance_meters {
+                Some(distance) => format!("{}m {}", distance, s.runtime),
+                None => s.runtime.clone(),
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ResultExport {
+    pub name: String,
+    pub date: NaiveDate,
+    pub generated: String,
+    pub heats: Vec<ResultExportHeat>,
+}
+
+fn disqualification_text(reason: &DisqualificationReason) -> String {
+    match reason {
+        DisqualificationReason::Disqualified => "DQ".into(),
+        DisqualificationReason::DidNotStart => "DNS".into(),
+        DisqualificationReason::DidNotFinish => "DNF".into(),
+        DisqualificationReason::Canceled => "Canceled".into(),
+        DisqualificationReason::Other(text) => text.clone(),
+    }
+}
+
+fn row_from_competitor(
+    competitor: &HeatCompetitor,
+    result: Option<&HeatCompetitorResult>,
+) -> ResultExportRow {
+    ResultExportRow {
+        rank: result.map(|r| r.rank),
+        lane: competitor.lane,
+        bib: competitor.bib,
+        last_name: competitor.last_name.clone(),
+        first_name: competitor.first_name.clone(),
+        club: competitor.club.clone(),
+        nation: competitor.nation.clone(),
+        runtime: result.map(|r| r.runtime.to_string()),
+        disqualification: competitor.disqualified.as_ref().map(disqualification_text),
+    }
+}
+
+/// Prefers the final result list, but falls back to the single evaluations while the heat is still being evaluated
+pub fn heat_result_export(data: &HeatData) -> ResultExportHeat {
+    let mut results: Vec<ResultExportRow> = Vec::new();
+
+    let wind = match (&data.result, &data.wind) {
+        (Some(result), _) if result.wind.is_some() => result.wind.as_ref(),
+        (_, Some(wind)) => Some(&wind.wind),
+        _ => None,
+    };
+    let wind_legality = WindLegality::evaluate(
+        wind,
+        wind_relevant_for_distance(data.start_list.distance_meters),
+    );
+
+    let evaluated: Vec<&HeatCompetitorResult> = match &data.result {
+        Some(result) => result.competitors_evaluated.iter().collect(),
+        None => data
+            .evaluations
+            .iter()
+            .flatten()
+            .map(|e| &e.competitor_result)
+            .collect(),
+    };
+    for result in &evaluated {
+        results.push(row_from_competitor(&result.competitor, Some(result)));
+    }
+
+    // everyone from the start list without a time (did not finish, disqualified, not yet evaluated)
+    for competitor in &data.start_list.competitors {
+        if !evaluated.iter().any(|r| r.competitor.bib == competitor.bib) {
+            let competitor = data
+                .result
+                .as_ref()
+                .and_then(|r| {
+                    r.competitors_left_to_evaluate
+                        .iter()
+                        .find(|c| c.bib == competitor.bib)
+                })
+                .unwrap_or(competitor);
+            results.push(row_from_competitor(competitor, None));
+        }
+    }
+
+    // ranked first, the rest in lane order
+    results.sort_by(|a, b| match (a.rank, b.rank) {
+        (Some(ra), Some(rb)) => ra.cmp(&rb),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => a.lane.cmp(&b.lane),
+    });
+
+    ResultExportHeat {
+        id: data.start_list.id,
+        name: data.start_list.name.clone(),
+        distance_meters: data.start_list.distance_meters,
+        scheduled_start_time: data.start_list.scheduled_start_time.to_string(),
+        wind: wind.map(|w| w.to_string()),
+        wind_legality,
+        splits: data
+            .splits
+            .iter()
+            .flatten()
+            .map(|s| ResultExportSplit {
+                distance_meters: s.distance_meters,
+                runtime: s.race_time.to_string(),
+            })
+            .collect(),
+        results,
+    }
+}
+
+fn result_export(dbss: &DatabaseStaticState, heats: Vec<ResultExportHeat>) -> ResultExport {
+    ResultExport {
+        name: meet_name(dbss),
+        date: dbss.date,
+        generated: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        heats,
+    }
+}
+
+pub fn generate_heat_result_export(
+    dbss: &DatabaseStaticState,
+    heat_id: Uuid,
+    manager: &DatabaseManager,
+) -> Result<ResultExport, String> {
+    let data = get_heat_data(heat_id, manager)
+        .map_err(|e| format!("Could not read heat {} from database: {}", heat_id, e))?;
+
+    Ok(result_export(dbss, vec![heat_result_export(&data)]))
+}
+
+pub fn start_lists_in_schedule_order(
+    manager: &DatabaseManager,
+) -> Result<Vec<HeatStartList>, String> {
+    let mut start_lists = HeatStartList::get_all_from_database(manager)
+        .map_err(|e| format!("Could not read heats from database: {}", e))?;
+    start_lists.sort_by(|a, b| {
+        a.session_nr
+            .cmp(&b.session_nr)
+            .then(
+                Duration::from(a.scheduled_start_time.clone())
+                    .cmp(&Duration::from(b.scheduled_start_time.clone())),
+            )
+            .then(a.nr.cmp(&b.nr))
+    });
+
+    Ok(start_lists)
+}
+
+/// All heats that already have a time for at least one competitor, in schedule order
+pub fn generate_meet_result_export(
+    dbss: &DatabaseStaticState,
+    manager: &DatabaseManager,
+) -> Result<ResultExport, String> {
+    let start_lists = start_lists_in_schedule_order(manager)?;
+
+    let mut heats = Vec::new();
+    for start_list in start_lists {
+        let data = match get_heat_data(start_list.id, manager) {
+            Ok(d) => d,
+            Err(e) => {
+                error!("Skipping heat {} in results export: {}", start_list.name, e);
+                continue;
+            }
+        };
+        if data.result.is_none() && data.evaluations.is_none() {
+            continue;
+        }
+        heats.push(heat_result_export(&data));
+    }
+
+    Ok(result_export(dbss, heats))
+}
+
+// semicolon, so the file opens directly in spreadsheet programs with german locale
+pub(super) const CSV_SEPARATOR: char = ';';
+
+pub(super) fn csv_field(value: &str) -> String {
+    if value.contains(CSV_SEPARATOR)
+        || value.contains('"')
+        || value.contains('\n')
+        || value.contains('\r')
+    {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn render_csv(export: &ResultExport) -> String {
+    let mut out = [
+        "heat",
+        "distance",
+        "wind",
+        "wind_aided",
+        "rank",
+        "bib",
+        "last_name",
+        "first_name",
+        "club",
+        "nation",
+        "runtime",
+        "disqualification",
+        "splits",
+    ]
+    .join(&CSV_SEPARATOR.to_string());
+    out.push('\n');
+
+    for heat in &export.heats {
+        let splits = heat.splits_text();
+        for row in &heat.results {
+            let fields = [
+                heat.name.clone(),
+                heat.distance_meters.to_string(),
+                heat.wind.clone().unwrap_or_default(),
+                heat.wind_legality.marker().to_string(),
+                row.rank.map(|r| r.to_string()).unwrap_or_default(),
+                row.bib.to_string(),
+                row.last_name.clone(),
+                row.first_name.clone(),
+                row.club.clone(),
+                row.nation.clone(),
+                row.runtime.clone().unwrap_or_default(),
+                row.disqualification.clone().unwrap_or_default(),
+                splits.clone(),
+            ];
+            out.push_str(
+                &fields
+                    .iter()
+                    .map(|f| csv_field(f))
+                    .collect::<Vec<String>>()
+                    .join(&CSV_SEPARATOR.to_string()),
+            );
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+pub fn html_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+pub(super) const HTML_STYLE: &str = "body { font-family: sans-serif; margin: 1.5cm; }
+h1 { font-size: 1.4em; margin-bottom: 0; }
+h2 { font-size: 1.1em; margin-bottom: 0.2em; }
+.meta { color: #555; font-size: 0.85em; }
+.heat { page-break-inside: avoid; margin-top: 1.2em; }
+table { border-collapse: collapse; width: 100%; }
+th, td { border-bottom: 1px solid #ccc; padding: 0.15em 0.4em; text-align: left; }
+td.num { text-align: right; }
+@media print { body { margin: 0; } }";
+
+fn render_html(export: &ResultExport) -> String {
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{}\n</style>\n</head>\n<body>\n",
+        html_escape(&export.name),
+        HTML_STYLE
+    );
+    out.push_str(&format!(
+        "<h1>{}</h1>\n<p class=\"meta\">{}, generated {}</p>\n",
+        html_escape(&export.name),
+        export.date,
+        html_escape(&export.generated)
+    ));
+
+    for heat in &export.heats {
+        out.push_str("<div class=\"heat\">\n");
+        out.push_str(&format!(
+            "<h2>{}</h2>\n<p class=\"meta\">{}m, {}{}</p>\n",
+            html_escape(&heat.name),
+            heat.distance_meters,
+            html_escape(&heat.scheduled_start_time),
+            match heat.wind_text() {
+                Some(w) => format!(", Wind: {}", html_escape(&w)),
+                None => String::new(),
+            }
+        ));
+        if !heat.splits.is_empty() {
+            out.push_str(&format!(
+                "<p class=\"meta\">Splits: {}</p>\n",
+                html_escape(&heat.splits_text())
+            ));
+        }
+        out.push_str("<table>\n<tr><th>Rank</th><th>Bib</th><th>Name</th><th>Club</th><th>Nation</th><th>Time</th></tr>\n");
+        for row in &heat.results {
+            out.push_str(&format!(
+                "<tr><td class=\"num\">{}</td><td class=\"num\">{}</td><td>{} {}</td><td>{}</td><td>{}</td><td class=\"num\">{}</td></tr>\n",
+                row.rank.map(|r| format!("{}.", r)).unwrap_or_default(),
+                row.bib,
+                html_escape(&row.first_name),
+                html_escape(&row.last_name),
+                html_escape(&row.club),
+                html_escape(&row.nation),
+                html_escape(
+                    &row.disqualification
+                        .clone()
+                        .or(row.runtime.clone())
+                        .unwrap_or_default()
+                ),
+            ));
+        }
+        out.push_str("</table>\n</div>\n");
+    }
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+pub fn render_result_export(
+    export: &ResultExport,
+    format: ResultExportFormat,
+) -> Result<String, String> {
+    match format {
+        ResultExportFormat::Csv => Ok(render_csv(export)),
+        ResultExportFormat::Json => serde_json::to_string_pretty(export)
+            .map_err(|e| format!("Could not serialize results: {}", e)),
+        ResultExportFormat::Html => Ok(render_html(export)),
+    }
+}
+
+/// file name without extension
+pub fn result_export_file_name(dbss: &DatabaseStaticState, heat_id: Option<Uuid>) -> String {
+    match heat_id {
+        Some(id) => format!("jta-dwa-{}-results-{}", dbss.date, id),
+        None => format!("jta-dwa-{}-results", dbss.date),
+    }
+}
+
+/// Writes the results in all formats into the export folder
+pub fn write_result_exports(
+    args: &Args,
+    dbss: &DatabaseStaticState,
+    heat_id: Option<Uuid>,
+    manager: &DatabaseManager,
+) {
+    let export = match heat_id {
+        Some(id) => generate_heat_result_export(dbss, id, manager),
+        None => generate_meet_result_export(dbss, manager),
+    };
+    let export = match export {
+        Ok(e) => e,
+        Err(e) => {
+            error!("Could not generate results export: {}", e);
+            return;
+        }
+    };
+
+    let file_name = result_export_file_name(dbss, heat_id);
+    for format in ResultExportFormat::ALL {
+        match render_result_export(&export, format) {
+            Ok(data) => write_to_output_file(
+                args,
+                &format!("{}.{}", file_name, format.file_extension()),
+                &data,
+            ),
+            Err(e) => error!("{}", e),
+        }
+    }
+
+    // the wind report belongs to the results of the whole meet
+    if heat_id.is_none() {
+        let report = match generate_wind_report(dbss, manager) {
+            Ok(r) => r,
+            Err(e) => {
+                error!("Could not generate wind report: {}", e);
+                return;
+            }
+        };
+        let file_name = wind_report_file_name(dbss);
+        for format in ResultExportFormat::ALL {
+            match render_wind_report(&report, format) {
+                Ok(data) => write_to_output_file(
+                    args,
+                    &format!("{}.{}", file_name, format.file_extension()),
+                    &data,
+                ),
+                Err(e) => error!("{}", e),
+            }
+        }
+    }
+}
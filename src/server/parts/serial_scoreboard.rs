@@ -0,0 +1,337 @@
+use crate::args::Args;
+use crate::server::comm_channel::InstructionCommunicationChannel;
+use crate::server::live_feed::LiveFeedEvent;
+use async_broadcast::RecvError;
+use serialport::SerialPort;
+use std::io::{self, Error, ErrorKind, Write};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+const SCOREBOARD_DEFAULT_BAUD_RATE: u32 = 9600;
+const SCOREBOARD_DEFAULT_WIDTH: usize = 8;
+const SCOREBOARD_WRITE_QUEUE: usize = 16;
+const SCOREBOARD_REOPEN_INTERVAL: Duration = Duration::from_millis(1000);
+
+#[derive(Debug, Clone, PartialEq)]
+enum FramingPart {
+    Bytes(Vec<u8>),
+    Text,
+    Status,
+}
+
+/// Every board family frames its text differently, so the framing is a template:
+/// `{text}` is the right aligned text, `{status}` one character telling what is shown
+/// (R running, I intermediate, F finish, C cleared, W wind) and `<XX>` a byte in hex (`<2C>` for a comma).
+/// `line` is short for `{text}<0D>`, `framed` for `<02>{status}{text}<03>`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreboardFraming {
+    parts: Vec<FramingPart>,
+}
+impl ScoreboardFraming {
+    fn parse(input: &str) -> Result<Self, String> {
+        let template = match input.to_lowercase().as_str() {
+            "line" => "{text}<0D>",
+            "framed" => "<02>{status}{text}<03>",
+            _ => input,
+        };
+
+        let mut parts = Vec::new();
+        let mut rest = template;
+        while !rest.is_empty() {
+            if let Some(after) = rest.strip_prefix("{text}") {
+                parts.push(FramingPart::Text);
+                rest = after;
+            } else if let Some(after) = rest.strip_prefix("{status}") {
+                parts.push(FramingPart::Status);
+                rest = after;
+            } else if rest.starts_with('<') {
+                let (hex, after) = rest[1..]
+                    .split_once('>')
+                    .ok_or(format!("Unclosed '<' in scoreboard framing '{}'", input))?;
+                let byte = u8::from_str_radix(hex, 16).map_err(|_| {
+                    format!("Invalid byte <{}> in scoreboard framing '{}'", hex, input)
+                })?;
+                parts.push(FramingPart::Bytes(vec![byte]));
+                rest = after;
+            } else {
+                // at least the first character, a '{' that starts no placeholder is taken literally
+                let first_len = rest.chars().next().map_or(1, |c| c.len_utf8());
+                let literal_end = rest[first_len..]
+                    .find(['{', '<'])
+                    .map(|i| i + first_len)
+                    .unwrap_or(rest.len());
+                parts.push(FramingPart::Bytes(rest.as_bytes()[..literal_end].to_vec()));
+                rest = &rest[literal_end..];
+            }
+        }
+
+        if !parts.contains(&FramingPart::Text) {
+            return Err(format!(
+                "Scoreboard framing '{}' has no {{text}} in it",
+                input
+            ));
+        }
+        Ok(Self { parts })
+    }
+
+    /// Without a status the board can not tell a wind from a time, it only has the one line
+    fn has_status(&self) -> bool {
+        self.parts.contains(&FramingPart::Status)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ScoreboardConfig {
+    pub name: String,
+    pub framing: ScoreboardFraming,
+    pub port_path: String,
+    pub baud_rate: u32,
+    pub width: usize, // number of characters the board can show
+}
+
+/// All `scoreboard` entries ("name,framing,port[,baud[,width]]")
+pub fn scoreboards_from_args(args: &Args) -> Result<Vec<ScoreboardConfig>, String> {
+    let mut res: Vec<ScoreboardConfig> = Vec::new();
+
+    for entry in &args.scoreboard {
+        let parts: Vec<&str> = entry.split(',').map(|p| p.trim()).collect();
+        if parts.len() < 3 || parts.len() > 5 || parts[0].is_empty() || parts[2].is_empty() {
+            return Err(format!(
+                "Scoreboard '{}' must have the format 'name,framing,port[,baud[,width]]'",
+                entry
+            ));
+        }
+        if res.iter().any(|b| b.name == parts[0]) {
+            return Err(format!("Scoreboard name '{}' is used twice", parts[0]));
+        }
+
+        let baud_rate = match parts.get(3) {
+            Some(baud) => baud
+                .parse()
+                .map_err(|_| format!("Invalid baud rate '{}' for scoreboard", baud))?,
+            None => SCOREBOARD_DEFAULT_BAUD_RATE,
+        };
+        let width = match parts.get(4) {
+            Some(width) => width
+                .parse()
+                .ok()
+                .filter(|w| *w > 0)
+                .ok_or(format!("Invalid width '{}' for scoreboard", width))?,
+            None => SCOREBOARD_DEFAULT_WIDTH,
+        };
+
+        res.push(ScoreboardConfig {
+            name: String::from(parts[0]),
+            framing: ScoreboardFraming::parse(parts[1])?,
+            port_path: String::from(parts[2]),
+            baud_rate,
+            width,
+        });
+    }
+
+    Ok(res)
+}
+
+/// What a board should show, None for events it does not care about
+fn scoreboard_content(
+    args: &Args,
+    board: &ScoreboardConfig,
+    event: &LiveFeedEvent,
+) -> Option<(u8, String)> {
+    match event {
+        LiveFeedEvent::TimeRunning(rt) => Some((
+            b'R',
+            rt.optimize_representation_for_display(Some(1)).to_string(),
+        )),
+        LiveFeedEvent::TimeHeld(rt) => Some((
+            b'I',
+            rt.optimize_representation_for_display(Some(args.max_decimal_place_after_comma))
+                .to_string(),
+        )),
+        LiveFeedEvent::TimeEnd(rt) => Some((
+            b'F',
+            rt.optimize_representation_for_display(Some(args.max_decimal_place_after_comma))
+                .to_string(),
+        )),
+        LiveFeedEvent::TimeReset => Some((b'C', String::new())),
+        // a single line board keeps showing the time
+        LiveFeedEvent::Wind(wind) if board.framing.has_status() => {
+            Some((b'W', wind.wind.to_string()))
+        }
+        _ => None,
+    }
+}
+
+fn encode_for_scoreboard(board: &ScoreboardConfig, status: u8, text: &str) -> Vec<u8> {
+    // boards cut off on the right, so too long texts lose their leading characters instead
+    let chars: Vec<char> = text.chars().collect();
+    let visible: String = chars[chars.len().saturating_sub(board.width)..]
+        .iter()
+        .collect();
+    let aligned = format!("{:>width$}", visible, width = board.width);
+
+    let mut out = Vec::new();
+    for part in &board.framing.parts {
+        match part {
+            FramingPart::Bytes(bytes) => out.extend_from_slice(bytes),
+            FramingPart::Text => out.extend_from_slice(aligned.as_bytes()),
+            FramingPart::Status => out.push(status),
+        }
+    }
+    out
+}
+
+fn open_scoreboard_port(board: &ScoreboardConfig) -> Option<Box<dyn SerialPort>> {
+    match serialport::new(&board.port_path, board.baud_rate)
+        .timeout(Duration::from_millis(500))
+        .open()
+    {
+        Err(e) => {
+            error!(
+                "IO error when opening COM port {} of scoreboard '{}': {}",
+                board.port_path,
+                board.name,
+                e.to_string()
+            );
+            None
+        }
+        Ok(port) => {
+            info!(
+                "COM port {} of scoreboard '{}' opened",
+                board.port_path, board.name
+            );
+            Some(port)
+        }
+    }
+}
+
+/// The serialport lib is blocking, so the port lives in a blocking task that gets the encoded data over a channel
+fn run_scoreboard_writer(board: ScoreboardConfig, mut data_receiver: mpsc::Receiver<Vec<u8>>) {
+    let mut port: Option<Box<dyn SerialPort>> = None;
+    let mut last_open_attempt: Option<Instant> = None;
+    let mut last_sent: Option<Vec<u8>> = None;
+
+    while let Some(mut data) = data_receiver.blocking_recv() {
+        // if the board fell behind, only the newest content is of interest
+        while let Ok(newer) = data_receiver.try_recv() {
+            data = newer;
+        }
+
+        if port.is_none() {
+            // wait for the next attempt instead of dropping the data, it may be the final time
+            if let Some(remaining) =
+                last_open_attempt.and_then(|t| SCOREBOARD_REOPEN_INTERVAL.checked_sub(t.elapsed()))
+            {
+                std::thread::sleep(remaining);
+                while let Ok(newer) = data_receiver.try_recv() {
+                    data = newer;
+                }
+            }
+            last_open_attempt = Some(Instant::now());
+            port = open_scoreboard_port(&board);
+            last_sent = None;
+        }
+
+        // the running time arrives more often than the shown tenths change
+        if last_sent.as_ref() == Some(&data) {
+            continue;
+        }
+
+        if let Some(open_port) = port.as_mut() {
+            match open_port.write_all(&data).and_then(|_| open_port.flush()) {
+                Ok(()) => last_sent = Some(data),
+                Err(e) => {
+                    error!(
+                        "IO error when writing to scoreboard '{}': {}",
+                        board.name,
+                        e.to_string()
+                    );
+                    port = None; // retry with fresh connection
+                }
+            }
+        }
+    }
+
+    debug!("Writer of scoreboard '{}' stopped", board.name);
+}
+
+/// Drives a legacy LED board directly from the live feed
+pub async fn serial_scoreboard(
+    args: Args,
+    comm_channel: InstructionCommunicationChannel,
+    shutdown_marker: Arc<AtomicBool>,
+    board: ScoreboardConfig,
+) -> io::Result<()> {
+    let mut receiver = comm_channel.live_feed_receiver();
+    let (data_sender, data_receiver) = mpsc::channel::<Vec<u8>>(SCOREBOARD_WRITE_QUEUE);
+    let writer_board = board.clone();
+    let writer_task =
+        tokio::task::spawn_blocking(move || run_scoreboard_writer(writer_board, data_receiver));
+
+    loop {
+        if shutdown_marker.load(Ordering::SeqCst) {
+            info!(
+                "Shutdown requested, stopping output to scoreboard '{}'",
+                board.name
+            );
+            break;
+        }
+
+        let event = match receiver.wait_for_some_data().await {
+            Ok(Ok(event)) => event,
+            Ok(Err(RecvError::Overflowed(n))) => {
+                warn!(
+                    "Scoreboard '{}' was too slow, skipped {} events",
+                    board.name, n
+                );
+                continue;
+            }
+            Ok(Err(e)) => {
+                error!(
+                    "Scoreboard '{}' could not read from internal comm channel: {}",
+                    board.name,
+                    e.to_string()
+                );
+                return Err(Error::new(ErrorKind::Other, e.to_string()));
+            }
+            Err(_) => {
+                trace!("No live feed event for the scoreboard within timeout interval");
+                continue;
+            }
+        };
+
+        let Some((status, text)) = scoreboard_content(&args, &board, &event) else {
+            continue;
+        };
+        let data = encode_for_scoreboard(&board, status, &text);
+
+        // the running time comes again with the next tick, everything else has to reach the board
+        let writer_running = match event {
+            LiveFeedEvent::TimeRunning(_) => match data_sender.try_send(data) {
+                Ok(()) => true,
+                Err(mpsc::error::TrySendError::Full(_)) => {
+                    trace!("Scoreboard '{}' is busy writing, skipping", board.name);
+                    true
+                }
+                Err(mpsc::error::TrySendError::Closed(_)) => false,
+            },
+            _ => data_sender.send(data).await.is_ok(),
+        };
+        if !writer_running {
+            error!("Writer of scoreboard '{}' stopped unexpectedly", board.name);
+            return Err(Error::new(ErrorKind::Other, "Scoreboard writer stopped"));
+        }
+    }
+
+    drop(data_sender); // lets the writer run out
+    if let Err(e) = writer_task.await {
+        error!("Writer of scoreboard '{}' failed: {}", board.name, e);
+    }
+
+    receiver.conn_check_usage_end_function();
+    Ok(())
+}
@@ -1,16 +1,22 @@
 use crate::args::Args;
 use crate::interface::{ServerStateMachine, ServerStateMachineServerStateReader};
 use crate::server::comm_channel::{InstructionCommunicationChannel, PacketCommunicationChannel};
+use crate::server::display_clients::display_clients_from_args;
 use crate::server::parts::client_communicator::client_communicator;
 use crate::server::parts::database::create_database_manager;
 use crate::server::parts::intake_commands::intake_commands;
-use crate::server::parts::tcp_client_camera_program::tcp_client_camera_program;
+use crate::server::parts::serial_scoreboard::{scoreboards_from_args, serial_scoreboard};
+use crate::server::parts::session_replay::session_replay;
 use crate::server::parts::tcp_forwarder_display_program::tcp_forwarder_display_program;
 use crate::server::parts::tcp_listener_bib_detection::tcp_listener_bib_detection;
 use crate::server::parts::tcp_listener_idcapture_server::tcp_listener_idcapture_server;
 use crate::server::parts::tcp_listener_timing_program::tcp_listener_timing_program;
 use crate::server::parts::tcp_listener_wind_server::tcp_listener_wind_server;
+use crate::server::parts::timing_source::{
+    run_timing_source, timing_source_from_args, TimingSourceContext,
+};
 use crate::webserver::{get_local_ip, webserver, HttpServerStateManager, Server};
+use futures::future::try_join_all;
 use std::io::Error;
 use std::net::SocketAddr;
 use std::sync::{
@@ -44,56 +50,38 @@ pub async fn run_server(args: &Args) -> () {
         );
     }
 
-    let (camera_program_timing_address, camera_program_data_address, camera_program_xml_address) =
-        if let Some(camera_program_ip) = &args.address_camera_program {
-            let camera_program_timing_address: SocketAddr =
-                format!("{}:{}", camera_program_ip, args.camera_exchange_timing_port)
-                    .parse()
-                    .expect("Invalid camera program address for timing");
-            let camera_program_data_address: SocketAddr =
-                format!("{}:{}", camera_program_ip, args.camera_exchange_data_port)
-                    .parse()
-                    .expect("Invalid camera program address for data");
-            let camera_program_xml_address: SocketAddr =
-                format!("{}:{}", camera_program_ip, args.camera_exchange_xml_port)
-                    .parse()
-                    .expect("Invalid camera program address for xml");
-            info!(
-                "Talking to the camera program on {}, {} and {}",
-                camera_program_timing_address,
-                camera_program_data_address,
-                camera_program_xml_address
-            );
-
-            (
-                Some(camera_program_timing_address),
-                Some(camera_program_data_address),
-                Some(camera_program_xml_address),
-            )
-        } else {
-            info!("Not configured to connect to a camera program");
-            (None, None, None)
-        };
-
-    let internal_communication_address =
-        if let Some(display_client_ip) = &args.address_display_client {
-            let internal_communication_address: SocketAddr = format!(
-                "{}:{}",
-                display_client_ip, args.display_client_communication_port
-            )
-            .parse()
-            .expect("Invalid internal address");
+    let timing_source = timing_source_from_args(args);
 
-            info!(
-                "Talking to {} for internal communication to display client",
-                internal_communication_address
-            );
+    let display_clients = match display_clients_from_args(args) {
+        Ok(clients) => clients,
+        Err(e) => {
+            error!("Display client configuration problem: {}", e);
+            return;
+        }
+    };
+    if display_clients.is_empty() {
+        info!("Not configured to connect to a display client");
+    }
+    for client in display_clients.iter() {
+        info!(
+            "Talking to {} for internal communication to display client '{}' ({:?})",
+            client.address, client.name, client.role
+        );
+    }
 
-            Some(internal_communication_address)
-        } else {
-            info!("Not configured to connect to a display client");
-            None
-        };
+    let scoreboards = match scoreboards_from_args(args) {
+        Ok(boards) => boards,
+        Err(e) => {
+            error!("Scoreboard configuration problem: {}", e);
+            return;
+        }
+    };
+    for board in scoreboards.iter() {
+        info!(
+            "Driving scoreboard '{}' on {} ({} baud)",
+            board.name, board.port_path, board.baud_rate
+        );
+    }
 
     let own_addr_webcontrol: SocketAddr = format!("0.0.0.0:{}", args.internal_webcontrol_port)
         .parse()
@@ -168,9 +156,13 @@ pub async fn run_server(args: &Args) -> () {
         }
         Ok(man) => man,
     };
-    let (server_state, server_state_reader) = ServerStateMachineServerStateReader::build(
-        ServerStateMachine::new(&args, comm_channel.clone(), database_manager),
-    );
+    let (server_state, server_state_reader) =
+        ServerStateMachineServerStateReader::build(ServerStateMachine::new(
+            &args,
+            comm_channel.clone(),
+            database_manager.clone(),
+            display_clients.clone(),
+        ));
     let shutdown_marker = Arc::new(AtomicBool::new(false));
 
     let tcp_listener_server_instance = tcp_listener_timing_program(
@@ -191,13 +183,30 @@ pub async fn run_server(args: &Args) -> () {
         passthrough_address_display_program,
     );
 
-    let client_communicator_instance = client_communicator(
-        args.clone(),
-        server_state_reader.clone(),
-        comm_channel.clone(),
-        Arc::clone(&shutdown_marker),
-        internal_communication_address,
-    );
+    let client_communicator_instances = display_clients
+        .into_iter()
+        .map(|client| {
+            client_communicator(
+                args.clone(),
+                server_state_reader.clone(),
+                comm_channel.clone(),
+                Arc::clone(&shutdown_marker),
+                client,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let serial_scoreboard_instances = scoreboards
+        .into_iter()
+        .map(|board| {
+            serial_scoreboard(
+                args.clone(),
+                comm_channel.clone(),
+                Arc::clone(&shutdown_marker),
+                board,
+            )
+        })
+        .collect::<Vec<_>>();
 
     let intake_commands_instance = intake_commands(
         args.clone(),
@@ -206,17 +215,22 @@ pub async fn run_server(args: &Args) -> () {
         Arc::clone(&shutdown_marker),
     );
 
-    let tcp_client_camera_program_instance = tcp_client_camera_program(
-        args.clone(),
-        server_state_reader.clone(),
-        comm_channel.clone(),
-        Arc::clone(&shutdown_marker),
-        camera_program_timing_address,
-        camera_program_data_address,
-        camera_program_xml_address,
+    let timing_source_instance = run_timing_source(
+        timing_source,
+        TimingSourceContext {
+            args: args.clone(),
+            state_reader: server_state_reader.clone(),
+            comm_channel: comm_channel.clone(),
+            shutdown_marker: Arc::clone(&shutdown_marker),
+        },
     );
 
-    let web_server_task = webserver(own_addr_webcontrol, comm_channel.clone());
+    let web_server_task = webserver(
+        own_addr_webcontrol,
+        args,
+        comm_channel.clone(),
+        database_manager,
+    );
     let (web_server_manager, http_server): (HttpServerStateManager, Server) = match web_server_task
     {
         Ok(res) => res,
@@ -250,15 +264,20 @@ pub async fn run_server(args: &Args) -> () {
         bib_server_address,
     );
 
+    let session_replay_instance =
+        session_replay(args.clone(), comm_channel.clone(), shutdown_marker.clone());
+
     // spawn the async runtimes in parallel
-    let client_communicator_task = tokio::spawn(client_communicator_instance);
+    let client_communicator_task = tokio::spawn(try_join_all(client_communicator_instances));
+    let serial_scoreboard_task = tokio::spawn(try_join_all(serial_scoreboard_instances));
     let intake_commands_task = tokio::spawn(intake_commands_instance);
     let tcp_listener_server_task = tokio::spawn(tcp_listener_server_instance);
     let tcp_forwarder_display_program_task = tokio::spawn(tcp_forwarder_display_program_instance);
-    let tcp_client_camera_program_task = tokio::spawn(tcp_client_camera_program_instance);
+    let timing_source_task = tokio::spawn(timing_source_instance);
     let tcp_client_wind_server_task = tokio::spawn(tcp_client_wind_server_instance);
     let tcp_client_idcapture_server_task = tokio::spawn(tcp_client_idcapture_server_instance);
     let tcp_client_bib_server_task = tokio::spawn(tcp_client_bib_server_instance);
+    let session_replay_task = tokio::spawn(session_replay_instance);
     let webserver_task = tokio::spawn(http_server);
     let shutdown_task = tokio::spawn(async move {
         // listen for ctrl-c
@@ -273,13 +292,15 @@ pub async fn run_server(args: &Args) -> () {
     // Wait for all tasks to complete
     match tokio::try_join!(
         client_communicator_task,
+        serial_scoreboard_task,
         intake_commands_task,
         tcp_listener_server_task,
         tcp_forwarder_display_program_task,
-        tcp_client_camera_program_task,
+        timing_source_task,
         tcp_client_wind_server_task,
         tcp_client_idcapture_server_task,
         tcp_client_bib_server_task,
+        session_replay_task,
         webserver_task,
         shutdown_task,
     ) {
@@ -0,0 +1,151 @@
+use crate::args::Args;
+use crate::nrbf::BufferedParser;
+use crate::server::bib_detection::MessageFromBibServer;
+use crate::server::comm_channel::InstructionCommunicationChannel;
+use crate::server::session_recording::{read_session_file, RecordedChunk, RecordedStream};
+use crate::server::xml_serial::{BufferedParserSerial, BufferedParserXML};
+use crate::wind::format::WindMessageBroadcast;
+use std::io::{self, Error, ErrorKind};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use std::time::Duration;
+use tokio::time::{self, Instant};
+
+struct ReplayParsers {
+    camera_program_timing: BufferedParserSerial,
+    camera_program_data: BufferedParserXML,
+    camera_program_xml: BufferedParserXML,
+    timing_program: BufferedParser,
+}
+
+pub async fn session_replay(
+    args: Args,
+    comm_channel: InstructionCommunicationChannel,
+    shutdown_marker: Arc<AtomicBool>,
+) -> io::Result<()> {
+    let path = if let Some(path) = &args.replay_session_file {
+        path.clone()
+    } else {
+        // nothing to replay -> we can just die
+        return Ok(());
+    };
+
+    let chunks = match read_session_file(&path) {
+        Ok(chunks) => chunks,
+        Err(e) => {
+            // a broken replay file should not take down the rest of the server
+            error!("Could not replay session: {}", e);
+            return Ok(());
+        }
+    };
+    info!(
+        "Replaying {} recorded chunks from {} with speed factor {}",
+        chunks.len(),
+        path,
+        args.replay_speed_factor
+    );
+
+    let mut parsers = ReplayParsers {
+        camera_program_timing: BufferedParserSerial::new(&args),
+        camera_program_data: BufferedParserXML::new(),
+        camera_program_xml: BufferedParserXML::new(),
+        timing_program: BufferedParser::new(args.clone()),
+    };
+
+    let mut target = Instant::now();
+    let mut last_offset_us: u64 = 0;
+    for chunk in chunks {
+        // relative waiting, as concatenated recordings restart their offsets
+        let delta_us = chunk.offset_us.saturating_sub(last_offset_us);
+        last_offset_us = chunk.offset_us;
+        if args.replay_speed_factor > 0.0 {
+            target += Duration::from_micros((delta_us as f64 / args.replay_speed_factor) as u64);
+        }
+
+        // sleep in pieces, so we can check shutdown flag periodically
+        loop {
+            if shutdown_marker.load(Ordering::SeqCst) {
+                info!("Shutdown requested, stopping session replay");
+                return Ok(());
+            }
+
+            let now = Instant::now();
+            if now >= target {
+                break;
+            }
+            time::sleep((target - now).min(Duration::from_millis(
+                args.wait_ms_before_testing_for_shutdown,
+            )))
+            .await;
+        }
+
+        if let Err(e) = replay_chunk(&chunk, &mut parsers, &comm_channel) {
+            // problems with the internal comm channel
+            return Err(Error::new(ErrorKind::Other, e));
+        }
+    }
+
+    info!("Session replay finished");
+    Ok(())
+}
+
+fn replay_chunk(
+    chunk: &RecordedChunk,
+    parsers: &mut ReplayParsers,
+    comm_channel: &InstructionCommunicationChannel,
+) -> Result<(), String> {
+    let bytes = match chunk.bytes() {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            error!("{}", e);
+            return Ok(());
+        }
+    };
+
+    let camera_program_result = match chunk.stream {
+        RecordedStream::CameraProgramTiming => parsers.camera_program_timing.feed_bytes(&bytes),
+        RecordedStream::CameraProgramData => parsers.camera_program_data.feed_bytes(&bytes),
+        RecordedStream::CameraProgramXML => parsers.camera_program_xml.feed_bytes(&bytes),
+        RecordedStream::TimingProgram => {
+            match parsers.timing_program.feed_bytes(&bytes) {
+                Some(Ok(parsed)) => match parsed.into_timing_program_instruction() {
+                    Ok(inst) => comm_channel.take_in_command_from_timing_program(inst)?,
+                    Err(e_inst) => error!(
+                        "Replayed timing program data was not a timing program instruction: {}",
+                        e_inst
+                    ),
+                },
+                Some(Err(e)) => error!("Could not parse replayed timing program data: {}", e),
+                None => (),
+            }
+            None
+        }
+        RecordedStream::WindServer => {
+            match serde_json::from_slice::<WindMessageBroadcast>(&bytes) {
+                Ok(mes) => comm_channel.take_in_command_from_wind_server(mes)?,
+                Err(e) => error!("Could not parse replayed wind server message: {}", e),
+            }
+            None
+        }
+        RecordedStream::BibServer => {
+            match serde_json::from_slice::<MessageFromBibServer>(&bytes) {
+                Ok(mes) => comm_channel.take_in_command_from_bib_server(mes)?,
+                Err(e) => error!("Could not parse replayed bib server message: {}", e),
+            }
+            None
+        }
+    };
+
+    match camera_program_result {
+        Some(Ok(inst)) => comm_channel.take_in_command_from_camera_program(inst)?,
+        Some(Err(e)) => error!(
+            "Could not parse replayed data from camera program ({:?}): {}",
+            chunk.stream, e
+        ),
+        None => (),
+    }
+
+    Ok(())
+}
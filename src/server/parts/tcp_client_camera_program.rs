@@ -1,6 +1,7 @@
 use crate::args::Args;
 use crate::interface::ServerStateMachineServerStateReader;
 use crate::server::comm_channel::{ConnectionCheck, InstructionCommunicationChannel};
+use crate::server::session_recording::RecordedStream;
 use crate::server::xml_serial::{BufferedParserSerial, BufferedParserXML};
 use std::io::{self, Error, ErrorKind};
 use std::net::SocketAddr;
@@ -79,6 +80,10 @@ pub async fn tcp_client_camera_program(
                                 Ok(0) => continue,
                                 Ok(n) => {
                                     let bytes_from_timing_endpoint = &buf[..n];
+                                    comm_channel_timing.record_inbound_bytes(
+                                        RecordedStream::CameraProgramTiming,
+                                        bytes_from_timing_endpoint,
+                                    );
 
                                     match parser.feed_bytes(bytes_from_timing_endpoint) {
                                         Some(Ok(inst)) => {
@@ -191,6 +196,10 @@ pub async fn tcp_client_camera_program(
                                 Ok(0) => continue,
                                 Ok(n) => {
                                     let bytes_from_xml_endpoint = &buf[..n];
+                                    comm_channel_xml.record_inbound_bytes(
+                                        RecordedStream::CameraProgramXML,
+                                        bytes_from_xml_endpoint,
+                                    );
 
                                     match parser.feed_bytes(bytes_from_xml_endpoint) {
                                         Some(Ok(inst)) => {
@@ -300,6 +309,10 @@ pub async fn tcp_client_camera_program(
                                 Ok(0) => continue,
                                 Ok(n) => {
                                     let bytes_from_data_endpoint = &buf[..n];
+                                    comm_channel_data.record_inbound_bytes(
+                                        RecordedStream::CameraProgramData,
+                                        bytes_from_data_endpoint,
+                                    );
 
                                     match parser.feed_bytes(bytes_from_data_endpoint) {
                                         Some(Ok(inst)) => {
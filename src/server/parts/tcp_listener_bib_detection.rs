@@ -3,6 +3,7 @@ use crate::interface::ServerStateMachineServerStateReader;
 use crate::json::make_json_exchange_codec;
 use crate::server::bib_detection::{MessageFromBibServer, MessageToBibServer};
 use crate::server::comm_channel::InstructionCommunicationChannel;
+use crate::server::session_recording::RecordedStream;
 use futures::{SinkExt, StreamExt};
 use std::io::{self, Error, ErrorKind};
 use std::net::SocketAddr;
@@ -85,18 +86,25 @@ pub async fn tcp_listener_bib_detection(
                         .await
                         {
                             Ok(Some(read_result)) => match read_result {
-                                Ok(mess_broadcast) => match comm_channel_read
-                                    .take_in_command_from_bib_server(mess_broadcast)
-                                {
-                                    Ok(()) => trace!(
-                                        "Message from bib server taken into internal communication"
-                                    ),
-                                    Err(e) => {
-                                        error!("Bib server could not deposit message into internal comm channel: {}", e.to_string());
-                                        // problems with the internal comm channel (technically this is reason to crash on the spot, this is kind of not supported by the err architecture ins this case -> other places will shut down the program if this happens)
-                                        return Err(Error::new(ErrorKind::Other, e.to_string()));
+                                Ok(mess_broadcast) => {
+                                    comm_channel_read.record_inbound_message(
+                                        RecordedStream::BibServer,
+                                        &mess_broadcast,
+                                    );
+
+                                    match comm_channel_read
+                                        .take_in_command_from_bib_server(mess_broadcast)
+                                    {
+                                        Ok(()) => trace!(
+                                            "Message from bib server taken into internal communication"
+                                        ),
+                                        Err(e) => {
+                                            error!("Bib server could not deposit message into internal comm channel: {}", e.to_string());
+                                            // problems with the internal comm channel (technically this is reason to crash on the spot, this is kind of not supported by the err architecture ins this case -> other places will shut down the program if this happens)
+                                            return Err(Error::new(ErrorKind::Other, e.to_string()));
+                                        }
                                     }
-                                },
+                                }
                                 Err(e) => {
                                     error!("Error in bib server communication: {}", e.to_string());
                                     // will attempt to reconnect in next iteration
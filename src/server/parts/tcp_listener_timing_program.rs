@@ -6,6 +6,7 @@ use crate::nrbf::{generate_response_bytes, BufferedParser};
 use crate::server::comm_channel::{
     InstructionCommunicationChannel, PacketCommunicationChannel, PacketData,
 };
+use crate::server::session_recording::RecordedStream;
 use async_broadcast::RecvError;
 use std::io;
 use std::net::SocketAddr;
@@ -114,6 +115,9 @@ pub async fn tcp_listener_timing_program(
                                 }
                             };
 
+                            comm_channel_read
+                                .record_inbound_bytes(RecordedStream::TimingProgram, &buf[..n]);
+
                             // Decoding does NOT log anything. Consider doing so yourself depending on reasonability
                             match parser.feed_bytes(&buf[..n]) {
                                 Some(res) => match res {
@@ -2,6 +2,7 @@ use crate::args::Args;
 use crate::interface::ServerStateMachineServerStateReader;
 use crate::json::make_json_exchange_codec;
 use crate::server::comm_channel::InstructionCommunicationChannel;
+use crate::server::session_recording::RecordedStream;
 use crate::wind::format::{MessageToWindServer, WindMessageBroadcast};
 use futures::{SinkExt, StreamExt};
 use std::io::{self, Error, ErrorKind};
@@ -85,14 +86,21 @@ pub async fn tcp_listener_wind_server(
                         .await
                         {
                             Ok(Some(read_result)) => match read_result {
-                                Ok(mess_broadcast) => match comm_channel_read.take_in_command_from_wind_server(mess_broadcast) {
-                                    Ok(()) => trace!("Message from wind server taken into internal communication"),
-                                    Err(e) =>  {
-                                        error!("Wind server could not deposit message into internal comm channel: {}", e.to_string());
-                                        // problems with the internal comm channel (technically this is reason to crash on the spot, this is kind of not supported by the err architecture ins this case -> other places will shut down the program if this happens)
-                                        return Err(Error::new(ErrorKind::Other, e.to_string()));
+                                Ok(mess_broadcast) => {
+                                    comm_channel_read.record_inbound_message(
+                                        RecordedStream::WindServer,
+                                        &mess_broadcast,
+                                    );
+
+                                    match comm_channel_read.take_in_command_from_wind_server(mess_broadcast) {
+                                        Ok(()) => trace!("Message from wind server taken into internal communication"),
+                                        Err(e) =>  {
+                                            error!("Wind server could not deposit message into internal comm channel: {}", e.to_string());
+                                            // problems with the internal comm channel (technically this is reason to crash on the spot, this is kind of not supported by the err architecture ins this case -> other places will shut down the program if this happens)
+                                            return Err(Error::new(ErrorKind::Other, e.to_string()));
+                                        }
                                     }
-                                },
+                                }
                                 Err(e) => {
                                     error!("Error in wind server communication: {}", e.to_string());
                                     // will attempt to reconnect in next iteration
@@ -0,0 +1,163 @@
+use crate::args::{Args, TimingSourceBackend};
+use crate::interface::ServerStateMachineServerStateReader;
+use crate::server::comm_channel::InstructionCommunicationChannel;
+use crate::server::parts::lynx_folder_watcher::lynx_folder_watcher;
+use crate::server::parts::tcp_client_camera_program::tcp_client_camera_program;
+use futures::future::BoxFuture;
+use std::io;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::{atomic::AtomicBool, Arc};
+
+/// Everything a timing source gets handed to feed the server
+#[derive(Clone)]
+pub struct TimingSourceContext {
+    pub args: Args,
+    pub state_reader: ServerStateMachineServerStateReader,
+    pub comm_channel: InstructionCommunicationChannel,
+    pub shutdown_marker: Arc<AtomicBool>,
+}
+
+/// A photo finish or transponder system, that produces the same instructions the camera program does.
+/// The server state machine does not know which one is in use
+pub trait TimingSource: Send {
+    fn name(&self) -> &'static str;
+
+    /// Runs until the shutdown marker is set
+    fn run(self: Box<Self>, context: TimingSourceContext) -> BoxFuture<'static, io::Result<()>>;
+}
+
+/// The camera program with its three tcp endpoints (timing serial stream, data and xml)
+pub struct CameraProgramTimingSource {
+    timing_addr: SocketAddr,
+    data_addr: SocketAddr,
+    xml_addr: SocketAddr,
+}
+
+impl CameraProgramTimingSource {
+    fn from_args(args: &Args) -> Option<Self> {
+        let camera_program_ip = match &args.address_camera_program {
+            Some(ip) => ip,
+            None => {
+                info!("Not configured to connect to a camera program");
+                return None;
+            }
+        };
+
+        let timing_addr: SocketAddr =
+            format!("{}:{}", camera_program_ip, args.camera_exchange_timing_port)
+                .parse()
+                .expect("Invalid camera program address for timing");
+        let data_addr: SocketAddr =
+            format!("{}:{}", camera_program_ip, args.camera_exchange_data_port)
+                .parse()
+                .expect("Invalid camera program address for data");
+        let xml_addr: SocketAddr =
+            format!("{}:{}", camera_program_ip, args.camera_exchange_xml_port)
+                .parse()
+                .expect("Invalid camera program address for xml");
+        info!(
+            "Talking to the camera program on {}, {} and {}",
+            timing_addr, data_addr, xml_addr
+        );
+
+        Some(Self {
+            timing_addr,
+            data_addr,
+            xml_addr,
+        })
+    }
+}
+
+impl TimingSource for CameraProgramTimingSource {
+    fn name(&self) -> &'static str {
+        "camera program"
+    }
+
+    fn run(self: Box<Self>, context: TimingSourceContext) -> BoxFuture<'static, io::Result<()>> {
+        Box::pin(tcp_client_camera_program(
+            context.args,
+            context.state_reader,
+            context.comm_channel,
+            context.shutdown_marker,
+            Some(self.timing_addr),
+            Some(self.data_addr),
+            Some(self.xml_addr),
+        ))
+    }
+}
+
+/// FinishLynx, reading start lists and results from its exchange folder
+pub struct FinishLynxTimingSource {
+    folder: PathBuf,
+}
+
+impl FinishLynxTimingSource {
+    fn from_args(args: &Args) -> Option<Self> {
+        match &args.lynx_folder_path {
+            Some(folder) => {
+                info!("Watching the FinishLynx folder {}", folder);
+                Some(Self {
+                    folder: PathBuf::from(folder),
+                })
+            }
+            None => {
+                error!("FinishLynx is selected as timing source, but no lynx folder is configured (lynx_folder_path)");
+                None
+            }
+        }
+    }
+}
+
+impl TimingSource for FinishLynxTimingSource {
+    fn name(&self) -> &'static str {
+        "FinishLynx"
+    }
+
+    fn run(self: Box<Self>, context: TimingSourceContext) -> BoxFuture<'static, io::Result<()>> {
+        Box::pin(lynx_folder_watcher(
+            context.args,
+            context.state_reader,
+            context.comm_channel,
+            context.shutdown_marker,
+            self.folder,
+        ))
+    }
+}
+
+/// None, if the selected backend is not configured completely (or none is selected)
+pub fn timing_source_from_args(args: &Args) -> Option<Box<dyn TimingSource>> {
+    match args.timing_source {
+        TimingSourceBackend::CameraProgram => CameraProgramTimingSource::from_args(args)
+            .map(|source| Box::new(source) as Box<dyn TimingSource>),
+        TimingSourceBackend::FinishLynx => FinishLynxTimingSource::from_args(args)
+            .map(|source| Box::new(source) as Box<dyn TimingSource>),
+        TimingSourceBackend::None => {
+            info!("Not configured to use a timing source");
+            None
+        }
+    }
+}
+
+pub async fn run_timing_source(
+    source: Option<Box<dyn TimingSource>>,
+    context: TimingSourceContext,
+) -> io::Result<()> {
+    let source = match source {
+        Some(source) => source,
+        // nothing to listen to -> we can just die
+        None => return Ok(()),
+    };
+
+    let name = source.name();
+    match source.run(context).await {
+        Err(e) => {
+            error!("Error in the timing source ({})", name);
+            Err(e)
+        }
+        Ok(()) => {
+            info!("Timing source ({}) closed successfully", name);
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use super::export::meet_name;
+use super::results_export::{
+    csv_field, generate_meet_result_export, html_escape, ResultExportFormat, CSV_SEPARATOR,
+    HTML_STYLE,
+};
+use crate::{
+    database::{
+        get_field_event_attempts, DatabaseManager, DatabaseSerializable, DatabaseStaticState,
+    },
+    server::field_events::{
+        format_field_mark, rank_field_event, FieldAttemptResult, FieldEvent, FieldEventKind,
+    },
+    times::{wind_relevant_for_distance, WindLegality},
+};
+use chrono::{Local, NaiveDate};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct WindReportHeat {
+    pub name: String,
+    pub distance_meters: u32,
+    pub scheduled_start_time: String,
+    pub wind: Option<String>,
+    pub legality: WindLegality,
+    pub nr_results: usize,
+}
+
+/// Jumps are judged per attempt, so the report lists every competitor with a mark
+#[derive(Debug, Serialize)]
+pub struct WindReportJump {
+    pub event: String,
+    pub bib: u32,
+    pub first_name: String,
+    pub last_name: String,
+    pub best_mark: String,
+    pub wind: Option<String>,
+    pub legality: WindLegality,
+    pub best_legal_mark: Option<String>, // best attempt with a measured wind of at most +2.0
+}
+
+#[derive(Debug, Serialize)]
+pub struct WindReport {
+    pub name: String,
+    pub date: NaiveDate,
+    pub generated: String,
+    pub nr_legal: usize,
+    pub nr_wind_aided: usize,
+    pub nr_not_measured: usize,
+    pub heats: Vec<WindReportHeat>,
+    pub jumps: Vec<WindReportJump>,
+}
+
+fn jumps_for_report(manager: &DatabaseManager) -> Result<Vec<WindReportJump>, String> {
+    let mut events = FieldEvent::get_all_from_database(manager)
+        .map_err(|e| format!("Could not read field events from database: {}", e))?;
+    events.retain(|e| e.kind == FieldEventKind::Jump);
+
+    let mut jumps = Vec::new();
+    for event in events {
+        let attempts = get_field_event_attempts(event.id, manager)
+            .map_err(|e| format!("Could not read attempts of {}: {}", event.name, e))?;
+
+        for standing in rank_field_event(&event, &attempts) {
+            let Some(best_mark) = standing.best_mark else {
+                continue;
+            };
+            let best_legal_mark = standing
+                .attempts
+                .iter()
+                .flatten()
+                .filter(|a| a.wind.as_ref().is_some_and(|w| !w.is_wind_aided()))
+                .filter_map(|a| match a.result {
+                    FieldAttemptResult::Mark(cm) => Some(cm),
+                    _ => None,
+                })
+                .max();
+
+            jumps.push(WindReportJump {
+                event: event.name.clone(),
+                bib: standing.competitor.bib,
+                first_name: standing.competitor.first_name,
+                last_name: standing.competitor.last_name,
+                best_mark: format_field_mark(best_mark),
+                wind: standing.best_mark_wind.as_ref().map(|w| w.to_string()),
+                legality: WindLegality::evaluate(standing.best_mark_wind.as_ref(), true),
+                best_legal_mark: best_legal_mark.map(format_field_mark),
+            });
+        }
+    }
+
+    Ok(jumps)
+}
+
+/// All wind relevant heats with results and all jumps of the meet
+pub fn generate_wind_report(
+    dbss: &DatabaseStaticState,
+    manager: &DatabaseManager,
+) -> Result<WindReport, String> {
+    let heats: Vec<WindReportHeat> = generate_meet_result_export(dbss, manager)?
+        .heats
+        .into_iter()
+        .filter(|h| wind_relevant_for_distance(h.distance_meters))
+        .map(|h| WindReportHeat {
+            nr_results: h.results.iter().filter(|r| r.runtime.is_some()).count(),
+            name: h.name,
+            distance_meters: h.distance_meters,
+            scheduled_start_time: h.scheduled_start_time,
+            wind: h.wind,
+            legality: h.wind_legality,
+        })
+        .collect();
+    let jumps = jumps_for_report(manager)?;
+
+    let count = |legality: WindLegality| {
+        heats.iter().filter(|h| h.legality == legality).count()
+            + jumps.iter().filter(|j| j.legality == legality).count()
+    };
+
+    Ok(WindReport {
+        name: meet_name(dbss),
+        date: dbss.date,
+        generated: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        nr_legal: count(WindLegality::Legal),
+        nr_wind_aided: count(WindLegality::WindAided),
+        nr_not_measured: count(WindLegality::NotMeasured),
+        heats,
+        jumps,
+    })
+}
+
+fn legality_text(legality: &WindLegality) -> &'static str {
+    match legality {
+        WindLegality::Legal => "legal",
+        WindLegality::WindAided => "wind aided",
+        WindLegality::NotMeasured => "not measured",
+        WindLegality::NotRelevant => "",
+    }
+}
+
+fn render_csv(report: &WindReport) -> String {
+    let mut out = [
+        "event",
+        "bib",
+        "name",
+        "performance",
+        "wind",
+        "legality",
+        "best_legal_performance",
+    ]
+    .join(&CSV_SEPARATOR.to_string());
+    out.push('\n');
+
+    let mut push_line = |fields: [String; 7]| {
+        out.push_str(
+            &fields
+                .iter()
+                .map(|f| csv_field(f))
+                .collect::<Vec<String>>()
+                .join(&CSV_SEPARATOR.to_string()),
+        );
+        out.push('\n');
+    };
+
+    for heat in &report.heats {
+        push_line([
+            heat.name.clone(),
+            String::new(),
+            String::new(),
+            format!("{} results", heat.nr_results),
+            heat.wind.clone().unwrap_or_default(),
+            legality_text(&heat.legality).to_string(),
+            String::new(),
+        ]);
+    }
+    for jump in &report.jumps {
+        push_line([
+            jump.event.clone(),
+            jump.bib.to_string(),
+            format!("{} {}", jump.first_name, jump.last_name),
+            jump.best_mark.clone(),
+            jump.wind.clone().unwrap_or_default(),
+            legality_text(&jump.legality).to_string(),
+            jump.best_legal_mark.clone().unwrap_or_default(),
+        ]);
+    }
+
+    out
+}
+
+fn render_html(report: &WindReport) -> String {
+    let title = format!("{} - Wind report", report.name);
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{}\n</style>\n</head>\n<body>\n",
+        html_escape(&title),
+        HTML_STYLE
+    );
+    out.push_str(&format!(
+        "<h1>{}</h1>\n<p class=\"meta\">{}, generated {}</p>\n<p>{} legal, {} wind aided (w), {} without wind reading</p>\n",
+        html_escape(&title),
+        report.date,
+        html_escape(&report.generated),
+        report.nr_legal,
+        report.nr_wind_aided,
+        report.nr_not_measured
+    ));
+
+    if !report.heats.is_empty() {
+        out.push_str("<div class=\"heat\">\n<h2>Heats</h2>\n<table>\n<tr><th>Heat</th><th>Start</th><th>Results</th><th>Wind</th><th></th></tr>\n");
+        for heat in &report.heats {
+            out.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td class=\"num\">{}</td><td class=\"num\">{} {}</td><td>{}</td></tr>\n",
+                html_escape(&heat.name),
+                html_escape(&heat.scheduled_start_time),
+                heat.nr_results,
+                html_escape(&heat.wind.clone().unwrap_or_default()),
+                heat.legality.marker(),
+                legality_text(&heat.legality)
+            ));
+        }
+        out.push_str("</table>\n</div>\n");
+    }
+
+    if !report.jumps.is_empty() {
+        out.push_str("<div class=\"heat\">\n<h2>Jumps</h2>\n<table>\n<tr><th>Event</th><th>Bib</th><th>Name</th><th>Best</th><th>Wind</th><th></th><th>Best legal</th></tr>\n");
+        for jump in &report.jumps {
+            out.push_str(&format!(
+                "<tr><td>{}</td><td class=\"num\">{}</td><td>{} {}</td><td class=\"num\">{}</td><td class=\"num\">{} {}</td><td>{}</td><td class=\"num\">{}</td></tr>\n",
+                html_escape(&jump.event),
+                jump.bib,
+                html_escape(&jump.first_name),
+                html_escape(&jump.last_name),
+                jump.best_mark,
+                html_escape(&jump.wind.clone().unwrap_or_default()),
+                jump.legality.marker(),
+                legality_text(&jump.legality),
+                jump.best_legal_mark.clone().unwrap_or_default()
+            ));
+        }
+        out.push_str("</table>\n</div>\n");
+    }
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+pub fn render_wind_report(
+    report: &WindReport,
+    format: ResultExportFormat,
+) -> Result<String, String> {
+    match format {
+        ResultExportFormat::Csv => Ok(render_csv(report)),
+        ResultExportFormat::Json => serde_json::to_string_pretty(report)
+            .map_err(|e| format!("Could not serialize wind report: {}", e)),
+        ResultExportFormat::Html => Ok(render_html(report)),
+    }
+}
+
+/// file name without extension
+pub fn wind_report_file_name(dbss: &DatabaseStaticState) -> String {
+    format!("jta-dwa-{}-wind-report", dbss.date)
+}
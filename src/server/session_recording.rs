@@ -0,0 +1,138 @@
+use crate::args::Args;
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::sync::Arc;
+use std::thread;
+use std::time::Instant;
+use tokio::sync::mpsc;
+
+/// The inbound streams that can be recorded and replayed
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordedStream {
+    CameraProgramTiming,
+    CameraProgramData,
+    CameraProgramXML,
+    TimingProgram,
+    WindServer,
+    BibServer,
+}
+
+/// One line in a session file
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RecordedChunk {
+    /// microseconds since the recording was started
+    pub offset_us: u64,
+    pub stream: RecordedStream,
+    /// hex encoded raw bytes
+    pub data: String,
+}
+impl RecordedChunk {
+    pub fn bytes(&self) -> Result<Vec<u8>, String> {
+        hex::decode(&self.data).map_err(|e| format!("Recorded chunk is not valid hex: {}", e))
+    }
+}
+
+struct SessionRecorderInner {
+    started: Instant,
+    chunk_sender: mpsc::UnboundedSender<RecordedChunk>,
+}
+
+/// Appends every inbound chunk to the session file (if one was configured). Cheap to clone, no-op if disabled.
+/// The file is written from a dedicated thread, so recording never blocks the async tasks that receive the streams
+#[derive(Clone)]
+pub struct SessionRecorder {
+    inner: Option<Arc<SessionRecorderInner>>,
+}
+impl SessionRecorder {
+    pub fn new(args: &Args) -> Self {
+        let path = match &args.record_session_file {
+            Some(path) => path,
+            None => return Self { inner: None },
+        };
+
+        let file = match OpenOptions::new().create(true).append(true).open(path) {
+            Ok(file) => file,
+            Err(e) => {
+                error!("Could not open session file {} for recording: {}", path, e);
+                return Self { inner: None };
+            }
+        };
+
+        // unbounded, a recording with holes would be useless for replaying
+        let (chunk_sender, chunk_receiver) = mpsc::unbounded_channel::<RecordedChunk>();
+        let writer = BufWriter::new(file);
+        if let Err(e) = thread::Builder::new()
+            .name("session-recorder".into())
+            .spawn(move || run_session_writer(writer, chunk_receiver))
+        {
+            error!("Could not start the session recording writer: {}", e);
+            return Self { inner: None };
+        }
+
+        info!("Recording all inbound streams into session file {}", path);
+        Self {
+            inner: Some(Arc::new(SessionRecorderInner {
+                started: Instant::now(),
+                chunk_sender,
+            })),
+        }
+    }
+
+    pub fn record(&self, stream: RecordedStream, bytes: &[u8]) -> () {
+        let inner = match &self.inner {
+            Some(inner) => inner,
+            None => return,
+        };
+
+        let chunk = RecordedChunk {
+            offset_us: inner.started.elapsed().as_micros() as u64,
+            stream,
+            data: hex::encode(bytes),
+        };
+        if inner.chunk_sender.send(chunk).is_err() {
+            error!("Session recording writer stopped, chunk is lost");
+        }
+    }
+}
+
+fn run_session_writer(
+    mut writer: BufWriter<File>,
+    mut chunk_receiver: mpsc::UnboundedReceiver<RecordedChunk>,
+) {
+    while let Some(chunk) = chunk_receiver.blocking_recv() {
+        let line = match serde_json::to_string(&chunk) {
+            Ok(line) => line,
+            Err(e) => {
+                error!("Could not serialize recorded chunk: {}", e);
+                continue;
+            }
+        };
+
+        // flush every line, so a crash on race day still leaves a usable session file
+        if let Err(e) = writeln!(writer, "{}", line).and_then(|_| writer.flush()) {
+            error!("Could not write to session file: {}", e);
+        }
+    }
+
+    debug!("Session recording writer stopped");
+}
+
+pub fn read_session_file(path: &str) -> Result<Vec<RecordedChunk>, String> {
+    let file =
+        File::open(path).map_err(|e| format!("Could not open session file {}: {}", path, e))?;
+
+    let mut chunks = Vec::new();
+    for (line_nr, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.map_err(|e| format!("Could not read session file {}: {}", path, e))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let chunk: RecordedChunk = serde_json::from_str(&line)
+            .map_err(|e| format!("Invalid chunk in line {}: {}", line_nr + 1, e))?;
+        chunks.push(chunk);
+    }
+
+    return Ok(chunks);
+}
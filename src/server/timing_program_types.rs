@@ -0,0 +1,259 @@
+//! Payloads of the layouts the timing program sends (see nrbf.rs for the decoding).
+//! The timing program never sends structured data, only values for the elements of its display layouts
+use crate::helpers::uuids_from_seed;
+use crate::server::camera_program_types::{
+    DisqualificationReason, HeatCompetitor, HeatCompetitorResult, HeatResult, HeatStartList,
+};
+use crate::server::lynx_files::distance_from_event_name;
+use crate::times::{DayTime, RaceTime, RaceWind};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// One SetProperty packet: a property of an element in the current layout changes
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TimingProgramProperty {
+    pub element_name: String,
+    pub property_name: String,
+    pub value: TimingProgramPropertyValue,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum TimingProgramPropertyValue {
+    Null,
+    Text(String),
+    Bool(bool),
+    Number(f64), // also enums like the slideshow transition
+    Duration(Duration),
+    Table(TimingProgramTable),
+    Unknown(String), // class name of a value we can not interpret
+}
+
+/// The rows of a list layout (start list, results), cells as the timing program formatted them
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TimingProgramTable {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<Option<String>>>,
+}
+impl TimingProgramTable {
+    pub fn cell<'a>(&self, row: &'a [Option<String>], column: &str) -> Option<&'a str> {
+        let index = self.columns.iter().position(|c| c == column)?;
+        row.get(index)
+            .and_then(|c| c.as_deref())
+            .map(|c| c.trim())
+            .filter(|c| !c.is_empty())
+    }
+}
+
+/// Settings of ours that the timing program can change
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimingProgramSetting {
+    SlideshowDuration(u32), // ms
+}
+
+fn find_property<'a>(
+    properties: &'a [TimingProgramProperty],
+    element_name: &str,
+    property_name: &str,
+) -> Option<&'a TimingProgramPropertyValue> {
+    // later packets overwrite earlier ones
+    properties
+        .iter()
+        .rev()
+        .find(|p| p.element_name == element_name && p.property_name == property_name)
+        .map(|p| &p.value)
+}
+
+fn text_property<'a>(
+    properties: &'a [TimingProgramProperty],
+    element_name: &str,
+) -> Option<&'a str> {
+    match find_property(properties, element_name, "Text") {
+        Some(TimingProgramPropertyValue::Text(text)) if !text.trim().is_empty() => {
+            Some(text.trim())
+        }
+        _ => None,
+    }
+}
+
+fn list_table(properties: &[TimingProgramProperty]) -> Option<&TimingProgramTable> {
+    match find_property(properties, "List", "Data") {
+        Some(TimingProgramPropertyValue::Table(table)) => Some(table),
+        _ => None,
+    }
+}
+
+/// The timing program formats with the german decimal comma
+fn parse_result_time(input: &str) -> Result<RaceTime, String> {
+    RaceTime::parse_from_string(&input.replace(',', "."))
+}
+
+/// Only properties that are settings themselves, nothing is guessed from the shown content.
+/// Of the settings the timing program sends (see the captured packets), only the slideshow interval has a
+/// counterpart here. Its slideshow transition mode and repeat flag have none, everything else is display content
+pub fn settings_from_properties(properties: &[TimingProgramProperty]) -> Vec<TimingProgramSetting> {
+    let mut settings = Vec::new();
+
+    if let Some(TimingProgramPropertyValue::Duration(interval)) =
+        find_property(properties, "Slideshow", "ChangeInterval")
+    {
+        settings.push(TimingProgramSetting::SlideshowDuration(
+            interval.as_millis() as u32,
+        ));
+    }
+
+    settings
+}
+
+fn parse_wind(properties: &[TimingProgramProperty]) -> Option<RaceWind> {
+    match find_property(properties, "HasWind", "Value") {
+        Some(TimingProgramPropertyValue::Bool(true)) => (),
+        _ => return None,
+    }
+
+    text_property(properties, "Wind")?
+        .trim_end_matches("m/s")
+        .trim()
+        .trim_start_matches('+')
+        .replace(',', ".")
+        .parse::<f32>()
+        .ok()
+        .map(RaceWind::parse_from_f32)
+}
+
+fn bib_of_row(table: &TimingProgramTable, row: &[Option<String>]) -> Option<u32> {
+    table.cell(row, "Bib").and_then(|b| b.parse().ok())
+}
+
+/// The timing program has no heat ids, the heat of a result list is the one with its title.
+/// Heats with the same name (or none matching it) are told apart by the bibs in the list
+pub fn matching_heat<'a>(
+    properties: &[TimingProgramProperty],
+    start_lists: &'a [HeatStartList],
+) -> Option<&'a HeatStartList> {
+    let table = list_table(properties)?;
+    let title = text_property(properties, "EventTitle").or(text_property(properties, "Header1"));
+    let bibs: Vec<u32> = table
+        .rows
+        .iter()
+        .filter_map(|row| bib_of_row(table, row))
+        .collect();
+
+    let bibs_in_heat = |heat: &HeatStartList| {
+        bibs.iter()
+            .filter(|bib| heat.competitors.iter().any(|c| c.bib == **bib))
+            .count()
+    };
+    let named: Vec<&HeatStartList> = start_lists
+        .iter()
+        .filter(|heat| title.is_some_and(|t| heat.name.trim().eq_ignore_ascii_case(t)))
+        .collect();
+
+    match named.as_slice() {
+        [heat] => Some(heat),
+        [] => start_lists
+            .iter()
+            .map(|heat| (heat, bibs_in_heat(heat)))
+            // without the title, all the listed athletes have to be in the heat
+            .filter(|(_, count)| *count > 0 && *count == bibs.len())
+            .max_by_key(|(_, count)| *count)
+            .map(|(heat, _)| heat),
+        several => several
+            .iter()
+            .copied()
+            .map(|heat| (heat, bibs_in_heat(heat)))
+            .filter(|(_, count)| *count > 0)
+            .max_by_key(|(_, count)| *count)
+            .map(|(heat, _)| heat),
+    }
+}
+
+/// For a result list that belongs to none of our heats (e.g. running without the camera program), a heat
+/// made up from the list itself: it is shown, but not stored. Without a start it is put to midnight
+pub fn heat_from_properties(properties: &[TimingProgramProperty]) -> HeatStartList {
+    let name = text_property(properties, "EventTitle")
+        .or(text_property(properties, "Header1"))
+        .unwrap_or("")
+        .to_string();
+
+    HeatStartList {
+        id: uuids_from_seed(&format!("timing_program_result_{}", name), 1)[0],
+        nr: 0,
+        session_nr: 0,
+        distance_meters: distance_from_event_name(&name),
+        scheduled_start_time: DayTime::from_hms_opt(0, 0, 0).unwrap(),
+        name,
+        competitors: Vec::new(),
+    }
+}
+
+/// The result list layout as the result of the given heat, for when there is no camera program to send the real one.
+/// Only the displayed (rounded) times are known, the finish times are counted from the given start time
+pub fn heat_result_from_properties(
+    properties: &[TimingProgramProperty],
+    heat: &HeatStartList,
+    start_time: DayTime,
+) -> Option<HeatResult> {
+    let table = list_table(properties)?;
+
+    let mut competitors_evaluated = Vec::new();
+    let mut competitors_left_to_evaluate = Vec::new();
+
+    for (index, row) in table.rows.iter().enumerate() {
+        let text = |column: &str| table.cell(row, column).unwrap_or("").to_string();
+        let bib = bib_of_row(table, row).unwrap_or(0);
+
+        // the start list knows the lane and the ids, the list only what is shown
+        let mut competitor = match heat.competitors.iter().find(|c| bib != 0 && c.bib == bib) {
+            Some(known) => known.clone(),
+            None => HeatCompetitor {
+                id: index.to_string(),
+                lane: 0,
+                bib,
+                class: text("Class"),
+                last_name: text("Lastname"),
+                first_name: text("Firstname"),
+                nation: text("Nation"),
+                club: text("Club"),
+                gender: text("Gender"),
+                disqualified: None,
+            },
+        };
+
+        let rank = table
+            .cell(row, "Rank")
+            .and_then(|r| r.trim_end_matches('.').parse::<u32>().ok())
+            .filter(|r| *r > 0);
+        let result = table.cell(row, "Result");
+
+        match (rank, result.map(parse_result_time)) {
+            (Some(rank), Some(Ok(runtime))) => {
+                let finish_time = start_time
+                    .add_duration(Duration::from_micros(runtime.into_ten_thousands() * 100));
+                competitors_evaluated.push(HeatCompetitorResult {
+                    competitor,
+                    distance: heat.distance_meters,
+                    rank,
+                    runtime: runtime.clone(),
+                    runtime_full_precision: runtime,
+                    finish_time,
+                });
+            }
+            (_, Some(Err(_))) => {
+                // a status like "DNF" in the result column
+                competitor.disqualified = result.map(DisqualificationReason::parse_from_string);
+                competitors_left_to_evaluate.push(competitor);
+            }
+            _ => competitors_left_to_evaluate.push(competitor),
+        }
+    }
+
+    Some(HeatResult {
+        id: heat.id,
+        name: heat.name.clone(),
+        distance_meters: heat.distance_meters,
+        start_time,
+        wind: parse_wind(properties),
+        competitors_evaluated,
+        competitors_left_to_evaluate,
+    })
+}
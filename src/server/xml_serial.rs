@@ -115,7 +115,7 @@ impl<'de> Deserialize<'de> for HeatRaceTime {
 }
 
 impl DisqualificationReason {
-    fn parse_from_string(input: &str) -> Self {
+    pub(super) fn parse_from_string(input: &str) -> Self {
         match input {
             "DISQ." | "DISQ" | "disqualifiziert" | "DSQ" | "DQ" => Self::Disqualified,
             "Nicht im Ziel" | "aufgegeben" | "gestürzt" | "DNF" | "surrender" | "fall" => {
@@ -426,6 +426,55 @@ impl RaceWind {
             fraction_part: fraction_part,
         }
     }
+
+    /// Signed value in 0.1 m/s steps, +1.2 -> 12
+    pub fn to_tenths(&self) -> i32 {
+        let tenths = self.whole_number_part as i32 * 10 + self.fraction_part as i32;
+        if self.back_wind {
+            tenths
+        } else {
+            -tenths
+        }
+    }
+
+    /// More than +2.0 m/s back wind, the performance can not count for records
+    pub fn is_wind_aided(&self) -> bool {
+        self.to_tenths() > WIND_LEGAL_LIMIT_TENTHS
+    }
+}
+
+const WIND_LEGAL_LIMIT_TENTHS: i32 = 20;
+/// Wind only matters for the sprints up to 200m (and the horizontal jumps)
+const WIND_RELEVANT_MAX_DISTANCE_METERS: u32 = 200;
+
+pub fn wind_relevant_for_distance(distance_meters: u32) -> bool {
+    distance_meters <= WIND_RELEVANT_MAX_DISTANCE_METERS
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, TypescriptSerializable)]
+pub enum WindLegality {
+    Legal,
+    WindAided,
+    NotMeasured, // wind would be relevant, but there is no reading
+    NotRelevant,
+}
+impl WindLegality {
+    pub fn evaluate(wind: Option<&RaceWind>, wind_relevant: bool) -> Self {
+        match (wind_relevant, wind) {
+            (false, _) => WindLegality::NotRelevant,
+            (true, None) => WindLegality::NotMeasured,
+            (true, Some(wind)) if wind.is_wind_aided() => WindLegality::WindAided,
+            (true, Some(_)) => WindLegality::Legal,
+        }
+    }
+
+    /// The "w" behind wind aided performances in result lists
+    pub fn marker(&self) -> &'static str {
+        match self {
+            WindLegality::WindAided => "w",
+            _ => "",
+        }
+    }
 }
 impl Display for RaceWind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
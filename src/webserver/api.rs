@@ -0,0 +1,355 @@
+//! REST api for third party tools. Commands are translated into the websocket messages, so they run through
+//! the same handlers of the server state machine. Reads go to the database directly, the answers of the
+//! websocket handlers are sent to every connected web control and would change what the operators see.
+//! See /RestApi.md for the documentation.
+use crate::client::QrCodePayload;
+use crate::database::{
+    get_all_athletes_meta_data, get_database_static_state, get_heat_data, get_log_limited,
+    get_wind_readings, DatabaseError, DatabaseManager, DatabaseSerializable,
+};
+use crate::server::camera_program_types::{HeatMeta, HeatStartList};
+use crate::server::comm_channel::InstructionCommunicationChannel;
+use crate::times::DayTime;
+use crate::webserver::auth::{WebControlRole, WebControlSessions};
+use crate::webserver::interface::{
+    MessageFromWebControl, MessageToWebControl, PDFBackground, WindValueRequestDateContainer,
+};
+use crate::webserver::routes::SessionTokenQuery;
+use actix_web::{http::header, web, HttpRequest, HttpResponse};
+use async_broadcast::RecvError;
+use serde::{Deserialize, Serialize};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use uuid::Uuid;
+
+/// How long a GET waits for the server state machine to answer
+const API_ANSWER_TIMEOUT: Duration = Duration::from_secs(5);
+const API_DEFAULT_NUMBER_OF_LOGS: u32 = 50;
+
+type CommChannelData = web::Data<Arc<InstructionCommunicationChannel>>;
+type SessionsData = web::Data<Arc<WebControlSessions>>;
+type DatabaseManagerData = web::Data<DatabaseManager>;
+
+#[derive(Deserialize)]
+pub struct ApiLogsQuery {
+    count: Option<u32>,
+}
+
+#[derive(Deserialize)]
+pub struct ApiFreeText {
+    text: String,
+}
+
+/// Token from "Authorization: Bearer <token>" or the "token" query parameter
+fn token_from_request(req: &HttpRequest) -> Option<String> {
+    let from_header = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(|token| token.trim().to_string());
+
+    from_header.or_else(|| {
+        web::Query::<SessionTokenQuery>::from_query(req.query_string())
+            .ok()
+            .and_then(|query| query.into_inner().token)
+    })
+}
+
+/// Checked here to answer with a proper status code, the state machine checks the role again on its own
+fn authorize(
+    req: &HttpRequest,
+    sessions: &WebControlSessions,
+    required_role: WebControlRole,
+) -> Result<WebControlRole, HttpResponse> {
+    let session = token_from_request(req)
+        .and_then(|token| sessions.session(&token))
+        .ok_or_else(|| HttpResponse::Unauthorized().body("Session not valid"))?;
+
+    if session.role < required_role {
+        return Err(HttpResponse::Forbidden().body(format!(
+            "Role {:?} is not allowed to do this (requires {:?})",
+            session.role, required_role
+        )));
+    }
+
+    Ok(session.role)
+}
+
+/// Fire and forget, like the websocket commands
+async fn forward(
+    comm_channel: &InstructionCommunicationChannel,
+    sessions: &WebControlSessions,
+    req: &HttpRequest,
+    msg: MessageFromWebControl,
+) -> HttpResponse {
+    let role = match authorize(req, sessions, msg.required_role()) {
+        Ok(role) => role,
+        Err(response) => return response,
+    };
+
+    match comm_channel.take_in_command_from_web_control(role, msg) {
+        Ok(()) => HttpResponse::Accepted().finish(),
+        Err(e) => {
+            error!("Api could not forward into internal message queue: {}", e);
+            HttpResponse::InternalServerError().body(e)
+        }
+    }
+}
+
+/// Sends the request message and waits for the first answer that `extract` accepts.
+/// Only for requests whose answer every web control shows the same way anyway (like the connection state)
+async fn request_answer<T: Serialize>(
+    comm_channel: &InstructionCommunicationChannel,
+    sessions: &WebControlSessions,
+    req: &HttpRequest,
+    msg: MessageFromWebControl,
+    extract: impl Fn(MessageToWebControl) -> Option<T>,
+) -> HttpResponse {
+    let role = match authorize(req, sessions, msg.required_role()) {
+        Ok(role) => role,
+        Err(response) => return response,
+    };
+
+    // subscribe before sending, so the answer can not be missed
+    let mut receiver = comm_channel.web_control_receiver();
+    if let Err(e) = comm_channel.take_in_command_from_web_control(role, msg) {
+        error!("Api could not forward into internal message queue: {}", e);
+        return HttpResponse::InternalServerError().body(e);
+    }
+
+    let deadline = Instant::now() + API_ANSWER_TIMEOUT;
+    while Instant::now() < deadline {
+        match receiver.wait_for_some_data().await {
+            Err(_) => continue,
+            Ok(Err(RecvError::Overflowed(_))) => continue,
+            Ok(Err(e)) => {
+                error!("Error reading from internal channel: {}", e.to_string());
+                break;
+            }
+            Ok(Ok(MessageToWebControl::StaticConfigurationNotInitialized)) => {
+                return HttpResponse::ServiceUnavailable()
+                    .body("Database is not initialized, do so over the web control");
+            }
+            Ok(Ok(answer)) => {
+                if let Some(data) = extract(answer) {
+                    return HttpResponse::Ok().json(data);
+                }
+            }
+        }
+    }
+
+    HttpResponse::GatewayTimeout()
+        .body("The server did not answer in time (see the server logs for the reason)")
+}
+
+/// Read only access for the Viewer role, without going through the server state machine
+fn reject_reader(
+    manager: &DatabaseManager,
+    sessions: &WebControlSessions,
+    req: &HttpRequest,
+) -> Option<HttpResponse> {
+    if let Err(response) = authorize(req, sessions, WebControlRole::Viewer) {
+        return Some(response);
+    }
+
+    if get_database_static_state(manager).is_err() {
+        return Some(
+            HttpResponse::ServiceUnavailable()
+                .body("Database is not initialized, do so over the web control"),
+        );
+    }
+
+    None
+}
+
+fn database_answer<T: Serialize>(
+    manager: &DatabaseManager,
+    sessions: &WebControlSessions,
+    req: &HttpRequest,
+    read: impl FnOnce(&DatabaseManager) -> Result<T, DatabaseError>,
+) -> HttpResponse {
+    if let Some(response) = reject_reader(manager, sessions, req) {
+        return response;
+    }
+
+    match read(manager) {
+        Ok(data) => HttpResponse::Ok().json(data),
+        Err(e) => {
+            error!("Api could not read from the database: {}", e);
+            HttpResponse::InternalServerError().body(e.error_message)
+        }
+    }
+}
+
+pub async fn api_heats_route(
+    database_manager: DatabaseManagerData,
+    sessions: SessionsData,
+    req: HttpRequest,
+) -> HttpResponse {
+    database_answer(&database_manager, &sessions, &req, |manager| {
+        HeatStartList::get_all_from_database(manager)
+            .map(|heats| heats.into_iter().map(HeatMeta::from).collect::<Vec<_>>())
+    })
+}
+
+pub async fn api_heat_route(
+    database_manager: DatabaseManagerData,
+    sessions: SessionsData,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let id = match Uuid::parse_str(&path.into_inner()) {
+        Ok(id) => id,
+        Err(_) => return HttpResponse::BadRequest().body("Heat id is not a valid uuid"),
+    };
+
+    if let Some(response) = reject_reader(&database_manager, &sessions, &req) {
+        return response;
+    }
+    if HeatStartList::get_from_database_by_id(id, &database_manager).is_err() {
+        return HttpResponse::NotFound().body("Unknown heat");
+    }
+
+    database_answer(&database_manager, &sessions, &req, |manager| {
+        get_heat_data(id, manager)
+    })
+}
+
+pub async fn api_athletes_route(
+    database_manager: DatabaseManagerData,
+    sessions: SessionsData,
+    req: HttpRequest,
+) -> HttpResponse {
+    database_answer(
+        &database_manager,
+        &sessions,
+        &req,
+        get_all_athletes_meta_data,
+    )
+}
+
+pub async fn api_wind_route(
+    database_manager: DatabaseManagerData,
+    sessions: SessionsData,
+    req: HttpRequest,
+    query: web::Query<WindValueRequestDateContainer>,
+) -> HttpResponse {
+    let range = query.into_inner();
+    database_answer(&database_manager, &sessions, &req, |manager| {
+        get_wind_readings(range.from, range.to, manager)
+    })
+}
+
+pub async fn api_logs_route(
+    database_manager: DatabaseManagerData,
+    sessions: SessionsData,
+    req: HttpRequest,
+    query: web::Query<ApiLogsQuery>,
+) -> HttpResponse {
+    let count = query.count.unwrap_or(API_DEFAULT_NUMBER_OF_LOGS);
+    database_answer(&database_manager, &sessions, &req, |manager| {
+        get_log_limited(Some(count), manager)
+    })
+}
+
+pub async fn api_connection_state_route(
+    comm_channel: CommChannelData,
+    sessions: SessionsData,
+    req: HttpRequest,
+) -> HttpResponse {
+    request_answer(
+        &comm_channel,
+        &sessions,
+        &req,
+        MessageFromWebControl::RequestConnectionStates,
+        |answer| match answer {
+            MessageToWebControl::ConnectionState(state) => Some(state),
+            _ => None,
+        },
+    )
+    .await
+}
+
+pub async fn api_free_text_route(
+    comm_channel: CommChannelData,
+    sessions: SessionsData,
+    req: HttpRequest,
+    body: web::Json<ApiFreeText>,
+) -> HttpResponse {
+    forward(
+        &comm_channel,
+        &sessions,
+        &req,
+        MessageFromWebControl::FreeText(body.into_inner().text),
+    )
+    .await
+}
+
+pub async fn api_qr_code_route(
+    comm_channel: CommChannelData,
+    sessions: SessionsData,
+    req: HttpRequest,
+    body: web::Json<QrCodePayload>,
+) -> HttpResponse {
+    forward(
+        &comm_channel,
+        &sessions,
+        &req,
+        MessageFromWebControl::DisplayQrCode(body.into_inner()),
+    )
+    .await
+}
+
+/// /api/mode/{mode} with the same modes as the buttons of the web control
+pub async fn api_mode_route(
+    comm_channel: CommChannelData,
+    sessions: SessionsData,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let msg = match path.into_inner().as_str() {
+        "idle" => MessageFromWebControl::Idle,
+        "advertisements" => MessageFromWebControl::Advertisements,
+        "timing" => MessageFromWebControl::Timing,
+        "start_list" => MessageFromWebControl::StartList,
+        "result_list" => MessageFromWebControl::ResultList,
+        "switch" => MessageFromWebControl::SwitchMode,
+        _ => return HttpResponse::NotFound().body("Unknown mode"),
+    };
+
+    forward(&comm_channel, &sessions, &req, msg).await
+}
+
+/// The web control uploads the rendered pdf backgrounds here, they do not fit into a websocket frame
+pub async fn api_pdf_background_route(
+    comm_channel: CommChannelData,
+    sessions: SessionsData,
+    req: HttpRequest,
+    body: web::Json<PDFBackground>,
+) -> HttpResponse {
+    forward(
+        &comm_channel,
+        &sessions,
+        &req,
+        MessageFromWebControl::StorePDFBackground(body.into_inner()),
+    )
+    .await
+}
+
+pub async fn api_clock_route(
+    comm_channel: CommChannelData,
+    sessions: SessionsData,
+    req: HttpRequest,
+    body: web::Json<DayTime>,
+) -> HttpResponse {
+    forward(
+        &comm_channel,
+        &sessions,
+        &req,
+        MessageFromWebControl::Clock(body.into_inner()),
+    )
+    .await
+}
@@ -0,0 +1,244 @@
+#[cfg(test)]
+mod password_tests;
+
+use crate::args::Args;
+use argon2::{
+    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use hex::encode;
+use rand_chacha::rand_core::{OsRng, RngCore};
+use rust_to_ts_types::TypescriptSerializable;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Sessions run out if they are not used for this long
+const SESSION_IDLE_TIMEOUT: Duration = Duration::from_secs(12 * 60 * 60);
+const SESSION_TOKEN_BYTES: usize = 32;
+/// Name of the user that is created from `webcontrol_password`, if no users are configured
+const DEFAULT_ADMIN_NAME: &str = "admin";
+
+/// Ordered from least to most privileged, so a role may do everything the lower roles may do
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    TypescriptSerializable,
+)]
+pub enum WebControlRole {
+    /// Can only request data
+    Viewer,
+    /// Can run the meet (timing, display clients, athletes, ...)
+    Operator,
+    /// Can additionally initialize and reset the database and delete athletes
+    Admin,
+}
+impl WebControlRole {
+    fn parse(role: &str) -> Option<Self> {
+        match role.to_lowercase().as_str() {
+            "viewer" => Some(Self::Viewer),
+            "operator" => Some(Self::Operator),
+            "admin" => Some(Self::Admin),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, TypescriptSerializable)]
+pub struct WebControlLoginRequest {
+    pub name: String,
+    pub password: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct WebControlSessionInfo {
+    pub name: String,
+    pub role: WebControlRole,
+}
+
+#[derive(Debug, Serialize, Deserialize, TypescriptSerializable)]
+pub struct WebControlLoginResponse {
+    pub token: String,
+    pub session: WebControlSessionInfo,
+}
+
+/// Argon2id with the default parameters, stored in the PHC string format ("$argon2id$v=19$m=...$salt$hash")
+pub fn hash_password(password: &str) -> String {
+    let salt = SaltString::generate(&mut OsRng);
+
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .expect("the default argon2 parameters accept every password and generated salt")
+        .to_string()
+}
+
+/// The hash brings its own algorithm, parameters and salt. The comparison is constant time
+fn verify_password(password: &str, stored_hash: &str) -> bool {
+    match PasswordHash::new(stored_hash) {
+        Ok(hash) => Argon2::default()
+            .verify_password(password.as_bytes(), &hash)
+            .is_ok(),
+        Err(e) => {
+            error!("Stored web control password hash is not readable: {}", e);
+            false
+        }
+    }
+}
+
+struct WebControlUser {
+    name: String,
+    role: WebControlRole,
+    password_hash: String,
+}
+
+/// Users come from `webcontrol_user` ("name:role:hash").
+/// Without any configured user, `webcontrol_password` is the password of a single admin user.
+fn users_from_args(args: &Args) -> Vec<WebControlUser> {
+    let mut users = Vec::new();
+
+    for entry in &args.webcontrol_user {
+        let mut parts = entry.splitn(3, ':');
+        match (
+            parts.next(),
+            parts.next().and_then(WebControlRole::parse),
+            parts.next(),
+        ) {
+            (Some(name), Some(role), Some(password_hash)) if !name.is_empty() => {
+                if users.iter().any(|u: &WebControlUser| u.name == name) {
+                    error!(
+                        "Web control user '{}' is configured twice, ignoring the second entry",
+                        name
+                    );
+                    continue;
+                }
+                users.push(WebControlUser {
+                    name: name.to_string(),
+                    role,
+                    password_hash: password_hash.to_string(),
+                });
+            }
+            _ => {
+                error!(
+                    "Web control user '{}' is not of the form name:viewer|operator|admin:hash, ignoring it",
+                    entry
+                );
+            }
+        }
+    }
+
+    if users.is_empty() {
+        info!(
+            "No web control users configured, using '{}' with the webcontrol_password",
+            DEFAULT_ADMIN_NAME
+        );
+        users.push(WebControlUser {
+            name: DEFAULT_ADMIN_NAME.to_string(),
+            role: WebControlRole::Admin,
+            password_hash: hash_password(&args.webcontrol_password),
+        });
+    }
+
+    users
+}
+
+struct WebControlSession {
+    info: WebControlSessionInfo,
+    last_used: Instant,
+}
+
+pub struct WebControlSessions {
+    users: Vec<WebControlUser>,
+    /// Checked for unknown names, so they take as long as wrong passwords
+    unknown_user_hash: String,
+    sessions: Mutex<HashMap<String, WebControlSession>>,
+}
+impl WebControlSessions {
+    pub fn new(args: &Args) -> Self {
+        Self {
+            users: users_from_args(args),
+            unknown_user_hash: hash_password(""),
+            sessions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn login(&self, request: &WebControlLoginRequest) -> Option<WebControlLoginResponse> {
+        let user = self.users.iter().find(|u| u.name == request.name);
+
+        // always hash once, so unknown names take as long as wrong passwords
+        let password_matches = match user {
+            Some(user) => verify_password(&request.password, &user.password_hash),
+            None => {
+                let _ = verify_password(&request.password, &self.unknown_user_hash);
+                false
+            }
+        };
+        let user = user.filter(|_| password_matches)?;
+
+        let mut token_bytes = [0u8; SESSION_TOKEN_BYTES];
+        OsRng.fill_bytes(&mut token_bytes);
+        let token = encode(token_bytes);
+
+        let info = WebControlSessionInfo {
+            name: user.name.clone(),
+            role: user.role,
+        };
+
+        let mut sessions = self.sessions.lock().unwrap_or_else(|e| e.into_inner());
+        sessions.retain(|_, s| s.last_used.elapsed() < SESSION_IDLE_TIMEOUT);
+        sessions.insert(
+            token.clone(),
+            WebControlSession {
+                info: info.clone(),
+                last_used: Instant::now(),
+            },
+        );
+
+        Some(WebControlLoginResponse {
+            token,
+            session: info,
+        })
+    }
+
+    /// Looks up a session and keeps it alive
+    pub fn session(&self, token: &str) -> Option<WebControlSessionInfo> {
+        let mut sessions = self.sessions.lock().unwrap_or_else(|e| e.into_inner());
+
+        match sessions.get_mut(token) {
+            Some(session) if session.last_used.elapsed() < SESSION_IDLE_TIMEOUT => {
+                session.last_used = Instant::now();
+                Some(session.info.clone())
+            }
+            Some(_) => {
+                sessions.remove(token);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub fn logout(&self, token: &str) {
+        let mut sessions = self.sessions.lock().unwrap_or_else(|e| e.into_inner());
+        sessions.remove(token);
+    }
+}
+
+/// Key mode helper to generate the hash part of a `webcontrol_user` entry
+pub fn print_webcontrol_password_hash(args: &Args) -> bool {
+    match &args.hash_webcontrol_password {
+        Some(password) => {
+            info!("Web control password hash: {}", hash_password(password));
+            true
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,54 @@
+//! Password hashes of web control users and the login with them
+
+use super::{
+    hash_password, verify_password, WebControlLoginRequest, WebControlRole, WebControlSessions,
+};
+use crate::args::Args;
+use clap::Parser;
+
+fn login(sessions: &WebControlSessions, name: &str, password: &str) -> Option<WebControlRole> {
+    sessions
+        .login(&WebControlLoginRequest {
+            name: String::from(name),
+            password: String::from(password),
+        })
+        .map(|response| response.session.role)
+}
+
+#[test]
+fn hash_is_salted_argon2() {
+    let first = hash_password("correct horse");
+    let second = hash_password("correct horse");
+
+    assert!(first.starts_with("$argon2id$"));
+    assert_ne!(first, second);
+    assert!(verify_password("correct horse", &first));
+    assert!(verify_password("correct horse", &second));
+    assert!(!verify_password("wrong horse", &first));
+}
+
+#[test]
+fn unreadable_hash_never_matches() {
+    assert!(!verify_password("", ""));
+    assert!(!verify_password("secret", "00ff$00ff"));
+}
+
+#[test]
+fn configured_users_log_in_with_their_role() {
+    let user = format!("speaker:viewer:{}", hash_password("microphone"));
+    let args = Args::parse_from([
+        "jta-display-wall-adapter",
+        "server",
+        "--webcontrol-user",
+        user.as_str(),
+    ]);
+    let sessions = WebControlSessions::new(&args);
+
+    assert_eq!(
+        login(&sessions, "speaker", "microphone"),
+        Some(WebControlRole::Viewer)
+    );
+    assert_eq!(login(&sessions, "speaker", "wrong"), None);
+    // the built-in admin only exists without configured users
+    assert_eq!(login(&sessions, "admin", "microphone"), None);
+}
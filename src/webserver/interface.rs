@@ -1,17 +1,22 @@
 use crate::{
-    client::{frametime::FrametimeReport, TimingSettings},
+    client::{frametime::FrametimeReport, QrCodePayload, TickerMessage, TimingSettings},
     database::{DatabaseStaticState, PermanentlyStoredDataset},
     productkey::ProductKey,
     server::{
         bib_detection::{BibDataPoint, BibEntryModeData, BibEquivalence, DisplayEntry},
         camera_program_types::{
-            Athlete, AthleteWithMetadata, CompetitorEvaluated, HeatAssignment, HeatData,
-            HeatFinish, HeatIntermediate, HeatMeta, HeatResult, HeatStart, HeatStartList, HeatWind,
+            Athlete, AthleteWithMetadata, CompetitionEvent, CompetitorEvaluated, HeatAssignment,
+            HeatData, HeatFinish, HeatIntermediate, HeatMeta, HeatResult, HeatStart, HeatStartList,
+            HeatWind,
         },
+        display_clients::{DisplayClientConnection, DisplayClientUpdate},
+        field_events::{FieldEvent, FieldEventAttempt, FieldEventData},
     },
     times::{DayTime, RaceTime},
+    webserver::auth::WebControlRole,
     wind::format::WindMeasurement,
 };
+use base64::{prelude::BASE64_STANDARD, Engine};
 use chrono::NaiveDateTime;
 use rust_to_ts_types::TypescriptSerializable;
 use serde::{Deserialize, Serialize};
@@ -23,6 +28,7 @@ pub enum MessageFromWebControl {
     Idle,
     Advertisements,
     FreeText(String),
+    DisplayQrCode(QrCodePayload),
     RequestDisplayClientState,
     SwitchMode,
     GetHeats,
@@ -39,7 +45,10 @@ pub enum MessageFromWebControl {
     InitStaticDatabaseState(DatabaseStaticState),
     RequestStaticDatabaseState,
     ExportDataToFile,
+    ExportResultsToFile(Option<Uuid>), // None exports the whole meet
+    ExportPDFsToFile,
     CreateAthlete(Athlete),
+    ImportAthletes(Vec<Athlete>),
     DeleteAthlete(Uuid),
     CreateHeatAssignment(HeatAssignment),
     DeleteHeatAssignment(i32),
@@ -47,12 +56,28 @@ pub enum MessageFromWebControl {
     StorePDFConfigurationSetting(PDFConfigurationSetting),
     DeletePDFConfigurationSetting(Uuid),
     RequestPDFConfigurationSettings,
+    StorePDFBackground(PDFBackground), // too big for a websocket frame, sent to /api/pdf_background
+    DeletePDFBackground(PDFSettingFor),
+    StoreCompetitionEvent(CompetitionEvent),
+    DeleteCompetitionEvent(Uuid),
+    RequestCompetitionEvents,
+    StoreTickerMessage(TickerMessage),
+    DeleteTickerMessage(Uuid),
+    RequestTickerMessages,
+    StoreFieldEvent(FieldEvent),
+    DeleteFieldEvent(Uuid),
+    RequestFieldEvents,
+    StoreFieldEventAttempt(FieldEventAttempt),
+    DeleteFieldEventAttempt(FieldEventAttempt),
+    ShowFieldEvent(Uuid),
     DeleteCompetitorEvaluated(DayTime), // to target the correct HeatCompetitorResult, as here are no ids
     SendDebugDisplayCommand(DisplayEntry),
     RequestDevMode,
-    RequestPassword,
     RequestLicense,
     RequestConnectionStates,
+    UpdateDisplayClient(DisplayClientUpdate),
+    SelectDisplayClientPreview(String),
+    CallRoom(String), // name of the display client
     SelectHeatForBibMode(Uuid),
     RequestBibEntryModeData,
     SendHeatDataToDisplay(Uuid),
@@ -70,8 +95,51 @@ pub enum MessageFromWebControl {
     DevSendWind(HeatWind),
     DevRequestMainHeatStartList,
 }
+impl MessageFromWebControl {
+    /// The message type without its content (which may hold athlete data), to log it
+    pub fn type_name(&self) -> String {
+        serde_json::to_value(self)
+            .ok()
+            .and_then(|value| value.get("type")?.as_str().map(String::from))
+            .unwrap_or_else(|| String::from("unknown"))
+    }
 
-#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+    /// Least privileged role that may send this message
+    pub fn required_role(&self) -> WebControlRole {
+        match self {
+            MessageFromWebControl::RequestDisplayClientState
+            | MessageFromWebControl::GetHeats
+            | MessageFromWebControl::GetMainHeat
+            | MessageFromWebControl::GetLogs(_)
+            | MessageFromWebControl::RequestTimingSettings
+            | MessageFromWebControl::RequestWindValues(_)
+            | MessageFromWebControl::RequestStaticDatabaseState
+            | MessageFromWebControl::RequestAthletes
+            | MessageFromWebControl::RequestPDFConfigurationSettings
+            | MessageFromWebControl::RequestCompetitionEvents
+            | MessageFromWebControl::RequestTickerMessages
+            | MessageFromWebControl::RequestFieldEvents
+            | MessageFromWebControl::RequestDevMode
+            | MessageFromWebControl::RequestLicense
+            | MessageFromWebControl::RequestConnectionStates
+            | MessageFromWebControl::RequestBibEntryModeData => WebControlRole::Viewer,
+            MessageFromWebControl::InitStaticDatabaseState(_)
+            | MessageFromWebControl::DeleteAthlete(_)
+            | MessageFromWebControl::DevReset
+            | MessageFromWebControl::DevStartRace(_)
+            | MessageFromWebControl::DevSendStartList(_)
+            | MessageFromWebControl::DevSendIntermediateSignal(_)
+            | MessageFromWebControl::DevSendFinishSignal(_)
+            | MessageFromWebControl::DevSendEvaluated(_)
+            | MessageFromWebControl::DevSendResultList(_)
+            | MessageFromWebControl::DevSendWind(_)
+            | MessageFromWebControl::DevRequestMainHeatStartList => WebControlRole::Admin,
+            _ => WebControlRole::Operator,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, TypescriptSerializable)]
 pub enum PDFSettingFor {
     Bib,
     Certificate,
@@ -90,6 +158,25 @@ pub struct PDFConfigurationSetting {
     pub content: PDFConfigurationContent,
 }
 
+/// The page the browser prints the settings onto, the uploaded pdf as the web client rendered it
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct PDFBackground {
+    pub setting_for: PDFSettingFor,
+    pub data_url: String, // "data:image/png;base64,..."
+}
+impl PDFBackground {
+    pub fn png(&self) -> Result<Vec<u8>, String> {
+        let encoded = self
+            .data_url
+            .strip_prefix("data:image/png;base64,")
+            .ok_or_else(|| String::from("Background is no png data url"))?;
+
+        BASE64_STANDARD
+            .decode(encoded)
+            .map_err(|e| format!("Background is no valid base64: {}", e))
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
 #[serde(tag = "type", content = "data")]
 pub enum PDFConfigurationContent {
@@ -102,6 +189,25 @@ pub enum PDFConfigurationContent {
     },
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct AthleteImportConflict {
+    pub row: u32, // 1-based index into the imported list
+    pub bib: u32,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct AthleteImportReport {
+    pub imported: u32,
+    pub conflicts: Vec<AthleteImportConflict>,
+}
+
+/// Everything the last meet export had to leave out
+#[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
+pub struct MeetExportReport {
+    pub problems: Vec<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, TypescriptSerializable)]
 pub struct WindValueRequestDateContainer {
     pub from: NaiveDateTime,
@@ -142,6 +248,8 @@ pub struct ConnectionState {
     pub display_passthrough_address: String,
     pub timing_program_is_connected: bool,
     pub listening_to_timing_program: bool,
+    pub display_clients: Vec<DisplayClientConnection>,
+    pub preview_display_client: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, TypescriptSerializable)]
@@ -157,12 +265,16 @@ pub enum MessageToWebControl {
     WindMeasurements(Vec<WindMeasurement>),
     CurrentDisplayFrame(Vec<u8>), // gets handled extra and sent as binary data
     AthletesData(Vec<AthleteWithMetadata>),
+    AthleteImportReport(AthleteImportReport),
+    MeetExportReport(MeetExportReport),
     PDFConfigurationSettingsData(Vec<PDFConfigurationSetting>),
+    CompetitionEventsData(Vec<CompetitionEvent>),
+    TickerMessages(Vec<TickerMessage>),
+    FieldEventsData(Vec<FieldEventData>),
     MainHeat(HeatData),
     VersionMismatch((String, String)),
     FrametimeReport(FrametimeReport),
     DevModeStatus(bool),
-    Password(String),
     Licensed(Option<ProductKey>),
     StaticConfigurationNotInitialized,
     ConnectionState(ConnectionState),
@@ -0,0 +1,64 @@
+use crate::server::comm_channel::InstructionCommunicationChannel;
+use crate::server::live_feed::LiveFeedEvent;
+use actix_web::{web, HttpResponse};
+use async_broadcast::RecvError;
+use futures::{stream, StreamExt};
+use std::sync::Arc;
+
+fn sse_frame(event: &LiveFeedEvent) -> Option<web::Bytes> {
+    match serde_json::to_string(event) {
+        Ok(json) => Some(web::Bytes::from(format!("data: {}\n\n", json))),
+        Err(e) => {
+            error!(
+                "Serde could not serealize live feed event. This should not happen: {}",
+                e
+            );
+            None
+        }
+    }
+}
+
+/// Public server-sent event stream, starts with a snapshot of the current heat
+pub async fn live_events_route(
+    comm_channel_data: web::Data<Arc<InstructionCommunicationChannel>>,
+) -> HttpResponse {
+    // subscribe before taking the snapshot, so no event in between is lost
+    let receiver = comm_channel_data.live_feed_receiver();
+    let snapshot = LiveFeedEvent::Snapshot(comm_channel_data.live_feed_snapshot());
+
+    let initial = stream::iter(sse_frame(&snapshot).map(Ok::<_, actix_web::Error>));
+    let events = stream::unfold(receiver, |mut receiver| async move {
+        loop {
+            match receiver.wait_for_some_data().await {
+                Err(_) => {
+                    // comment line, keeps proxies and browsers from closing the idle connection
+                    return Some((Ok(web::Bytes::from_static(b": keepalive\n\n")), receiver));
+                }
+                Ok(Err(RecvError::Overflowed(n))) => {
+                    warn!("Live feed listener was too slow, skipped {} events", n);
+                    continue;
+                }
+                Ok(Err(e)) => {
+                    error!("Error reading from internal channel: {}", e.to_string());
+                    return None;
+                }
+                Ok(Ok(event)) => match sse_frame(&event) {
+                    Some(frame) => return Some((Ok(frame), receiver)),
+                    None => continue,
+                },
+            }
+        }
+    });
+
+    HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header(("Cache-Control", "no-cache"))
+        .streaming(initial.chain(events))
+}
+
+/// Public json snapshot of the current heat, for overlays that poll instead of streaming
+pub async fn live_state_route(
+    comm_channel_data: web::Data<Arc<InstructionCommunicationChannel>>,
+) -> HttpResponse {
+    HttpResponse::Ok().json(comm_channel_data.live_feed_snapshot())
+}
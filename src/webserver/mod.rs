@@ -1,4 +1,8 @@
+mod api;
+mod auth;
 mod interface;
+mod live;
+mod public_results;
 mod routes;
 mod static_files;
 mod web;
@@ -11,8 +15,12 @@ pub fn get_local_ip() -> String {
     }
 }
 
+pub use auth::{
+    print_webcontrol_password_hash, WebControlLoginRequest, WebControlLoginResponse, WebControlRole,
+};
 pub use interface::{
-    ConnectionState, DisplayClientState, MessageFromWebControl, MessageToWebControl,
-    PDFConfigurationSetting,
+    AthleteImportConflict, AthleteImportReport, ConnectionState, DisplayClientState,
+    MeetExportReport, MessageFromWebControl, MessageToWebControl, PDFBackground,
+    PDFConfigurationContent, PDFConfigurationSetting, PDFSettingFor,
 };
 pub use web::{webserver, HttpServerStateManager, Server};
@@ -0,0 +1,189 @@
+use crate::database::{
+    get_database_static_state, get_heat_data, DatabaseManager, DatabaseSerializable,
+    DatabaseStaticState,
+};
+use crate::server::camera_program_types::{HeatResult, HeatStart};
+use crate::server::export_functions::{
+    heat_result_export, html_escape, meet_name, start_lists_in_schedule_order,
+};
+use actix_web::{web, HttpResponse};
+use uuid::Uuid;
+
+/// Spectators keep the page open on their phones, so it reloads itself
+const PUBLIC_REFRESH_SECONDS: u32 = 20;
+pub const PUBLIC_RESULTS_PATH: &str = "/public";
+
+const PUBLIC_STYLE: &str = "body { font-family: sans-serif; margin: 0; padding: 0.6em; font-size: 16px; background: #fafafa; }
+h1 { font-size: 1.25em; margin: 0; }
+h2 { font-size: 1.1em; margin: 0.2em 0; }
+a { color: inherit; }
+.meta { color: #555; font-size: 0.85em; margin: 0.2em 0 0.8em 0; }
+.heat { display: block; text-decoration: none; background: #fff; border-radius: 6px; padding: 0.5em 0.7em; margin-bottom: 0.5em; box-shadow: 0 1px 2px #0002; }
+.status { float: right; font-size: 0.8em; color: #555; }
+.status.final { color: #2e7d32; }
+.status.started { color: #c62828; }
+table { border-collapse: collapse; width: 100%; background: #fff; }
+th, td { border-bottom: 1px solid #ddd; padding: 0.35em 0.3em; text-align: left; }
+td.num { text-align: right; white-space: nowrap; }";
+
+fn page(title: &str, body: &str) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .insert_header(("Cache-Control", "no-cache"))
+        .body(format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n<meta http-equiv=\"refresh\" content=\"{}\">\n<title>{}</title>\n<style>\n{}\n</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+            PUBLIC_REFRESH_SECONDS,
+            html_escape(title),
+            PUBLIC_STYLE,
+            body
+        ))
+}
+
+fn not_available(reason: &str) -> HttpResponse {
+    HttpResponse::ServiceUnavailable()
+        .content_type("text/html; charset=utf-8")
+        .body(format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n<meta http-equiv=\"refresh\" content=\"{}\">\n<title>Results</title>\n</head>\n<body>\n<p>{}</p>\n</body>\n</html>\n",
+            PUBLIC_REFRESH_SECONDS,
+            html_escape(reason)
+        ))
+}
+
+fn static_state(manager: &DatabaseManager) -> Result<DatabaseStaticState, HttpResponse> {
+    get_database_static_state(manager)
+        .map_err(|_| not_available("Results are not available yet, please try again later."))
+}
+
+fn meet_header(dbss: &DatabaseStaticState) -> String {
+    format!(
+        "<h1>{}</h1>\n<p class=\"meta\">{}, {} {}</p>\n",
+        html_escape(&meet_name(dbss)),
+        dbss.date,
+        html_escape(&dbss.meet_city),
+        html_escape(&dbss.meet_location)
+    )
+}
+
+/// /public with all heats of the meet
+pub async fn public_overview_route(database_manager: web::Data<DatabaseManager>) -> HttpResponse {
+    let manager = database_manager.get_ref();
+    let dbss = match static_state(manager) {
+        Ok(dbss) => dbss,
+        Err(response) => return response,
+    };
+    let start_lists = match start_lists_in_schedule_order(manager) {
+        Ok(start_lists) => start_lists,
+        Err(e) => {
+            error!("Could not generate public results overview: {}", e);
+            return not_available("Results are not available at the moment.");
+        }
+    };
+
+    let mut body = meet_header(&dbss);
+    if start_lists.is_empty() {
+        body.push_str("<p>No heats yet.</p>\n");
+    }
+    for start_list in start_lists {
+        // database error here basically always is not found error -> heat is not that far yet
+        let (status, status_class) =
+            if HeatResult::get_from_database_by_id(start_list.id, manager).is_ok() {
+                ("Results", "final")
+            } else if HeatStart::get_from_database_by_id(start_list.id, manager).is_ok() {
+                ("Started", "started")
+            } else {
+                ("Start list", "")
+            };
+
+        body.push_str(&format!(
+            "<a class=\"heat\" href=\"{}/heat/{}\"><span class=\"status {}\">{}</span><b>{}</b><br><span class=\"meta\">{} &middot; {}m</span></a>\n",
+            PUBLIC_RESULTS_PATH,
+            start_list.id,
+            status_class,
+            status,
+            html_escape(&start_list.name),
+            html_escape(&start_list.scheduled_start_time.to_string()),
+            start_list.distance_meters
+        ));
+    }
+
+    page(&meet_name(&dbss), &body)
+}
+
+/// /public/heat/{heat_id} with the results, or the start list as long as nobody is evaluated
+pub async fn public_heat_route(
+    database_manager: web::Data<DatabaseManager>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let manager = database_manager.get_ref();
+    let dbss = match static_state(manager) {
+        Ok(dbss) => dbss,
+        Err(response) => return response,
+    };
+    let heat_id = match Uuid::parse_str(&path.into_inner()) {
+        Ok(id) => id,
+        Err(_) => return HttpResponse::NotFound().body("Unknown heat"),
+    };
+    let data = match get_heat_data(heat_id, manager) {
+        Ok(data) => data,
+        Err(_) => return HttpResponse::NotFound().body("Unknown heat"),
+    };
+
+    let mut body = meet_header(&dbss);
+    body.push_str(&format!(
+        "<p><a href=\"{}\">&larr; All heats</a></p>\n",
+        PUBLIC_RESULTS_PATH
+    ));
+
+    let export = heat_result_export(&data);
+    body.push_str(&format!(
+        "<h2>{}</h2>\n<p class=\"meta\">{}m &middot; {}{}</p>\n",
+        html_escape(&export.name),
+        export.distance_meters,
+        html_escape(&export.scheduled_start_time),
+        match export.wind_text() {
+            Some(w) => format!(" &middot; Wind: {}", html_escape(&w)),
+            None => String::new(),
+        }
+    ));
+
+    if data.result.is_none() && data.evaluations.is_none() {
+        body.push_str("<table>\n<tr><th>Lane</th><th>Bib</th><th>Name</th></tr>\n");
+        let mut competitors = data.start_list.competitors.clone();
+        competitors.sort_by_key(|c| c.lane);
+        for competitor in competitors {
+            body.push_str(&format!(
+                "<tr><td class=\"num\">{}</td><td class=\"num\">{}</td><td>{} {}<br><span class=\"meta\">{}</span></td></tr>\n",
+                competitor.lane,
+                competitor.bib,
+                html_escape(&competitor.first_name),
+                html_escape(&competitor.last_name),
+                html_escape(&competitor.club)
+            ));
+        }
+        body.push_str("</table>\n");
+    } else {
+        if data.result.is_none() {
+            body.push_str("<p class=\"meta\">Unofficial, the heat is still being evaluated.</p>\n");
+        }
+        body.push_str("<table>\n<tr><th>Rank</th><th>Name</th><th>Time</th></tr>\n");
+        for row in &export.results {
+            body.push_str(&format!(
+                "<tr><td class=\"num\">{}</td><td>{} {}<br><span class=\"meta\">{} &middot; Bib {}</span></td><td class=\"num\">{}</td></tr>\n",
+                row.rank.map(|r| format!("{}.", r)).unwrap_or_default(),
+                html_escape(&row.first_name),
+                html_escape(&row.last_name),
+                html_escape(&row.club),
+                row.bib,
+                html_escape(
+                    &row.disqualification
+                        .clone()
+                        .or(row.runtime.clone())
+                        .unwrap_or_default()
+                ),
+            ));
+        }
+        body.push_str("</table>\n");
+    }
+
+    page(&format!("{} - {}", export.name, meet_name(&dbss)), &body)
+}
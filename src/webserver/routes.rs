@@ -1,17 +1,92 @@
+use crate::database::{get_database_static_state, DatabaseManager};
 use crate::server::comm_channel::InstructionCommunicationChannel;
+use crate::server::export_functions::{
+    generate_heat_result_export, generate_meet_result_export, generate_wind_report,
+    render_result_export, render_wind_report, result_export_file_name, wind_report_file_name,
+    ResultExportFormat,
+};
+use crate::webserver::auth::{WebControlLoginRequest, WebControlSessions};
 use crate::webserver::interface::MessageFromWebControl;
-use actix_web::{web, HttpRequest, Responder};
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use actix_ws::Message;
 use futures::StreamExt;
+use serde::Deserialize;
 use std::{sync::Arc, time::Duration};
+use uuid::Uuid;
 
 use super::MessageToWebControl;
 
+#[derive(Deserialize)]
+pub struct SessionTokenQuery {
+    pub token: Option<String>,
+}
+
+pub async fn login_route(
+    sessions: web::Data<Arc<WebControlSessions>>,
+    request: web::Json<WebControlLoginRequest>,
+) -> impl Responder {
+    let request = request.into_inner();
+    let name = request.name.clone();
+
+    // hashing is slow on purpose, so keep it off the async workers
+    let sessions = Arc::clone(sessions.get_ref());
+    match web::block(move || sessions.login(&request)).await {
+        Ok(Some(login)) => {
+            info!("Web control user '{}' logged in", name);
+            HttpResponse::Ok().json(login)
+        }
+        Ok(None) => {
+            warn!("Failed web control login for user '{}'", name);
+            HttpResponse::Unauthorized().body("Wrong user name or password")
+        }
+        Err(e) => {
+            error!("Could not check web control login: {}", e);
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
+
+pub async fn logout_route(
+    sessions: web::Data<Arc<WebControlSessions>>,
+    query: web::Query<SessionTokenQuery>,
+) -> impl Responder {
+    if let Some(token) = &query.token {
+        sessions.logout(token);
+    }
+
+    HttpResponse::Ok().finish()
+}
+
+/// Lets the web control check if a stored token is still valid
+pub async fn session_route(
+    sessions: web::Data<Arc<WebControlSessions>>,
+    query: web::Query<SessionTokenQuery>,
+) -> impl Responder {
+    match query
+        .token
+        .as_ref()
+        .and_then(|token| sessions.session(token))
+    {
+        Some(info) => HttpResponse::Ok().json(info),
+        None => HttpResponse::Unauthorized().body("Session not valid"),
+    }
+}
+
 pub async fn ws_route(
     comm_channel_data: web::Data<Arc<InstructionCommunicationChannel>>,
+    sessions: web::Data<Arc<WebControlSessions>>,
+    query: web::Query<SessionTokenQuery>,
     req: HttpRequest,
     body: web::Payload,
-) -> actix_web::Result<impl Responder> {
+) -> actix_web::Result<HttpResponse> {
+    let token = match &query.token {
+        Some(token) if sessions.session(token).is_some() => token.clone(),
+        _ => {
+            warn!("Web control tried to connect without a valid session");
+            return Ok(HttpResponse::Unauthorized().body("Session not valid"));
+        }
+    };
+
     let (response, mut session, mut msg_stream) = actix_ws::handle(&req, body)?;
 
     // HEARTBEAT TASK
@@ -112,8 +187,18 @@ pub async fn ws_route(
                         }
                     };
 
+                    // look up the session for every message, so logouts and timeouts take effect immediately
+                    let role = match sessions.session(&token) {
+                        Some(info) => info.role,
+                        None => {
+                            warn!("Web control session ended, closing websocket");
+                            break;
+                        }
+                    };
+
                     trace!("Websocket received message: {:?}", msg_parsed);
-                    match comm_channel_data_write.take_in_command_from_web_control(msg_parsed) {
+                    match comm_channel_data_write.take_in_command_from_web_control(role, msg_parsed)
+                    {
                         Ok(()) => trace!("Websocket message forwarded into internal communication"),
                         Err(e) => {
                             error!("Websocket could not forward into internal message queue. That one should be always empty though. Not recoverable error: {}",e);
@@ -130,3 +215,124 @@ pub async fn ws_route(
 
     Ok(response)
 }
+
+/// /results/{format}, the results of the whole meet
+pub async fn meet_results_route(
+    database_manager: web::Data<DatabaseManager>,
+    path: web::Path<String>,
+) -> impl Responder {
+    results_response(database_manager.get_ref(), &path.into_inner(), None)
+}
+
+/// /results/{format}/{heat_id}, the results of a single heat
+pub async fn heat_results_route(
+    database_manager: web::Data<DatabaseManager>,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let (format_string, heat_id_string) = path.into_inner();
+    results_response(
+        database_manager.get_ref(),
+        &format_string,
+        Some(heat_id_string),
+    )
+}
+
+fn results_response(
+    manager: &DatabaseManager,
+    format_string: &str,
+    heat_id_string: Option<String>,
+) -> HttpResponse {
+    let format = match ResultExportFormat::parse(format_string) {
+        Some(f) => f,
+        None => return HttpResponse::NotFound().body("Unknown export format"),
+    };
+    let heat_id = match heat_id_string {
+        Some(id) => match Uuid::parse_str(&id) {
+            Ok(id) => Some(id),
+            Err(_) => return HttpResponse::BadRequest().body("Heat id is not a valid uuid"),
+        },
+        None => None,
+    };
+
+    let dbss = match get_database_static_state(manager) {
+        Ok(dbss) => dbss,
+        Err((_, e)) => {
+            return HttpResponse::ServiceUnavailable()
+                .body(format!("Static configuration not available: {}", e))
+        }
+    };
+    let export = match heat_id {
+        Some(id) => generate_heat_result_export(&dbss, id, manager),
+        None => generate_meet_result_export(&dbss, manager),
+    };
+
+    export_response(
+        export.and_then(|e| render_result_export(&e, format)),
+        format,
+        &result_export_file_name(&dbss, heat_id),
+        "results export",
+    )
+}
+
+/// /results/wind_report/{format}, legal and wind aided results of the whole meet
+pub async fn wind_report_route(
+    database_manager: web::Data<DatabaseManager>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let format = match ResultExportFormat::parse(&path.into_inner()) {
+        Some(f) => f,
+        None => return HttpResponse::NotFound().body("Unknown export format"),
+    };
+
+    let manager = database_manager.get_ref();
+    let dbss = match get_database_static_state(manager) {
+        Ok(dbss) => dbss,
+        Err((_, e)) => {
+            return HttpResponse::ServiceUnavailable()
+                .body(format!("Static configuration not available: {}", e))
+        }
+    };
+
+    export_response(
+        generate_wind_report(&dbss, manager).and_then(|r| render_wind_report(&r, format)),
+        format,
+        &wind_report_file_name(&dbss),
+        "wind report",
+    )
+}
+
+/// The rendered export as a download, file_name without extension
+fn export_response(
+    rendered: Result<String, String>,
+    format: ResultExportFormat,
+    file_name: &str,
+    what: &str,
+) -> HttpResponse {
+    match rendered {
+        Ok(body) => {
+            // html gets shown in the browser, so it can be printed right away
+            let disposition = if format == ResultExportFormat::Html {
+                "inline"
+            } else {
+                "attachment"
+            };
+
+            HttpResponse::Ok()
+                .content_type(format.content_type())
+                .insert_header((
+                    "Content-Disposition",
+                    format!(
+                        "{}; filename=\"{}.{}\"",
+                        disposition,
+                        file_name,
+                        format.file_extension()
+                    ),
+                ))
+                .body(body)
+        }
+        Err(e) => {
+            error!("Could not generate {}: {}", what, e);
+            HttpResponse::InternalServerError().body(e)
+        }
+    }
+}
@@ -1,36 +1,65 @@
+use crate::args::Args;
+use crate::database::DatabaseManager;
 use crate::server::comm_channel::InstructionCommunicationChannel;
-use crate::webserver::routes::ws_route;
+use crate::webserver::api::{
+    api_athletes_route, api_clock_route, api_connection_state_route, api_free_text_route,
+    api_heat_route, api_heats_route, api_logs_route, api_mode_route, api_pdf_background_route,
+    api_qr_code_route, api_wind_route,
+};
+use crate::webserver::auth::WebControlSessions;
+use crate::webserver::live::{live_events_route, live_state_route};
+use crate::webserver::public_results::{
+    public_heat_route, public_overview_route, PUBLIC_RESULTS_PATH,
+};
+use crate::webserver::routes::{
+    heat_results_route, login_route, logout_route, meet_results_route, session_route,
+    wind_report_route, ws_route,
+};
 use crate::webserver::static_files;
 use actix_cors::Cors;
 pub use actix_web::dev::Server;
 use actix_web::dev::ServerHandle;
+use actix_web::http::header;
 use actix_web::middleware::Logger;
 use actix_web::{web, App, HttpServer};
 use std::net::SocketAddr;
 use std::sync::Arc;
 
 const STATIC_PATH_SEGMENT: &str = "static";
+/// A page rendered by the web client as png, in base64
+const PDF_BACKGROUND_MAX_BYTES: usize = 64 * 1024 * 1024;
 
 pub fn webserver(
     addr: SocketAddr,
+    args: &Args,
     comm_channel: InstructionCommunicationChannel,
+    database_manager: DatabaseManager,
 ) -> Result<(HttpServerStateManager, Server), String> {
     let comm_channel = Arc::new(comm_channel);
+    let sessions = Arc::new(WebControlSessions::new(args));
 
     let http_server: Server = match HttpServer::new(move || {
         let file_map = web::Data::new(static_files::cache_static_files());
         let channel_clone = Arc::clone(&comm_channel);
         let comm_channel_data = web::Data::new(channel_clone);
+        let database_manager_data = web::Data::new(database_manager.clone());
+        let sessions_data = web::Data::new(Arc::clone(&sessions));
 
         let cors = Cors::default()
             .allow_any_origin()
-            .allowed_methods(vec!["GET"])
-            // .allowed_headers(vec![header::CONTENT_TYPE, header::ACCEPT])
+            .allowed_methods(vec!["GET", "POST"])
+            .allowed_headers(vec![
+                header::CONTENT_TYPE,
+                header::ACCEPT,
+                header::AUTHORIZATION,
+            ])
             .max_age(3600);
 
         App::new()
             .app_data(file_map)
             .app_data(comm_channel_data)
+            .app_data(database_manager_data)
+            .app_data(sessions_data)
             .wrap(cors)
             .wrap(Logger::default())
             .service(
@@ -38,6 +67,51 @@ pub fn webserver(
                     .route("/{path:.*}", web::get().to(static_files::static_handler)),
             )
             .service(web::scope("/ws").route("/{path:.*}", web::get().to(ws_route)))
+            .service(
+                web::scope("/api")
+                    .route("/heats", web::get().to(api_heats_route))
+                    .route("/heats/{heat_id}", web::get().to(api_heat_route))
+                    .route("/athletes", web::get().to(api_athletes_route))
+                    .route("/wind", web::get().to(api_wind_route))
+                    .route("/logs", web::get().to(api_logs_route))
+                    .route(
+                        "/connection_state",
+                        web::get().to(api_connection_state_route),
+                    )
+                    .route("/free_text", web::post().to(api_free_text_route))
+                    .route("/qr_code", web::post().to(api_qr_code_route))
+                    .route("/mode/{mode}", web::post().to(api_mode_route))
+                    .route("/clock", web::post().to(api_clock_route))
+                    .service(
+                        web::resource("/pdf_background")
+                            .app_data(web::JsonConfig::default().limit(PDF_BACKGROUND_MAX_BYTES))
+                            .route(web::post().to(api_pdf_background_route)),
+                    ),
+            )
+            .service(
+                web::scope(PUBLIC_RESULTS_PATH)
+                    .route("", web::get().to(public_overview_route))
+                    .route("/", web::get().to(public_overview_route))
+                    .route("/heat/{heat_id}", web::get().to(public_heat_route)),
+            )
+            .service(
+                web::scope("/live")
+                    .route("/events", web::get().to(live_events_route))
+                    .route("/state", web::get().to(live_state_route)),
+            )
+            .service(
+                web::scope("/auth")
+                    .route("/login", web::post().to(login_route))
+                    .route("/logout", web::post().to(logout_route))
+                    .route("/session", web::get().to(session_route)),
+            )
+            .service(
+                web::scope("/results")
+                    // before the heat route, which would take "wind_report" as the format
+                    .route("/wind_report/{format}", web::get().to(wind_report_route))
+                    .route("/{format}", web::get().to(meet_results_route))
+                    .route("/{format}/{heat_id}", web::get().to(heat_results_route)),
+            )
             .service(web::redirect("/", format!("/{}/", STATIC_PATH_SEGMENT)))
     })
     .bind(addr)
@@ -1,4 +1,33 @@
-export function wsURL(overwriteIp: string | null = null) {
+export function getNonLocalDomainOrIp(): string | null {
+    const name = window.location.hostname;
+
+    if (name == "localhost") {
+        return null;
+    }
+
+    if (name == "127.0.0.1") {
+        return null;
+    }
+
+    if (name == "0.0.0.0") {
+        return null;
+    }
+
+    return name;
+}
+
+export function wsURL(token: string, overwriteIp: string | null = null) {
+    let ip = String(import.meta.env.VITE_WS_IP);
+    const port = String(import.meta.env.VITE_WS_PORT);
+
+    if (overwriteIp != null) {
+        ip = overwriteIp;
+    }
+
+    return `ws://${ip}:${port}/ws/?token=${encodeURIComponent(token)}`;
+}
+
+export function httpURL(path: string, overwriteIp: string | null = null) {
     let ip = String(import.meta.env.VITE_WS_IP);
     const port = String(import.meta.env.VITE_WS_PORT);
 
@@ -6,5 +35,5 @@ export function wsURL(overwriteIp: string | null = null) {
         ip = overwriteIp;
     }
 
-    return `ws://${ip}:${port}/ws/`;
+    return `http://${ip}:${port}${path}`;
 }
@@ -1,5 +1,237 @@
-export interface Person {
-    firstName: string;
-    lastName: string;
-    birthDate: string;
+import Papa from "papaparse";
+import { Athlete, AthleteWithMetadata, Gender } from "../generated/interface";
+import { uuid } from "./uuid";
+
+export interface ImportRow {
+    row: number; // 1-based, without the header line
+    athlete: Athlete | null;
+    problems: string[];
+}
+
+// accepted header names (lowercase), english and german
+const COLUMN_NAMES: { [key: string]: string[] } = {
+    bib: ["bib", "startnummer", "stnr"],
+    first_name: ["first_name", "firstname", "vorname"],
+    last_name: ["last_name", "lastname", "nachname"],
+    club: ["club", "verein"],
+    nation: ["nation", "land"],
+    gender: ["gender", "geschlecht"],
+    birth_date: ["birth_date", "birthdate", "geburtsdatum", "jahrgang"],
+    spk_guess: ["spk_guess", "guess", "tipp"],
+    street_run_rounds: ["street_run_rounds", "rounds", "runden"],
+};
+
+export const IMPORT_COLUMNS = Object.keys(COLUMN_NAMES);
+
+// street runs are not split by gender, the headerless format has none
+const HEADERLESS_GENDER = Gender.Mixed;
+const HEADERLESS_STREET_RUN_ROUNDS = 4;
+const HEADERLESS_FIRST_BIB = 100;
+
+function parseGender(value: string): Gender | null {
+    switch (value.trim().toLowerCase()) {
+        case "m":
+        case "male":
+        case "männlich":
+            return Gender.Male;
+        case "f":
+        case "w":
+        case "female":
+        case "weiblich":
+            return Gender.Female;
+        case "x":
+        case "d":
+        case "mixed":
+        case "divers":
+            return Gender.Mixed;
+        default:
+            return null;
+    }
+}
+
+// the german locale variant uses decimal commas
+function parseNumber(value: string): number | null {
+    const trimmed = value.trim().replace(",", ".");
+    if (trimmed == "") {
+        return null;
+    }
+    const num = Number(trimmed);
+    return isNaN(num) ? null : num;
+}
+
+// yyyy-mm-dd, the german dd.mm.yyyy or only the year (jahrgang), returned as yyyy-mm-dd
+function parseDate(value: string): string | null {
+    const trimmed = value.trim();
+    if (/^\d{4}-\d{2}-\d{2}$/.test(trimmed)) {
+        return trimmed;
+    }
+    if (/^\d{4}$/.test(trimmed)) {
+        return `${trimmed}-01-01`;
+    }
+    const german = trimmed.match(/^(\d{1,2})\.(\d{1,2})\.(\d{4})$/);
+    if (german) {
+        return `${german[3]}-${german[2].padStart(2, "0")}-${german[1].padStart(2, "0")}`;
+    }
+    return null;
+}
+
+function normalizeHeader(header: string): string {
+    const normalized = header.trim().toLowerCase();
+    const column = IMPORT_COLUMNS.find((c) => COLUMN_NAMES[c].includes(normalized));
+    return column ?? normalized;
+}
+
+/**
+ * Parses comma or semicolon separated text into athletes. With a header line, the columns are taken from it.
+ * Without one, the lines are the street run format firstName;lastName;birthDate and get bibs from `firstFreeBib` on.
+ * Throws, if the text can not be read as CSV at all
+ */
+export function parseAthleteCSV(text: string, firstFreeBib: number): ImportRow[] {
+    const firstLine = Papa.parse<string[]>(text, {
+        skipEmptyLines: true,
+        preview: 1,
+        delimitersToGuess: [";", ",", "\t"],
+    });
+    const hasHeader = (firstLine.data[0] ?? []).some((cell) => IMPORT_COLUMNS.includes(normalizeHeader(cell)));
+
+    return hasHeader ? parseWithHeader(text) : parseHeaderless(text, firstFreeBib);
+}
+
+function parseWithHeader(text: string): ImportRow[] {
+    const result = Papa.parse<{ [key: string]: string }>(text, {
+        skipEmptyLines: true,
+        header: true,
+        delimitersToGuess: [";", ",", "\t"],
+        transformHeader: normalizeHeader,
+    });
+
+    if (result.errors.length > 0 && result.data.length == 0) {
+        throw new Error(result.errors[0].message);
+    }
+    const fields = result.meta.fields ?? [];
+    for (const required of ["bib", "first_name", "last_name", "gender"]) {
+        if (!fields.includes(required)) {
+            throw new Error(`Column "${required}" is missing in the header line`);
+        }
+    }
+
+    return result.data.map((line, index): ImportRow => {
+        const problems: string[] = [];
+        const value = (column: string) => (line[column] ?? "").trim();
+
+        const bib = parseNumber(value("bib"));
+        if (bib == null || !Number.isInteger(bib) || bib < 0) {
+            problems.push(`Invalid bib "${value("bib")}"`);
+        }
+        if (value("first_name") == "" || value("last_name") == "") {
+            problems.push("Name is missing");
+        }
+        const gender = parseGender(value("gender"));
+        if (gender == null) {
+            problems.push(value("gender") == "" ? "Gender is missing" : `Invalid gender "${value("gender")}"`);
+        }
+        let birthDate = null as string | null;
+        if (value("birth_date") != "") {
+            birthDate = parseDate(value("birth_date"));
+            if (birthDate == null) {
+                problems.push(`Invalid birth date "${value("birth_date")}"`);
+            }
+        }
+        const rounds = parseNumber(value("street_run_rounds"));
+
+        if (problems.length > 0) {
+            return { row: index + 1, athlete: null, problems };
+        }
+
+        return {
+            row: index + 1,
+            athlete: {
+                id: uuid(),
+                bib: bib as number,
+                first_name: value("first_name"),
+                last_name: value("last_name"),
+                club: value("club"),
+                nation: value("nation") != "" ? value("nation") : "GER",
+                gender: gender as Gender,
+                birth_date: birthDate,
+                spk_guess: parseNumber(value("spk_guess")),
+                street_run_rounds: rounds != null ? Math.round(rounds) : null,
+            },
+            problems,
+        };
+    });
+}
+
+function parseHeaderless(text: string, firstFreeBib: number): ImportRow[] {
+    const result = Papa.parse<string[]>(text, {
+        skipEmptyLines: true,
+        delimitersToGuess: [";", ",", "\t"],
+    });
+
+    if (result.errors.length > 0 && result.data.length == 0) {
+        throw new Error(result.errors[0].message);
+    }
+    if ((result.data[0] ?? []).length < 3) {
+        throw new Error("Without a header line, the lines must be firstName;lastName;birthDate");
+    }
+
+    const firstBib = Math.max(firstFreeBib, HEADERLESS_FIRST_BIB);
+    return result.data.map((line, index): ImportRow => {
+        const problems: string[] = [];
+        const value = (column: number) => (line[column] ?? "").trim();
+
+        if (value(0) == "" || value(1) == "") {
+            problems.push("Name is missing");
+        }
+        const birthDate = parseDate(value(2));
+        if (birthDate == null) {
+            problems.push(`Invalid birth date "${value(2)}"`);
+        }
+
+        if (problems.length > 0) {
+            return { row: index + 1, athlete: null, problems };
+        }
+
+        return {
+            row: index + 1,
+            athlete: {
+                id: uuid(),
+                bib: firstBib + index,
+                first_name: value(0),
+                last_name: value(1),
+                club: "",
+                nation: "GER",
+                gender: HEADERLESS_GENDER,
+                birth_date: birthDate,
+                spk_guess: null,
+                street_run_rounds: HEADERLESS_STREET_RUN_ROUNDS,
+            },
+            problems,
+        };
+    });
+}
+
+/**
+ * The bib after the highest stored one, for imports that bring no bibs
+ */
+export function nextFreeBib(existing: AthleteWithMetadata[]): number {
+    return existing.reduce((max, a) => Math.max(max, a.athlete.bib), 0) + 1;
+}
+
+/**
+ * Marks rows whose bib is already used by a stored athlete or appears earlier in the import (same check as the server does)
+ */
+export function addBibConflicts(rows: ImportRow[], existing: AthleteWithMetadata[]) {
+    rows.forEach((row, index) => {
+        const athlete = row.athlete;
+        if (!athlete) {
+            return;
+        }
+        const other = existing.find((a) => a.athlete.bib == athlete.bib);
+        if (other) {
+            row.problems.push(`Bib is already used by ${other.athlete.first_name} ${other.athlete.last_name}`);
+        } else if (rows.slice(0, index).some((r) => r.athlete?.bib == athlete.bib)) {
+            row.problems.push("Bib is used more than once in the import");
+        }
+    });
 }
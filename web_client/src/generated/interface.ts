@@ -4,6 +4,32 @@ export enum ApplicationMode {
     StreetLongRun = "StreetLongRun",
     SprinterKing = "SprinterKing",
 }
+export enum DisplayClientMode {
+    Created = "Created",
+    Idle = "Idle",
+    DisplayText = "DisplayText",
+    DisplayExternalFrame = "DisplayExternalFrame",
+    QrCode = "QrCode",
+    CallRoom = "CallRoom",
+    FieldEvent = "FieldEvent",
+    Advertisements = "Advertisements",
+    Timing = "Timing",
+    Clock = "Clock",
+}
+export enum DisplayClientRole {
+    FinishLine = "FinishLine",
+    StartArea = "StartArea",
+    Speaker = "Speaker",
+    CallRoom = "CallRoom",
+}
+export enum DistanceType {
+    Relay = "Relay",
+    Normal = "Normal",
+}
+export enum FieldEventKind {
+    Jump = "Jump",
+    Throw = "Throw",
+}
 export enum Gender {
     Male = "Male",
     Female = "Female",
@@ -18,8 +44,14 @@ export enum TimingTimeDisplayMode {
     TimeBigAndHoldTop = "TimeBigAndHoldTop",
     TimeBigAndHoldWithRunName = "TimeBigAndHoldWithRunName",
     TimeBigAndHoldTopWithRunName = "TimeBigAndHoldTopWithRunName",
+    TimeBigWithSplits = "TimeBigWithSplits",
     StreetRun = "StreetRun",
 }
+export enum WebControlRole {
+    Viewer = "Viewer",
+    Operator = "Operator",
+    Admin = "Admin",
+}
 export enum WindMeasurementType {
     Polling = "Polling",
     UnidentifiedMeasurement = "UnidentifiedMeasurement",
@@ -41,6 +73,15 @@ export type Athlete = {
     street_run_rounds: number | null;
     birth_date: NaiveDate | null;
 };
+export type AthleteImportConflict = {
+    row: number;
+    bib: number;
+    reason: string;
+};
+export type AthleteImportReport = {
+    imported: number;
+    conflicts: AthleteImportConflict[];
+};
 export type AthleteWithMetadata = {
     athlete: Athlete;
     heat_assignments: HeatAssignment[];
@@ -62,6 +103,16 @@ export type BibEquivalence = {
     finish_bib: number;
     alternative_bib: number;
 };
+export type CompetitionEvent = {
+    id: Uuid;
+    name: string;
+    distance: number;
+    distance_type: DistanceType;
+    heat_descriminator: number;
+    session_date: NaiveDate;
+    scheduled_start_time: DayTime;
+    minutes_between_heats: number;
+};
 export type CompetitorEvaluated = {
     application: string;
     version: string;
@@ -95,6 +146,8 @@ export type ConnectionState = {
     display_passthrough_address: string;
     timing_program_is_connected: boolean;
     listening_to_timing_program: boolean;
+    display_clients: DisplayClientConnection[];
+    preview_display_client: string | null;
 };
 export type DatabaseStaticState = {
     mode: ApplicationMode;
@@ -110,11 +163,34 @@ export type DayTime = {
     seconds: number;
     fractional_part_in_ten_thousands: number | null;
 };
+export type DisplayClientConnection = {
+    name: string;
+    role: DisplayClientRole;
+    address_with_port: string;
+    connected: boolean;
+    mode: DisplayClientMode;
+    routing: DisplayClientRouting;
+    server_imposed_settings: ServerImposedSettings;
+};
+export type DisplayClientRouting = {
+    free_text: boolean;
+    advertisements: boolean;
+    timing: boolean;
+    clock: boolean;
+    external_frames: boolean;
+    display_entries: boolean;
+    call_room: boolean;
+};
 export type DisplayClientState = {
     alive: boolean;
     external_passthrough_mode: boolean;
     can_switch_mode: boolean;
 };
+export type DisplayClientUpdate = {
+    name: string;
+    routing: DisplayClientRouting;
+    server_imposed_settings: ServerImposedSettings;
+};
 export type DisplayEntry = {
     bib: number;
     name: string;
@@ -132,6 +208,52 @@ export type DisqualificationReasonDidNotFinish = { type: "DidNotFinish" };
 export type DisqualificationReasonDidNotStart = { type: "DidNotStart" };
 export type DisqualificationReasonDisqualified = { type: "Disqualified" };
 export type DisqualificationReasonOther = { type: "Other"; data: string };
+export type FieldAttemptResult =
+    | FieldAttemptResultMark
+    | FieldAttemptResultFoul
+    | FieldAttemptResultPass;
+export type FieldAttemptResultFoul = { type: "Foul" };
+export type FieldAttemptResultMark = { type: "Mark"; data: number };
+export type FieldAttemptResultPass = { type: "Pass" };
+export type FieldEvent = {
+    id: Uuid;
+    name: string;
+    kind: FieldEventKind;
+    nr_attempts: number;
+    scheduled_start_time: DayTime;
+    competitors: FieldEventCompetitor[];
+};
+export type FieldEventAttempt = {
+    id: Uuid;
+    event_id: Uuid;
+    bib: number;
+    attempt_nr: number;
+    result: FieldAttemptResult;
+    wind: RaceWind | null;
+};
+export type FieldEventCompetitor = {
+    bib: number;
+    first_name: string;
+    last_name: string;
+    club: string;
+};
+export type FieldEventData = {
+    event: FieldEvent;
+    attempts: FieldEventAttempt[];
+    standings: FieldEventStanding[];
+    up_next: FieldEventUpNext | null;
+};
+export type FieldEventStanding = {
+    rank: number | null;
+    competitor: FieldEventCompetitor;
+    best_mark: number | null;
+    best_mark_wind: RaceWind | null;
+    attempts: (FieldEventAttempt | null)[];
+};
+export type FieldEventUpNext = {
+    competitor: FieldEventCompetitor;
+    attempt_nr: number;
+};
 export type FrametimeReport = {
     target_fps: number;
     time_percentage_taken_per_frame_since_last_report: number;
@@ -143,6 +265,7 @@ export type HeatAssignment = {
     distance: number;
     heat_descriminator: number;
     athlete_ids: { [key: number]: Uuid };
+    relay_teams: { [key: number]: RelayTeam };
 };
 export type HeatCompetitor = {
     id: string;
@@ -169,7 +292,9 @@ export type HeatData = {
     start_list: HeatStartList;
     start: HeatStart | null;
     intermediates: HeatIntermediate[] | null;
+    splits: HeatSplit[] | null;
     wind: HeatWind | null;
+    wind_server_wind: HeatWindServerWind | null;
     finish: HeatFinish | null;
     evaluations: CompetitorEvaluated[] | null;
     result: HeatResult | null;
@@ -205,6 +330,11 @@ export type HeatResult = {
     competitors_evaluated: HeatCompetitorResult[];
     competitors_left_to_evaluate: HeatCompetitor[];
 };
+export type HeatSplit = {
+    distance_meters: number | null;
+    race_time: RaceTime;
+    time: DayTime;
+};
 export type HeatStart = {
     application: string;
     version: string;
@@ -228,10 +358,19 @@ export type HeatWind = {
     id: Uuid;
     wind: RaceWind;
 };
+export type HeatWindServerWind = {
+    id: Uuid;
+    wind: RaceWind;
+    differs_from_camera_program: boolean;
+};
+export type MeetExportReport = {
+    problems: string[];
+};
 export type MessageFromWebControl =
     | MessageFromWebControlIdle
     | MessageFromWebControlAdvertisements
     | MessageFromWebControlFreeText
+    | MessageFromWebControlDisplayQrCode
     | MessageFromWebControlRequestDisplayClientState
     | MessageFromWebControlSwitchMode
     | MessageFromWebControlGetHeats
@@ -248,7 +387,10 @@ export type MessageFromWebControl =
     | MessageFromWebControlInitStaticDatabaseState
     | MessageFromWebControlRequestStaticDatabaseState
     | MessageFromWebControlExportDataToFile
+    | MessageFromWebControlExportResultsToFile
+    | MessageFromWebControlExportPDFsToFile
     | MessageFromWebControlCreateAthlete
+    | MessageFromWebControlImportAthletes
     | MessageFromWebControlDeleteAthlete
     | MessageFromWebControlCreateHeatAssignment
     | MessageFromWebControlDeleteHeatAssignment
@@ -256,12 +398,28 @@ export type MessageFromWebControl =
     | MessageFromWebControlStorePDFConfigurationSetting
     | MessageFromWebControlDeletePDFConfigurationSetting
     | MessageFromWebControlRequestPDFConfigurationSettings
+    | MessageFromWebControlStorePDFBackground
+    | MessageFromWebControlDeletePDFBackground
+    | MessageFromWebControlStoreCompetitionEvent
+    | MessageFromWebControlDeleteCompetitionEvent
+    | MessageFromWebControlRequestCompetitionEvents
+    | MessageFromWebControlStoreTickerMessage
+    | MessageFromWebControlDeleteTickerMessage
+    | MessageFromWebControlRequestTickerMessages
+    | MessageFromWebControlStoreFieldEvent
+    | MessageFromWebControlDeleteFieldEvent
+    | MessageFromWebControlRequestFieldEvents
+    | MessageFromWebControlStoreFieldEventAttempt
+    | MessageFromWebControlDeleteFieldEventAttempt
+    | MessageFromWebControlShowFieldEvent
     | MessageFromWebControlDeleteCompetitorEvaluated
     | MessageFromWebControlSendDebugDisplayCommand
     | MessageFromWebControlRequestDevMode
-    | MessageFromWebControlRequestPassword
     | MessageFromWebControlRequestLicense
     | MessageFromWebControlRequestConnectionStates
+    | MessageFromWebControlUpdateDisplayClient
+    | MessageFromWebControlSelectDisplayClientPreview
+    | MessageFromWebControlCallRoom
     | MessageFromWebControlSelectHeatForBibMode
     | MessageFromWebControlRequestBibEntryModeData
     | MessageFromWebControlSendHeatDataToDisplay
@@ -279,14 +437,20 @@ export type MessageFromWebControl =
     | MessageFromWebControlDevRequestMainHeatStartList;
 export type MessageFromWebControlAddBibEquivalence = { type: "AddBibEquivalence"; data: BibEquivalence };
 export type MessageFromWebControlAdvertisements = { type: "Advertisements" };
+export type MessageFromWebControlCallRoom = { type: "CallRoom"; data: string };
 export type MessageFromWebControlClock = { type: "Clock"; data: DayTime };
 export type MessageFromWebControlCreateAthlete = { type: "CreateAthlete"; data: Athlete };
 export type MessageFromWebControlCreateHeatAssignment = { type: "CreateHeatAssignment"; data: HeatAssignment };
 export type MessageFromWebControlDeleteAthlete = { type: "DeleteAthlete"; data: Uuid };
 export type MessageFromWebControlDeleteBibEquivalence = { type: "DeleteBibEquivalence"; data: BibEquivalence };
+export type MessageFromWebControlDeleteCompetitionEvent = { type: "DeleteCompetitionEvent"; data: Uuid };
 export type MessageFromWebControlDeleteCompetitorEvaluated = { type: "DeleteCompetitorEvaluated"; data: DayTime };
+export type MessageFromWebControlDeleteFieldEvent = { type: "DeleteFieldEvent"; data: Uuid };
+export type MessageFromWebControlDeleteFieldEventAttempt = { type: "DeleteFieldEventAttempt"; data: FieldEventAttempt };
 export type MessageFromWebControlDeleteHeatAssignment = { type: "DeleteHeatAssignment"; data: number };
+export type MessageFromWebControlDeletePDFBackground = { type: "DeletePDFBackground"; data: PDFSettingFor };
 export type MessageFromWebControlDeletePDFConfigurationSetting = { type: "DeletePDFConfigurationSetting"; data: Uuid };
+export type MessageFromWebControlDeleteTickerMessage = { type: "DeleteTickerMessage"; data: Uuid };
 export type MessageFromWebControlDevRequestMainHeatStartList = { type: "DevRequestMainHeatStartList" };
 export type MessageFromWebControlDevReset = { type: "DevReset" };
 export type MessageFromWebControlDevSendEvaluated = { type: "DevSendEvaluated"; data: CompetitorEvaluated };
@@ -296,34 +460,48 @@ export type MessageFromWebControlDevSendResultList = { type: "DevSendResultList"
 export type MessageFromWebControlDevSendStartList = { type: "DevSendStartList"; data: HeatStartList };
 export type MessageFromWebControlDevSendWind = { type: "DevSendWind"; data: HeatWind };
 export type MessageFromWebControlDevStartRace = { type: "DevStartRace"; data: HeatStart };
+export type MessageFromWebControlDisplayQrCode = { type: "DisplayQrCode"; data: QrCodePayload };
 export type MessageFromWebControlExportDataToFile = { type: "ExportDataToFile" };
+export type MessageFromWebControlExportResultsToFile = { type: "ExportResultsToFile"; data: Uuid | null };
+export type MessageFromWebControlExportPDFsToFile = { type: "ExportPDFsToFile" };
 export type MessageFromWebControlFreeText = { type: "FreeText"; data: string };
 export type MessageFromWebControlGetHeats = { type: "GetHeats" };
 export type MessageFromWebControlGetLogs = { type: "GetLogs"; data: number };
 export type MessageFromWebControlGetMainHeat = { type: "GetMainHeat" };
 export type MessageFromWebControlIdle = { type: "Idle" };
+export type MessageFromWebControlImportAthletes = { type: "ImportAthletes"; data: Athlete[] };
 export type MessageFromWebControlInitStaticDatabaseState = { type: "InitStaticDatabaseState"; data: DatabaseStaticState };
 export type MessageFromWebControlRecordBibRound = { type: "RecordBibRound"; data: number };
 export type MessageFromWebControlRequestAthletes = { type: "RequestAthletes" };
 export type MessageFromWebControlRequestBibEntryModeData = { type: "RequestBibEntryModeData" };
+export type MessageFromWebControlRequestCompetitionEvents = { type: "RequestCompetitionEvents" };
 export type MessageFromWebControlRequestConnectionStates = { type: "RequestConnectionStates" };
 export type MessageFromWebControlRequestDevMode = { type: "RequestDevMode" };
 export type MessageFromWebControlRequestDisplayClientState = { type: "RequestDisplayClientState" };
+export type MessageFromWebControlRequestFieldEvents = { type: "RequestFieldEvents" };
 export type MessageFromWebControlRequestLicense = { type: "RequestLicense" };
 export type MessageFromWebControlRequestPDFConfigurationSettings = { type: "RequestPDFConfigurationSettings" };
-export type MessageFromWebControlRequestPassword = { type: "RequestPassword" };
 export type MessageFromWebControlRequestStaticDatabaseState = { type: "RequestStaticDatabaseState" };
+export type MessageFromWebControlRequestTickerMessages = { type: "RequestTickerMessages" };
 export type MessageFromWebControlRequestTimingSettings = { type: "RequestTimingSettings" };
 export type MessageFromWebControlRequestWindValues = { type: "RequestWindValues"; data: WindValueRequestDateContainer };
 export type MessageFromWebControlResultList = { type: "ResultList" };
+export type MessageFromWebControlSelectDisplayClientPreview = { type: "SelectDisplayClientPreview"; data: string };
 export type MessageFromWebControlSelectHeat = { type: "SelectHeat"; data: string };
 export type MessageFromWebControlSelectHeatForBibMode = { type: "SelectHeatForBibMode"; data: Uuid };
 export type MessageFromWebControlSendDebugDisplayCommand = { type: "SendDebugDisplayCommand"; data: DisplayEntry };
 export type MessageFromWebControlSendHeatDataToDisplay = { type: "SendHeatDataToDisplay"; data: Uuid };
+export type MessageFromWebControlShowFieldEvent = { type: "ShowFieldEvent"; data: Uuid };
 export type MessageFromWebControlStartList = { type: "StartList" };
+export type MessageFromWebControlStoreCompetitionEvent = { type: "StoreCompetitionEvent"; data: CompetitionEvent };
+export type MessageFromWebControlStoreFieldEvent = { type: "StoreFieldEvent"; data: FieldEvent };
+export type MessageFromWebControlStoreFieldEventAttempt = { type: "StoreFieldEventAttempt"; data: FieldEventAttempt };
+export type MessageFromWebControlStorePDFBackground = { type: "StorePDFBackground"; data: PDFBackground };
 export type MessageFromWebControlStorePDFConfigurationSetting = { type: "StorePDFConfigurationSetting"; data: PDFConfigurationSetting };
+export type MessageFromWebControlStoreTickerMessage = { type: "StoreTickerMessage"; data: TickerMessage };
 export type MessageFromWebControlSwitchMode = { type: "SwitchMode" };
 export type MessageFromWebControlTiming = { type: "Timing" };
+export type MessageFromWebControlUpdateDisplayClient = { type: "UpdateDisplayClient"; data: DisplayClientUpdate };
 export type MessageFromWebControlUpdateTimingSettings = { type: "UpdateTimingSettings"; data: TimingSettings };
 export type MessageToWebControl =
     | MessageToWebControlDatabaseStaticState
@@ -336,26 +514,33 @@ export type MessageToWebControl =
     | MessageToWebControlWindMeasurements
     | MessageToWebControlCurrentDisplayFrame
     | MessageToWebControlAthletesData
+    | MessageToWebControlAthleteImportReport
+    | MessageToWebControlMeetExportReport
     | MessageToWebControlPDFConfigurationSettingsData
+    | MessageToWebControlCompetitionEventsData
+    | MessageToWebControlTickerMessages
+    | MessageToWebControlFieldEventsData
     | MessageToWebControlMainHeat
     | MessageToWebControlVersionMismatch
     | MessageToWebControlFrametimeReport
     | MessageToWebControlDevModeStatus
-    | MessageToWebControlPassword
     | MessageToWebControlLicensed
     | MessageToWebControlStaticConfigurationNotInitialized
     | MessageToWebControlConnectionState
     | MessageToWebControlBibRoundRecorded
     | MessageToWebControlHighlightBibEntry
     | MessageToWebControlDevMainHeatStartList;
+export type MessageToWebControlAthleteImportReport = { type: "AthleteImportReport"; data: AthleteImportReport };
 export type MessageToWebControlAthletesData = { type: "AthletesData"; data: AthleteWithMetadata[] };
 export type MessageToWebControlBibRoundRecorded = { type: "BibRoundRecorded"; data: BibDataPoint };
+export type MessageToWebControlCompetitionEventsData = { type: "CompetitionEventsData"; data: CompetitionEvent[] };
 export type MessageToWebControlConnectionState = { type: "ConnectionState"; data: ConnectionState };
 export type MessageToWebControlCurrentDisplayFrame = { type: "CurrentDisplayFrame"; data: number[] };
 export type MessageToWebControlDatabaseStaticState = { type: "DatabaseStaticState"; data: DatabaseStaticState };
 export type MessageToWebControlDevMainHeatStartList = { type: "DevMainHeatStartList"; data: HeatStartList };
 export type MessageToWebControlDevModeStatus = { type: "DevModeStatus"; data: boolean };
 export type MessageToWebControlDisplayClientState = { type: "DisplayClientState"; data: DisplayClientState };
+export type MessageToWebControlFieldEventsData = { type: "FieldEventsData"; data: FieldEventData[] };
 export type MessageToWebControlFrametimeReport = { type: "FrametimeReport"; data: FrametimeReport };
 export type MessageToWebControlHeatDataMessage = { type: "HeatDataMessage"; data: HeatData };
 export type MessageToWebControlHeatDataSelectionForBibMode = { type: "HeatDataSelectionForBibMode"; data: BibEntryModeData | null };
@@ -364,14 +549,19 @@ export type MessageToWebControlHighlightBibEntry = { type: "HighlightBibEntry";
 export type MessageToWebControlLicensed = { type: "Licensed"; data: ProductKey | null };
 export type MessageToWebControlLogs = { type: "Logs"; data: PermanentlyStoredDataset[] };
 export type MessageToWebControlMainHeat = { type: "MainHeat"; data: HeatData };
+export type MessageToWebControlMeetExportReport = { type: "MeetExportReport"; data: MeetExportReport };
 export type MessageToWebControlPDFConfigurationSettingsData = { type: "PDFConfigurationSettingsData"; data: PDFConfigurationSetting[] };
-export type MessageToWebControlPassword = { type: "Password"; data: string };
 export type MessageToWebControlStaticConfigurationNotInitialized = { type: "StaticConfigurationNotInitialized" };
+export type MessageToWebControlTickerMessages = { type: "TickerMessages"; data: TickerMessage[] };
 export type MessageToWebControlTimingSettingsState = { type: "TimingSettingsState"; data: TimingSettings };
 export type MessageToWebControlVersionMismatch = { type: "VersionMismatch"; data: [string, string] };
 export type MessageToWebControlWindMeasurements = { type: "WindMeasurements"; data: WindMeasurement[] };
 export type NaiveDate = string;
 export type NaiveDateTime = string;
+export type PDFBackground = {
+    setting_for: PDFSettingFor;
+    data_url: string;
+};
 export type PDFConfigurationContent =
     | PDFConfigurationContentPDFConfigurationContentText
     | PDFConfigurationContentPDFConfigurationContentReference;
@@ -405,6 +595,10 @@ export type ProductKey = {
     end_date: NaiveDate;
     company_name: string;
 };
+export type QrCodePayload = {
+    content: string;
+    caption: string | null;
+};
 export type RaceTime = {
     hours: number | null;
     minutes: number | null;
@@ -419,6 +613,24 @@ export type RaceWind = {
     whole_number_part: number;
     fraction_part: number;
 };
+export type RelayTeam = {
+    name: string;
+    member_ids: Uuid[];
+};
+export type ServerImposedSettings = {
+    position: [number, number, number, number];
+    table_duration_nr_ms: number;
+    slideshow_duration_nr_ms: number;
+    slideshow_transition_duration_nr_ms: number;
+    scroll_text_speed: number;
+    scroll_text_deadzones_nr_ms: number;
+};
+export type TickerMessage = {
+    id: Uuid;
+    text: string;
+    priority: number;
+    repeat: number | null;
+};
 export type TimingSettings = {
     fireworks_on_intermediate: boolean;
     fireworks_on_finish: boolean;
@@ -439,6 +651,18 @@ export type TimingSettings = {
     display_bibs_in_start_list: boolean;
 };
 export type Uuid = string;
+export type WebControlLoginRequest = {
+    name: string;
+    password: string;
+};
+export type WebControlLoginResponse = {
+    token: string;
+    session: WebControlSessionInfo;
+};
+export type WebControlSessionInfo = {
+    name: string;
+    role: WebControlRole;
+};
 export type WindMeasurement = {
     wind: RaceWind;
     probable_measurement_type: WindMeasurementType;
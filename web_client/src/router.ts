@@ -6,6 +6,9 @@ import WindRequest from "./components/WindRequest.vue";
 import PDFSettings from "./components/PDFSettings.vue";
 import PDFPrint from "./components/PDFPrint.vue";
 import Timing from "./components/Timing.vue";
+import DisplayClients from "./components/DisplayClients.vue";
+import Ticker from "./components/Ticker.vue";
+import FieldEvents from "./components/FieldEvents.vue";
 import ManageAthletes from "./components/ManageAthletes.vue";
 import Debug from "./components/Debug.vue";
 import BibInterface from "./components/BibInterface.vue";
@@ -17,13 +20,16 @@ const routes = [
     { path: "/", component: Index, name: "index", meta: { requiresNoAuth: true } },
     { path: "/heats", component: Heats, name: "heats" },
     { path: "/timing", component: Timing, name: "timing" },
+    { path: "/display_clients", component: DisplayClients, name: "display_clients" },
+    { path: "/ticker", component: Ticker, name: "ticker" },
+    { path: "/field_events", component: FieldEvents, name: "field_events" },
     { path: "/manage_athletes", component: ManageAthletes, name: "manage_athletes" },
     { path: "/wind_request", component: WindRequest, name: "wind_request" },
     { path: "/pdf_settings", component: PDFSettings, name: "pdf_settings" },
     { path: "/pdf_print", component: PDFPrint, name: "pdf_print" },
-    { path: "/debug", component: Debug, name: "debug" },
+    { path: "/debug", component: Debug, name: "debug", meta: { requiresAdmin: true } },
     { path: "/bib_results", component: BibInterfaceInternal, name: "bib_results" },
-    { path: "/bib_interface", component: BibInterface, name: "bib_interface", meta: { requiresNoAuth: true } },
+    { path: "/bib_interface", component: BibInterface, name: "bib_interface" },
     { path: "/:catchAll(.*)*", redirect: "/", meta: { requiresNoAuth: true } },
 ];
 
@@ -38,13 +44,19 @@ router.beforeEach((to) => {
     const requiresAuth = !(to.meta.requiresNoAuth === true);
 
     if (requiresAuth && !auth.authenticated) {
-        if (auth.passwordComparisonLoaded) {
-            // on page reload finish the navigation at first (we will navigate away, if password is loaded in the auth store)
+        if (auth.sessionChecked) {
+            // on page reload finish the navigation at first (we will navigate away, if the session turns out to be invalid)
             return {
                 name: "index",
             };
         }
     }
+
+    if (to.meta.requiresAdmin === true && auth.authenticated && !auth.isAdmin) {
+        return {
+            name: "index",
+        };
+    }
 });
 
 export default router;
@@ -1,40 +1,124 @@
 import { defineStore } from "pinia";
 import { computed, ref, watch } from "vue";
-import useMainStore from "./main";
+import { getNonLocalDomainOrIp, httpURL } from "../functions/environment";
 import router from "../router";
+import { WebControlLoginRequest, WebControlLoginResponse, WebControlRole, WebControlSessionInfo } from "../generated/interface";
 
-export default defineStore("auth", () => {
-    const mainStore = useMainStore();
+const ROLE_ORDER = [WebControlRole.Viewer, WebControlRole.Operator, WebControlRole.Admin];
 
-    const PASSWORD_KEY = "TOTALLY_SECURE_PASSWORD_STORAGE_KEY";
-    const password = ref(localStorage.getItem(PASSWORD_KEY) ?? "");
-    watch(password, () => {
-        localStorage.setItem(PASSWORD_KEY, password.value);
+export default defineStore("auth", () => {
+    const TOKEN_KEY = "WEB_CONTROL_SESSION_TOKEN_STORAGE_KEY";
+    const token = ref(localStorage.getItem(TOKEN_KEY));
+    watch(token, () => {
+        if (token.value == null) {
+            localStorage.removeItem(TOKEN_KEY);
+        } else {
+            localStorage.setItem(TOKEN_KEY, token.value);
+        }
     });
 
+    const session = ref(null as null | WebControlSessionInfo);
+    const sessionChecked = ref(false);
+    const loginFailed = ref(false);
+
     const authenticated = computed(() => {
-        return mainStore.managementPassword == password.value;
+        return session.value != null;
     });
 
-    const passwordComparisonLoaded = computed(() => {
-        return mainStore.managementPassword != null;
+    function hasRole(role: WebControlRole): boolean {
+        if (session.value == null) {
+            return false;
+        }
+        return ROLE_ORDER.indexOf(session.value.role) >= ROLE_ORDER.indexOf(role);
+    }
+    const isOperator = computed(() => hasRole(WebControlRole.Operator));
+    const isAdmin = computed(() => hasRole(WebControlRole.Admin));
+
+    // returns false only if the server rejected the token (not on network problems)
+    async function checkSession(): Promise<boolean> {
+        if (token.value == null) {
+            session.value = null;
+            sessionChecked.value = true;
+            return false;
+        }
+
+        try {
+            const response = await fetch(
+                httpURL(`/auth/session?token=${encodeURIComponent(token.value)}`, getNonLocalDomainOrIp())
+            );
+            if (response.ok) {
+                session.value = (await response.json()) as WebControlSessionInfo;
+            } else if (response.status == 401) {
+                token.value = null;
+                session.value = null;
+            }
+        } catch (e) {
+            console.error("Could not check the session", e);
+        }
+
+        sessionChecked.value = true;
+        return token.value != null;
+    }
+    checkSession();
+
+    watch(sessionChecked, (now, prev) => {
+        if (!prev && now && !authenticated.value) {
+            // trigger navigation (re-avaluates "can I be here" rule)
+            router.push({ name: router.currentRoute.value.name });
+        }
     });
 
-    watch(
-        () => mainStore.managementPassword,
-        (now, prev) => {
-            if (prev == null && now != null) {
-                if (password.value != now) {
-                    // trigger navigation (re-avaluates "can I be here" rule)
-                    router.push({ name: router.currentRoute.value.name });
-                }
+    async function login(name: string, password: string) {
+        const request: WebControlLoginRequest = {
+            name,
+            password,
+        };
+
+        try {
+            const response = await fetch(httpURL("/auth/login", getNonLocalDomainOrIp()), {
+                method: "POST",
+                headers: { "Content-Type": "application/json" },
+                body: JSON.stringify(request),
+            });
+            if (response.ok) {
+                const login = (await response.json()) as WebControlLoginResponse;
+                session.value = login.session;
+                token.value = login.token;
+                loginFailed.value = false;
+            } else {
+                loginFailed.value = true;
+            }
+        } catch (e) {
+            console.error("Could not log in", e);
+            loginFailed.value = true;
+        }
+    }
+
+    async function logout() {
+        if (token.value != null) {
+            try {
+                await fetch(httpURL(`/auth/logout?token=${encodeURIComponent(token.value)}`, getNonLocalDomainOrIp()), {
+                    method: "POST",
+                });
+            } catch (e) {
+                console.error("Could not log out on the server", e);
             }
         }
-    );
+        token.value = null;
+        session.value = null;
+        router.push({ name: "index" });
+    }
 
     return {
+        token,
+        session,
+        sessionChecked,
+        loginFailed,
         authenticated,
-        password,
-        passwordComparisonLoaded,
+        isOperator,
+        isAdmin,
+        checkSession,
+        login,
+        logout,
     };
 });
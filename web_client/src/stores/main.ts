@@ -1,6 +1,7 @@
 import { defineStore } from "pinia";
 import { computed, nextTick, ref, watch } from "vue";
-import { wsURL } from "../functions/environment";
+import { getNonLocalDomainOrIp, httpURL, wsURL } from "../functions/environment";
+import useAuthStore from "./auth";
 import {
     WindValueRequestDateContainer,
     AthleteWithMetadata,
@@ -14,6 +15,8 @@ import {
     DisplayEntry,
     HeatAssignment,
     PDFConfigurationSetting,
+    PDFBackground,
+    PDFSettingFor,
     TimingSettings,
     Uuid,
     MessageToWebControl,
@@ -23,9 +26,12 @@ import {
     MessageFromWebControlTiming,
     MessageFromWebControlStartList,
     MessageFromWebControlExportDataToFile,
+    MessageFromWebControlExportResultsToFile,
+    MessageFromWebControlExportPDFsToFile,
     MessageFromWebControlResultList,
     MessageFromWebControlIdle,
     MessageFromWebControlFreeText,
+    MessageFromWebControlDisplayQrCode,
     MessageFromWebControlSwitchMode,
     MessageFromWebControlRequestWindValues,
     MessageFromWebControlClock,
@@ -33,11 +39,32 @@ import {
     MessageFromWebControlInitStaticDatabaseState,
     MessageFromWebControlRequestAthletes,
     MessageFromWebControlCreateAthlete,
+    MessageFromWebControlImportAthletes,
+    AthleteImportReport,
+    MeetExportReport,
     MessageFromWebControlDeleteAthlete,
     MessageFromWebControlDeleteCompetitorEvaluated,
     MessageFromWebControlStorePDFConfigurationSetting,
     MessageFromWebControlDeletePDFConfigurationSetting,
+    MessageFromWebControlDeletePDFBackground,
     MessageFromWebControlRequestPDFConfigurationSettings,
+    CompetitionEvent,
+    MessageFromWebControlStoreCompetitionEvent,
+    MessageFromWebControlDeleteCompetitionEvent,
+    MessageFromWebControlRequestCompetitionEvents,
+    TickerMessage,
+    MessageFromWebControlStoreTickerMessage,
+    MessageFromWebControlDeleteTickerMessage,
+    MessageFromWebControlRequestTickerMessages,
+    FieldEvent,
+    FieldEventAttempt,
+    FieldEventData,
+    MessageFromWebControlStoreFieldEvent,
+    MessageFromWebControlDeleteFieldEvent,
+    MessageFromWebControlRequestFieldEvents,
+    MessageFromWebControlStoreFieldEventAttempt,
+    MessageFromWebControlDeleteFieldEventAttempt,
+    MessageFromWebControlShowFieldEvent,
     MessageFromWebControlCreateHeatAssignment,
     MessageFromWebControlDeleteHeatAssignment,
     MessageFromWebControlSendDebugDisplayCommand,
@@ -51,11 +78,14 @@ import {
     MessageFromWebControl,
     HeatStartList,
     FrametimeReport,
-    MessageFromWebControlRequestPassword,
     ProductKey,
     MessageFromWebControlRequestLicense,
     ConnectionState,
     MessageFromWebControlRequestConnectionStates,
+    MessageFromWebControlUpdateDisplayClient,
+    MessageFromWebControlSelectDisplayClientPreview,
+    MessageFromWebControlCallRoom,
+    DisplayClientUpdate,
     MessageFromWebControlSendHeatDataToDisplay,
     BibEntryModeData,
     MessageFromWebControlSelectHeatForBibMode,
@@ -76,12 +106,12 @@ function sleep(ms: number) {
 const WIND_MESSAGE = "Not synced (start any Race in Camera Program to fix)";
 
 export default defineStore("main", () => {
+    const authStore = useAuthStore();
     const staticConfiguration = ref(null as null | DatabaseStaticState);
     const connected = ref(false);
     const displayConnected = ref(false);
     const displayExternalPassthrough = ref(false);
     const displayCanSwitchModeInternal = ref(false);
-    const managementPassword = ref(null as null | string);
     const staticConfigurationMissing = ref(false);
     const license = ref(null as ProductKey | null);
     const connectionState = ref(null as ConnectionState | null);
@@ -121,7 +151,12 @@ export default defineStore("main", () => {
     const logEntriesRolling = new CircularBuffer<PermanentlyStoredDataset>(10);
     const requestedWindMeasurements = ref([] as WindMeasurement[]);
     const athletesData = ref([] as AthleteWithMetadata[]);
+    const athleteImportReport = ref(null as null | AthleteImportReport);
+    const meetExportReport = ref(null as null | MeetExportReport);
     const pdfConfigurationSettings = ref([] as PDFConfigurationSetting[]);
+    const competitionEvents = ref([] as CompetitionEvent[]);
+    const tickerMessages = ref([] as TickerMessage[]);
+    const fieldEvents = ref([] as FieldEventData[]);
     const mainHeat = ref(null as null | HeatData);
     const selectedHeatForBibMode = ref(null as null | BibEntryModeData);
     const devMode = ref(false);
@@ -206,9 +241,24 @@ export default defineStore("main", () => {
             case "AthletesData":
                 athletesData.value = msg.data;
                 return;
+            case "AthleteImportReport":
+                athleteImportReport.value = msg.data;
+                return;
+            case "MeetExportReport":
+                meetExportReport.value = msg.data;
+                return;
             case "PDFConfigurationSettingsData":
                 pdfConfigurationSettings.value = msg.data;
                 return;
+            case "CompetitionEventsData":
+                competitionEvents.value = msg.data;
+                return;
+            case "TickerMessages":
+                tickerMessages.value = msg.data;
+                return;
+            case "FieldEventsData":
+                fieldEvents.value = msg.data;
+                return;
             case "MainHeat":
                 mainHeat.value = msg.data;
                 return;
@@ -227,9 +277,6 @@ export default defineStore("main", () => {
             case "FrametimeReport":
                 frametimeReport.value = msg.data;
                 return;
-            case "Password":
-                managementPassword.value = msg.data;
-                return;
             case "StaticConfigurationNotInitialized":
                 staticConfigurationMissing.value = true;
                 return;
@@ -261,24 +308,6 @@ export default defineStore("main", () => {
         console.error("Received unhandled message type:", msg);
     }
 
-    function getNonLocalDomainOrIp(): string | null {
-        const name = window.location.hostname;
-
-        if (name == "localhost") {
-            return null;
-        }
-
-        if (name == "127.0.0.1") {
-            return null;
-        }
-
-        if (name == "0.0.0.0") {
-            return null;
-        }
-
-        return name;
-    }
-
     async function initWS() {
         if (reconnecting) {
             // prevent multiple runs
@@ -290,19 +319,32 @@ export default defineStore("main", () => {
 
         if (ws) {
             try {
+                ws.onclose = null;
                 ws.close();
             } catch (_) {}
             ws = null;
             await sleep(500);
         }
 
-        ws = new WebSocket(wsURL(getNonLocalDomainOrIp()));
+        if (authStore.token == null) {
+            // the websocket only accepts logged in sessions
+            console.log("Not logged in, not connecting WS");
+            connected.value = false;
+            reconnecting = false;
+            return;
+        }
+
+        ws = new WebSocket(wsURL(authStore.token, getNonLocalDomainOrIp()));
         ws.onerror = async () => {
             connected.value = false;
             connectionState.value = null;
             reconnecting = false;
 
             await sleep(2000);
+            if (!(await authStore.checkSession())) {
+                console.log("Session is not valid anymore, not reconnecting");
+                return;
+            }
             console.log("Retry connecting to socket after error");
             initWS();
         };
@@ -314,7 +356,6 @@ export default defineStore("main", () => {
             console.log("Socket connected");
 
             // this is kind of an init also, as this gets requested on connection establish:
-            sendRequestPasswordCommand();
             sendRequestLicenseCommand();
             sendRequestStaticConfigCommand();
 
@@ -325,6 +366,9 @@ export default defineStore("main", () => {
             sendRequestAthletesCommand();
             sendRequestAllPDFSettingsCommand();
             sendRequestAllPDFSettingsCommand();
+            sendRequestAllCompetitionEventsCommand();
+            sendRequestTickerMessagesCommand();
+            sendRequestFieldEventsCommand();
             sendRequestDevModeStatusCommand();
 
             // only assign the handlers if actually open
@@ -348,6 +392,12 @@ export default defineStore("main", () => {
         };
     }
     initWS();
+    watch(
+        () => authStore.token,
+        () => {
+            initWS();
+        }
+    );
 
     function sendWSCommand(dat: string) {
         if (ws && connected.value) {
@@ -383,11 +433,39 @@ export default defineStore("main", () => {
     }
 
     function sendExportToFileCommand() {
+        meetExportReport.value = null;
         const packet: MessageFromWebControlExportDataToFile = {
             type: "ExportDataToFile",
         };
         sendWSCommand(JSON.stringify(packet));
     }
+    function sendExportPDFsToFileCommand() {
+        const packet: MessageFromWebControlExportPDFsToFile = {
+            type: "ExportPDFsToFile",
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
+    /**
+     * @param heatId null exports the whole meet
+     */
+    function sendExportResultsToFileCommand(heatId: Uuid | null) {
+        const packet: MessageFromWebControlExportResultsToFile = {
+            type: "ExportResultsToFile",
+            data: heatId,
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
+    /**
+     * @param format csv, json or html
+     * @param heatId null downloads the whole meet
+     */
+    function resultsDownloadURL(format: string, heatId: Uuid | null): string {
+        return httpURL(`/results/${format}${heatId ? "/" + heatId : ""}`, getNonLocalDomainOrIp());
+    }
+
+    function windReportURL(format: string): string {
+        return httpURL(`/results/wind_report/${format}`, getNonLocalDomainOrIp());
+    }
 
     function createBibEquivalence(eq: BibEquivalence) {
         const packet: MessageFromWebControlAddBibEquivalence = {
@@ -435,6 +513,17 @@ export default defineStore("main", () => {
         sendWSCommand(JSON.stringify(packet));
     }
 
+    function sendQrCodeCommand(content: string, caption: string) {
+        const packet: MessageFromWebControlDisplayQrCode = {
+            type: "DisplayQrCode",
+            data: {
+                content,
+                caption: caption == "" ? null : caption,
+            },
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
+
     function sendSwitchModeCommand() {
         displayCanSwitchModeInternal.value = false; // will be reset on updating message
         const packet: MessageFromWebControlSwitchMode = {
@@ -443,6 +532,30 @@ export default defineStore("main", () => {
         sendWSCommand(JSON.stringify(packet));
     }
 
+    function sendUpdateDisplayClientCommand(update: DisplayClientUpdate) {
+        const packet: MessageFromWebControlUpdateDisplayClient = {
+            type: "UpdateDisplayClient",
+            data: update,
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
+
+    function sendSelectDisplayClientPreviewCommand(name: string) {
+        const packet: MessageFromWebControlSelectDisplayClientPreview = {
+            type: "SelectDisplayClientPreview",
+            data: name,
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
+
+    function sendCallRoomCommand(name: string) {
+        const packet: MessageFromWebControlCallRoom = {
+            type: "CallRoom",
+            data: name,
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
+
     function sendGetWindValuesCommand(data: WindValueRequestDateContainer) {
         const packet: MessageFromWebControlRequestWindValues = {
             type: "RequestWindValues",
@@ -476,12 +589,6 @@ export default defineStore("main", () => {
         };
         sendWSCommand(JSON.stringify(packet));
     }
-    function sendRequestPasswordCommand() {
-        const packet: MessageFromWebControlRequestPassword = {
-            type: "RequestPassword",
-        };
-        sendWSCommand(JSON.stringify(packet));
-    }
     function sendRequestLicenseCommand() {
         const packet: MessageFromWebControlRequestLicense = {
             type: "RequestLicense",
@@ -521,6 +628,14 @@ export default defineStore("main", () => {
         };
         sendWSCommand(JSON.stringify(packet));
     }
+    function sendImportAthletesCommand(athletes: Athlete[]) {
+        athleteImportReport.value = null;
+        const packet: MessageFromWebControlImportAthletes = {
+            type: "ImportAthletes",
+            data: athletes,
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
     function sendDeleteCompetitorEvaluatedCommand(dt: DayTime) {
         const packet: MessageFromWebControlDeleteCompetitorEvaluated = {
             type: "DeleteCompetitorEvaluated",
@@ -542,12 +657,123 @@ export default defineStore("main", () => {
         };
         sendWSCommand(JSON.stringify(packet));
     }
+    /**
+     * The rendered background is too big for a websocket message, so it is uploaded over http
+     */
+    async function uploadPDFBackground(background: PDFBackground) {
+        if (authStore.token == null) {
+            return;
+        }
+        try {
+            const response = await fetch(httpURL("/api/pdf_background", getNonLocalDomainOrIp()), {
+                method: "POST",
+                headers: {
+                    "Content-Type": "application/json",
+                    Authorization: `Bearer ${authStore.token}`,
+                },
+                body: JSON.stringify(background),
+            });
+            if (!response.ok) {
+                console.error("Could not upload the pdf background", response.status);
+            }
+        } catch (e) {
+            console.error("Could not upload the pdf background", e);
+        }
+    }
+    function sendDeletePDFBackgroundCommand(settingFor: PDFSettingFor) {
+        const packet: MessageFromWebControlDeletePDFBackground = {
+            type: "DeletePDFBackground",
+            data: settingFor,
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
     function sendRequestAllPDFSettingsCommand() {
         const packet: MessageFromWebControlRequestPDFConfigurationSettings = {
             type: "RequestPDFConfigurationSettings",
         };
         sendWSCommand(JSON.stringify(packet));
     }
+    function sendUpsertCompetitionEventCommand(event: CompetitionEvent) {
+        const packet: MessageFromWebControlStoreCompetitionEvent = {
+            type: "StoreCompetitionEvent",
+            data: event,
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
+    function sendDeleteCompetitionEventCommand(id: Uuid) {
+        const packet: MessageFromWebControlDeleteCompetitionEvent = {
+            type: "DeleteCompetitionEvent",
+            data: id,
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
+    function sendRequestAllCompetitionEventsCommand() {
+        const packet: MessageFromWebControlRequestCompetitionEvents = {
+            type: "RequestCompetitionEvents",
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
+    function sendUpsertTickerMessageCommand(message: TickerMessage) {
+        const packet: MessageFromWebControlStoreTickerMessage = {
+            type: "StoreTickerMessage",
+            data: message,
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
+    function sendDeleteTickerMessageCommand(id: Uuid) {
+        const packet: MessageFromWebControlDeleteTickerMessage = {
+            type: "DeleteTickerMessage",
+            data: id,
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
+    function sendRequestTickerMessagesCommand() {
+        const packet: MessageFromWebControlRequestTickerMessages = {
+            type: "RequestTickerMessages",
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
+    function sendUpsertFieldEventCommand(event: FieldEvent) {
+        const packet: MessageFromWebControlStoreFieldEvent = {
+            type: "StoreFieldEvent",
+            data: event,
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
+    function sendDeleteFieldEventCommand(id: Uuid) {
+        const packet: MessageFromWebControlDeleteFieldEvent = {
+            type: "DeleteFieldEvent",
+            data: id,
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
+    function sendRequestFieldEventsCommand() {
+        const packet: MessageFromWebControlRequestFieldEvents = {
+            type: "RequestFieldEvents",
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
+    function sendUpsertFieldEventAttemptCommand(attempt: FieldEventAttempt) {
+        const packet: MessageFromWebControlStoreFieldEventAttempt = {
+            type: "StoreFieldEventAttempt",
+            data: attempt,
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
+    function sendDeleteFieldEventAttemptCommand(attempt: FieldEventAttempt) {
+        const packet: MessageFromWebControlDeleteFieldEventAttempt = {
+            type: "DeleteFieldEventAttempt",
+            data: attempt,
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
+    function sendShowFieldEventCommand(id: Uuid) {
+        const packet: MessageFromWebControlShowFieldEvent = {
+            type: "ShowFieldEvent",
+            data: id,
+        };
+        sendWSCommand(JSON.stringify(packet));
+    }
     function sendRequestDevModeStatusCommand() {
         const packet: MessageFromWebControlRequestDevMode = {
             type: "RequestDevMode",
@@ -710,9 +936,13 @@ export default defineStore("main", () => {
     return {
         connected,
         sendSwitchModeCommand,
+        sendUpdateDisplayClientCommand,
+        sendSelectDisplayClientPreviewCommand,
+        sendCallRoomCommand,
         sendAdvertisementsCommand,
         sendIdleCommand,
         sendFreetextCommand,
+        sendQrCodeCommand,
         sendGetHeatsCommand,
         sendGetLogsCommand,
         sendSelectHeatCommand,
@@ -723,13 +953,29 @@ export default defineStore("main", () => {
         sendGetWindValuesCommand,
         sendStaticallyConfigureServerCommand,
         sendExportToFileCommand,
+        sendExportResultsToFileCommand,
+        sendExportPDFsToFileCommand,
+        resultsDownloadURL,
+        windReportURL,
         sendRequestAthletesCommand,
         sendUpsertAthleteCommand,
+        sendImportAthletesCommand,
         sendDeleteAthleteCommand,
         sendCreateHeatAssignmentCommand,
         sendDeleteHeatAssignmentCommand,
         sendUpsertPDFSettingCommand,
         sendDeletePDFSettingCommand,
+        uploadPDFBackground,
+        sendDeletePDFBackgroundCommand,
+        sendUpsertCompetitionEventCommand,
+        sendDeleteCompetitionEventCommand,
+        sendUpsertTickerMessageCommand,
+        sendDeleteTickerMessageCommand,
+        sendUpsertFieldEventCommand,
+        sendDeleteFieldEventCommand,
+        sendUpsertFieldEventAttemptCommand,
+        sendDeleteFieldEventAttemptCommand,
+        sendShowFieldEventCommand,
         sendGetMainHeatCommand,
         sendDeleteCompetitorEvaluatedCommand,
         sendDebugDisplayCommand,
@@ -759,12 +1005,16 @@ export default defineStore("main", () => {
         currentClientFrame,
         staticConfiguration,
         athletesData,
+        athleteImportReport,
+        meetExportReport,
         pdfConfigurationSettings,
+        competitionEvents,
+        tickerMessages,
+        fieldEvents,
         versionMismatchTriggered,
         devMode,
         devMainHeatStartList,
         frametimeReport,
-        managementPassword,
         staticConfigurationMissing,
         license,
         connectionState,